@@ -0,0 +1,128 @@
+//! Compares the arithmetic backends on matrix addition, multiplication and inversion.
+//!
+//! Run with `cargo bench --features bench`; pass a filter such as `cargo bench --features bench -- mul/exact` to run a subset.
+
+use criterion::{
+    BenchmarkGroup, BenchmarkId, Criterion, criterion_group, criterion_main, measurement::WallTime,
+};
+use ebi_arithmetic::{
+    Inversion,
+    bench_support::{
+        MAGNITUDES, SIZES, random_matrix_enum, random_matrix_exact, random_matrix_f64,
+    },
+    matrix::{
+        fraction_matrix_enum::FractionMatrixEnum, fraction_matrix_exact::FractionMatrixExact,
+        fraction_matrix_f64::FractionMatrixF64,
+    },
+};
+use std::hint::black_box;
+
+const SEED: u64 = 42;
+
+/// The matrices of all backends, generated from the same values.
+struct Backends {
+    f64: FractionMatrixF64,
+    exact: FractionMatrixExact,
+    enum_approx: FractionMatrixEnum,
+    enum_exact: FractionMatrixEnum,
+}
+
+impl Backends {
+    fn new(size: usize, magnitude: u64) -> Self {
+        Self {
+            f64: random_matrix_f64(size, magnitude, SEED),
+            exact: random_matrix_exact(size, magnitude, SEED),
+            enum_approx: random_matrix_enum(size, magnitude, SEED, false),
+            enum_exact: random_matrix_enum(size, magnitude, SEED, true),
+        }
+    }
+}
+
+/// Benchmarks the operation on each backend, for each size and magnitude.
+fn bench_operation(
+    c: &mut Criterion,
+    name: &str,
+    f64: impl Fn(&FractionMatrixF64),
+    exact: impl Fn(&FractionMatrixExact),
+    enumeration: impl Fn(&FractionMatrixEnum),
+) {
+    let mut group: BenchmarkGroup<WallTime> = c.benchmark_group(name);
+    for size in SIZES {
+        for magnitude in MAGNITUDES {
+            let backends = Backends::new(size, magnitude);
+            let parameter = format!("{}x{}/{}", size, size, magnitude);
+            group.bench_with_input(
+                BenchmarkId::new("f64", &parameter),
+                &backends.f64,
+                |b, m| b.iter(|| f64(black_box(m))),
+            );
+            group.bench_with_input(
+                BenchmarkId::new("exact", &parameter),
+                &backends.exact,
+                |b, m| b.iter(|| exact(black_box(m))),
+            );
+            group.bench_with_input(
+                BenchmarkId::new("enum_approx", &parameter),
+                &backends.enum_approx,
+                |b, m| b.iter(|| enumeration(black_box(m))),
+            );
+            group.bench_with_input(
+                BenchmarkId::new("enum_exact", &parameter),
+                &backends.enum_exact,
+                |b, m| b.iter(|| enumeration(black_box(m))),
+            );
+        }
+    }
+    group.finish();
+}
+
+fn add(c: &mut Criterion) {
+    bench_operation(
+        c,
+        "add",
+        |m| {
+            black_box((m + m).unwrap());
+        },
+        |m| {
+            black_box((m + m).unwrap());
+        },
+        |m| {
+            black_box((m + m).unwrap());
+        },
+    );
+}
+
+fn mul(c: &mut Criterion) {
+    bench_operation(
+        c,
+        "mul",
+        |m| {
+            black_box((m * m).unwrap());
+        },
+        |m| {
+            black_box((m * m).unwrap());
+        },
+        |m| {
+            black_box((m * m).unwrap());
+        },
+    );
+}
+
+fn invert(c: &mut Criterion) {
+    bench_operation(
+        c,
+        "invert",
+        |m| {
+            black_box(m.clone().invert().unwrap());
+        },
+        |m| {
+            black_box(m.clone().invert().unwrap());
+        },
+        |m| {
+            black_box(m.clone().invert().unwrap());
+        },
+    );
+}
+
+criterion_group!(backends, add, mul, invert);
+criterion_main!(backends);
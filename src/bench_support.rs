@@ -0,0 +1,102 @@
+//! Generators of reproducible matrices for benchmarking the arithmetic backends against each other.
+//!
+//! Each generator draws the same numerators and denominators for the same seed, such that every backend works on the same values.
+//! The generated matrices are strictly diagonally dominant, and thus invertible, such that they can also be used to benchmark inversion and solving.
+//! The benchmarks themselves are in `benches/backends.rs`; run them with `cargo bench --features bench`.
+
+use malachite::rational::Rational;
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+use crate::{
+    fraction::fraction::EPSILON,
+    matrix::{
+        fraction_matrix_enum::FractionMatrixEnum, fraction_matrix_exact::FractionMatrixExact,
+        fraction_matrix_f64::FractionMatrixF64,
+    },
+};
+
+/// The sizes of the square matrices of the benchmarks.
+pub const SIZES: [usize; 3] = [4, 16, 32];
+
+/// The largest absolute numerator and denominator of the values of the benchmarks.
+/// Exact arithmetic slows down as numerators and denominators grow; floats do not.
+pub const MAGNITUDES: [u64; 3] = [10, 1_000, 1_000_000];
+
+/// Returns the numerators and denominators of a strictly diagonally dominant square matrix, row by row.
+/// Numerators lie in [-magnitude, magnitude] and denominators in [1, magnitude]; the diagonal is increased to exceed the rest of its row.
+pub fn random_values(size: usize, magnitude: u64, seed: u64) -> Vec<(i64, u64)> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let magnitude = magnitude.max(1);
+    let mut values = Vec::with_capacity(size * size);
+    for row in 0..size {
+        let start = values.len();
+        let mut off_diagonal = 0;
+        for column in 0..size {
+            let numerator = rng.random_range(-(magnitude as i64)..=magnitude as i64);
+            let denominator = rng.random_range(1..=magnitude);
+            if column != row {
+                //each value is at most its numerator in absolute value
+                off_diagonal += numerator.unsigned_abs();
+            }
+            values.push((numerator, denominator));
+        }
+        values[start + row] = ((off_diagonal + 1) as i64, 1);
+    }
+    values
+}
+
+pub fn random_matrix_exact(size: usize, magnitude: u64, seed: u64) -> FractionMatrixExact {
+    FractionMatrixExact {
+        values: random_values(size, magnitude, seed)
+            .into_iter()
+            .map(|(numerator, denominator)| Rational::from_signeds(numerator, denominator as i64))
+            .collect(),
+        number_of_rows: size,
+        number_of_columns: size,
+    }
+}
+
+pub fn random_matrix_f64(size: usize, magnitude: u64, seed: u64) -> FractionMatrixF64 {
+    FractionMatrixF64 {
+        values: random_values(size, magnitude, seed)
+            .into_iter()
+            .map(|(numerator, denominator)| numerator as f64 / denominator as f64)
+            .collect(),
+        number_of_rows: size,
+        number_of_columns: size,
+        epsilon: EPSILON,
+    }
+}
+
+/// Returns a [FractionMatrixEnum] of the given variant, regardless of the global arithmetic mode.
+pub fn random_matrix_enum(
+    size: usize,
+    magnitude: u64,
+    seed: u64,
+    exact: bool,
+) -> FractionMatrixEnum {
+    if exact {
+        FractionMatrixEnum::Exact(random_matrix_exact(size, magnitude, seed))
+    } else {
+        FractionMatrixEnum::Approx(random_matrix_f64(size, magnitude, seed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Inversion,
+        bench_support::{random_matrix_exact, random_matrix_f64},
+    };
+
+    #[test]
+    fn bench_support() {
+        let m = random_matrix_exact(5, 1000, 3);
+        assert_eq!(m, random_matrix_exact(5, 1000, 3));
+        assert!(m.invert().is_ok());
+
+        let n = random_matrix_f64(5, 1000, 3);
+        assert!(n.is_diagonally_dominant());
+        assert!(n.invert().is_ok());
+    }
+}
@@ -0,0 +1,167 @@
+//======================== dual run ========================//
+
+use anyhow::{Result, anyhow};
+
+use crate::fraction::{
+    approximate::Approximate, exact::float_to_rational, fraction_enum::FractionEnum,
+    fraction_exact::FractionExact, fraction_f64::FractionF64,
+};
+
+/// Converts a number into an exact and an approximate representation of the same value, as inputs for a [DualRun].
+pub trait ToDual {
+    fn to_dual(&self) -> Result<(FractionExact, FractionF64)>;
+}
+
+impl ToDual for FractionExact {
+    fn to_dual(&self) -> Result<(FractionExact, FractionF64)> {
+        Ok((self.clone(), FractionF64(self.0.clone().approximate()?)))
+    }
+}
+
+/// The exact representation is the exact value of the float, which may have a large denominator.
+impl ToDual for FractionF64 {
+    fn to_dual(&self) -> Result<(FractionExact, FractionF64)> {
+        Ok((FractionExact(float_to_rational(self.0)?), *self))
+    }
+}
+
+impl ToDual for FractionEnum {
+    fn to_dual(&self) -> Result<(FractionExact, FractionF64)> {
+        match self {
+            FractionEnum::Exact(f) => FractionExact(f.clone()).to_dual(),
+            FractionEnum::Approx(f) => FractionF64(*f).to_dual(),
+            FractionEnum::CannotCombineExactAndApprox => {
+                Err(anyhow!("cannot combine exact and approximate arithmetic"))
+            }
+        }
+    }
+}
+
+/// The differences between the results of the exact and the approximate run.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DualRunReport {
+    /// For each result, the absolute difference between the approximate and the exact value.
+    pub absolute_errors: Vec<f64>,
+    pub max_absolute_error: f64,
+    /// The largest absolute error relative to the exact value; for exact zeroes, the absolute error is used.
+    pub max_relative_error: f64,
+}
+
+impl DualRunReport {
+    /// Returns whether every result of the approximate run is within the given relative tolerance of the exact result.
+    pub fn is_within(&self, relative_tolerance: f64) -> bool {
+        self.max_relative_error <= relative_tolerance
+    }
+}
+
+/// Runs a computation with exact and with approximate arithmetic on the same inputs, to validate the approximate computation against the exact one.
+///
+/// A generic function can be passed twice; Rust instantiates it once per arithmetic.
+/// ```
+/// use ebi_arithmetic::{anyhow::Result, dual_run::DualRun, fraction::fraction_exact::FractionExact};
+/// use std::ops::Mul;
+///
+/// fn square<T: Clone>(inputs: Vec<T>) -> Result<Vec<T>> where for<'a> &'a T: Mul<&'a T, Output = T> {
+///     Ok(inputs.iter().map(|x| x * x).collect())
+/// }
+///
+/// let inputs = vec![FractionExact::from((1, 3)), FractionExact::from(7)];
+/// let run = DualRun::run(&inputs, square, square).unwrap();
+/// assert!(run.report.is_within(1e-12));
+/// ```
+#[derive(Clone, Debug)]
+pub struct DualRun {
+    pub exact: Vec<FractionExact>,
+    pub approx: Vec<FractionF64>,
+    pub report: DualRunReport,
+}
+
+impl DualRun {
+    /// Converts the inputs, runs `exact` on the exact inputs and `approx` on the approximate inputs, and compares the results.
+    /// Returns an error if either computation fails or if they return a different number of results.
+    pub fn run<I, E, A>(inputs: &[I], exact: E, approx: A) -> Result<Self>
+    where
+        I: ToDual,
+        E: FnOnce(Vec<FractionExact>) -> Result<Vec<FractionExact>>,
+        A: FnOnce(Vec<FractionF64>) -> Result<Vec<FractionF64>>,
+    {
+        let (exact_inputs, approx_inputs) = inputs
+            .iter()
+            .map(ToDual::to_dual)
+            .collect::<Result<(Vec<_>, Vec<_>)>>()?;
+
+        let exact = exact(exact_inputs)?;
+        let approx = approx(approx_inputs)?;
+        if exact.len() != approx.len() {
+            return Err(anyhow!(
+                "the exact run returned {} results, but the approximate run returned {}",
+                exact.len(),
+                approx.len()
+            ));
+        }
+
+        let mut absolute_errors = Vec::with_capacity(exact.len());
+        let mut max_absolute_error: f64 = 0.0;
+        let mut max_relative_error: f64 = 0.0;
+        for (e, a) in exact.iter().zip(approx.iter()) {
+            let e = e.clone().approximate()?;
+            let error = if a.0 == e { 0.0 } else { (a.0 - e).abs() };
+            let error = if error.is_nan() { f64::INFINITY } else { error };
+            let relative = if e == 0.0 { error } else { error / e.abs() };
+            max_absolute_error = max_absolute_error.max(error);
+            max_relative_error = max_relative_error.max(relative);
+            absolute_errors.push(error);
+        }
+
+        Ok(Self {
+            exact,
+            approx,
+            report: DualRunReport {
+                absolute_errors,
+                max_absolute_error,
+                max_relative_error,
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use std::ops::AddAssign;
+
+    use crate::{
+        Zero,
+        dual_run::{DualRun, ToDual},
+        fraction::{fraction_exact::FractionExact, fraction_f64::FractionF64},
+    };
+
+    /// Sums the inputs many times, which accumulates rounding errors in the approximate run.
+    fn repeated_sum<T: Zero + Clone + AddAssign<T>>(inputs: Vec<T>) -> Result<Vec<T>> {
+        let mut sum = T::zero();
+        for _ in 0..1000 {
+            for x in inputs.iter() {
+                sum += x.clone();
+            }
+        }
+        Ok(vec![sum])
+    }
+
+    #[test]
+    fn dual_run() {
+        let inputs = vec![FractionExact::from((1, 10)), FractionExact::from((1, 3))];
+        let run = DualRun::run(&inputs, repeated_sum, repeated_sum).unwrap();
+        assert_eq!(run.exact, vec![FractionExact::from((1300, 3))]);
+        assert!(run.report.max_absolute_error > 0.0);
+        assert!(run.report.is_within(1e-10));
+        assert!(!run.report.is_within(0.0));
+
+        //a differing number of results
+        assert!(DualRun::run(&inputs, Ok, |_: Vec<FractionF64>| Ok(vec![])).is_err());
+
+        //floats are converted exactly
+        let (exact, _) = FractionF64::from(0.1).to_dual().unwrap();
+        assert_ne!(exact, FractionExact::from((1, 10)));
+        assert!(FractionF64::from(f64::NAN).to_dual().is_err());
+    }
+}
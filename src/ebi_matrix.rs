@@ -1,6 +1,18 @@
-use crate::exact::MaybeExact;
-use anyhow::Result;
+use crate::{
+    Zero,
+    exact::MaybeExact,
+    matrix::{diff::MatrixDiff, solution_space::SolutionSpace},
+};
+use anyhow::{Result, anyhow};
+use std::ops::{AddAssign, MulAssign, Range};
 
+/// A matrix of fractions.
+///
+/// Matrices may have zero rows and/or zero columns, with the following semantics:
+/// - a product with an inner dimension of zero is the zero matrix (or vector) of the outer dimensions, as an empty sum is zero;
+/// - the inverse of the 0x0 matrix is the 0x0 matrix, and Gauss-Jordan elimination leaves empty matrices unchanged;
+/// - operations that require a square matrix fail on non-square empty matrices, such as 1x0;
+/// - conversions from rows cannot derive the number of columns without a row, and yield a 0x0 matrix.
 pub trait EbiMatrix<T>:
     Clone + MaybeExact + IdentityMinus + GaussJordan + TryFrom<Vec<Vec<T>>> + Eq
 where
@@ -9,6 +21,24 @@ where
     /// Creates a new matrix with each value initialised to zeroes.
     fn new(number_of_rows: usize, number_of_columns: usize) -> Self;
 
+    /// Creates the identity matrix of the given size.
+    fn identity(size: usize) -> Self {
+        let mut result = Self::new(size, size);
+        for i in 0..size {
+            result.set_one(i, i);
+        }
+        result
+    }
+
+    /// Creates a square matrix with the given values on its diagonal, and zeroes elsewhere.
+    fn diagonal(values: Vec<T>) -> Self {
+        let mut result = Self::new(values.len(), values.len());
+        for (i, value) in values.into_iter().enumerate() {
+            result.set(i, i, value);
+        }
+        result
+    }
+
     /// Add a number of rows and columns to the matrix, initialised to zeroes.
     /// Does not decrease the size.
     fn increase_size_to(&mut self, number_of_rows: usize, number_of_columns: usize) {
@@ -77,6 +107,130 @@ where
     /// Removes columsn from the left of the matrix.
     fn pop_front_columns(&mut self, number_of_columns_to_remove: usize);
 
+    /// Returns a copy of a row of the matrix, if it exists.
+    fn get_row(&self, row: usize) -> Option<Vec<T>> {
+        if row >= self.number_of_rows() {
+            return None;
+        }
+        (0..self.number_of_columns())
+            .map(|column| self.get(row, column))
+            .collect()
+    }
+
+    /// Returns a copy of a column of the matrix, if it exists.
+    fn get_column(&self, column: usize) -> Option<Vec<T>> {
+        if column >= self.number_of_columns() {
+            return None;
+        }
+        (0..self.number_of_rows())
+            .map(|row| self.get(row, column))
+            .collect()
+    }
+
+    /// Returns a copy of the values on the diagonal, starting at the top left.
+    /// For a matrix that is not square, the diagonal ends at the last row or column.
+    fn get_diagonal(&self) -> Vec<T> {
+        (0..self.number_of_rows().min(self.number_of_columns()))
+            .filter_map(|i| self.get(i, i))
+            .collect()
+    }
+
+    /// Returns the sum of the values on the diagonal.
+    /// Returns an error if the matrix is not square, or if the values cannot be combined.
+    fn trace(&self) -> Result<T>
+    where
+        T: Zero + for<'a> AddAssign<&'a T>,
+    {
+        if self.number_of_rows() != self.number_of_columns() {
+            return Err(anyhow!(
+                "can only take the trace of a square matrix, but got a {}x{} matrix",
+                self.number_of_rows(),
+                self.number_of_columns()
+            ));
+        }
+        //start from the first value, such that the sum keeps its exactness regardless of the mode
+        let mut diagonal = self.get_diagonal().into_iter();
+        let Some(mut sum) = diagonal.next() else {
+            return Ok(T::zero());
+        };
+        for value in diagonal {
+            sum += &value;
+        }
+        Ok(sum)
+    }
+
+    /// Adds a row with the given values to the bottom of the matrix.
+    /// Returns an error if the number of values differs from the number of columns, or if the values cannot be combined with the matrix.
+    fn push_row(&mut self, values: Vec<T>) -> Result<()> {
+        if values.len() != self.number_of_columns() {
+            return Err(anyhow!(
+                "cannot add a row of {} values to a matrix of {} columns",
+                values.len(),
+                self.number_of_columns()
+            ));
+        }
+        let row = self.number_of_rows();
+        self.push_rows(1);
+        for (column, value) in values.into_iter().enumerate() {
+            self.set(row, column, value);
+        }
+        Ok(())
+    }
+
+    /// Removes a row, moving the rows below it up.
+    /// If row does not exist, behaviour is undefined, and may panic.
+    fn remove_row(&mut self, row: usize) {
+        let number_of_columns = self.number_of_columns();
+        let mut rows = self.clone().to_vec();
+        rows.remove(row);
+        *self = if rows.is_empty() {
+            Self::new(0, number_of_columns)
+        } else {
+            match rows.try_into() {
+                Ok(matrix) => matrix,
+                Err(_) => panic!("the remaining rows do not form a matrix"),
+            }
+        };
+    }
+
+    /// Removes a column, moving the columns right of it to the left.
+    /// If column does not exist, behaviour is undefined, and may panic.
+    fn remove_column(&mut self, column: usize) {
+        for row in 0..self.number_of_rows() {
+            for c in (1..=column).rev() {
+                if let Some(value) = self.get(row, c - 1) {
+                    self.set(row, c, value);
+                }
+            }
+        }
+        self.pop_front_columns(1);
+    }
+
+    /// Swaps two rows.
+    /// If a row does not exist, behaviour is undefined, and may panic.
+    fn swap_rows(&mut self, row_a: usize, row_b: usize) {
+        for column in 0..self.number_of_columns() {
+            if let (Some(a), Some(b)) = (self.get(row_a, column), self.get(row_b, column)) {
+                self.set(row_a, column, b);
+                self.set(row_b, column, a);
+            }
+        }
+    }
+
+    /// Multiplies each value of a row by the factor.
+    /// If row does not exist, behaviour is undefined, and may panic.
+    fn scale_row(&mut self, row: usize, factor: &T)
+    where
+        T: for<'a> MulAssign<&'a T>,
+    {
+        for column in 0..self.number_of_columns() {
+            if let Some(mut value) = self.get(row, column) {
+                value *= factor;
+                self.set(row, column, value);
+            }
+        }
+    }
+
     /// Returns a vector of the matrix
     fn to_vec(self) -> Vec<Vec<T>>;
 }
@@ -101,4 +255,102 @@ pub trait GaussJordan {
     fn gauss_jordan_reduced(self) -> Result<Self>
     where
         Self: Sized;
-}
\ No newline at end of file
+}
+pub trait Symmetric {
+    /// Returns whether the matrix is square and equal to its transpose.
+    /// Approximate matrices are compared with their tolerance.
+    /// Matrices that are symmetric without tolerance are inverted with an LDLᵀ decomposition that exploits the symmetry;
+    /// approximate matrices only if the decomposition shows them to be positive definite, and others with general elimination.
+    fn is_symmetric(&self) -> bool;
+}
+
+pub trait BlockDiagonal {
+    /// Constructs the block-diagonal matrix (direct sum) of the given blocks.
+    /// Each block is placed below and to the right of the previous one; all other values are zero.
+    /// The blocks do not need to be square.
+    fn block_diagonal(blocks: Vec<Self>) -> Result<Self>
+    where
+        Self: Sized;
+}
+
+pub trait Blocks {
+    /// Returns a copy of the values in the given ranges of rows and columns.
+    /// Returns an error if a range does not lie within the matrix.
+    fn submatrix(&self, rows: Range<usize>, columns: Range<usize>) -> Result<Self>
+    where
+        Self: Sized;
+
+    /// Constructs the matrix [[a, b], [c, d]] from the blocks `[[&a, &b], [&c, &d]]`.
+    /// Returns an error if blocks in the same row have different numbers of rows, or blocks in the same column have different numbers of columns.
+    fn from_blocks(blocks: &[[&Self; 2]; 2]) -> Result<Self>
+    where
+        Self: Sized;
+}
+
+pub trait ConvexCombination<T> {
+    /// Computes alpha * a + (1 - alpha) * b, in a single pass over the values.
+    /// Returns an error if alpha is not in [0, 1], or if the matrices have different dimensions.
+    fn convex_combination(alpha: &T, a: &Self, b: &Self) -> Result<Self>
+    where
+        Self: Sized;
+}
+
+pub trait Diff<Rhs = Self> {
+    /// Lists the cells in which this matrix and the other matrix differ, with both values and their errors.
+    /// Intended for investigating diverging results, for instance between the exact and approximate backends.
+    fn diff(&self, other: &Rhs) -> MatrixDiff;
+}
+
+pub trait SolutionSpaceOf<T> {
+    /// Returns all solutions x of the linear system A x = b, where A is this matrix and b is `rhs`:
+    /// a particular solution plus a basis of the null space, one vector per free variable.
+    /// Returns None if the system has no solution.
+    fn solution_space(&self, rhs: &[T]) -> Result<Option<SolutionSpace<T>>>;
+}
+
+pub trait Solve<T> {
+    /// Returns the unique solution x of the linear system A x = b, where A is this matrix and b is `rhs`.
+    /// Tridiagonal and other banded systems are solved with elimination within the band, which takes linear time in the size of the system for a fixed bandwidth;
+    /// detecting the band still scans all values of the dense matrix. Other systems are solved by reducing the augmented matrix with partial pivoting.
+    /// Returns an error if the system has no solution or more than one; use [SolutionSpaceOf] to obtain all solutions.
+    fn solve(&self, rhs: &[T]) -> Result<Vec<T>>;
+}
+
+pub trait SpectralRadiusBound<T> {
+    /// Returns the largest sum of absolute values of a row (the maximum row sum norm), which is an upper bound of the spectral radius.
+    /// The sums are computed exactly; for approximate matrices, the bound is rounded up to the next float.
+    /// Returns an error if the matrix is not square, or if it contains values that are not finite.
+    fn spectral_radius_upper_bound(&self) -> Result<T>;
+
+    /// Returns whether the matrix is square, has no negative values, and each of its rows sums to strictly less than one.
+    /// This certifies that the spectral radius is below one, such that the Neumann series Σ Mᵏ converges to (I - M)⁻¹.
+    /// The sums are computed exactly, also for approximate matrices; returns false if the certificate cannot be established.
+    fn is_substochastic_strict(&self) -> bool;
+}
+
+pub trait Determinant<T> {
+    /// Returns the determinant of the matrix, computed with elimination with row swaps.
+    /// A matrix is invertible if and only if its determinant is not zero; the determinant of a 0x0 matrix is one.
+    /// Returns an error if the matrix is not square.
+    fn determinant(&self) -> Result<T>;
+}
+
+pub trait Norm<T> {
+    /// Returns the largest sum of absolute values of a column (the maximum column sum norm).
+    /// The norm of an empty matrix is zero.
+    fn norm_1(&self) -> Result<T>;
+
+    /// Returns the largest sum of absolute values of a row (the maximum row sum norm).
+    /// The norm of an empty matrix is zero.
+    fn norm_inf(&self) -> Result<T>;
+
+    /// Returns the square root of the sum of squared values, approximated to the given number of decimals, also for exact matrices.
+    fn norm_frobenius(&self, decimal_places: u32) -> Result<T>;
+}
+
+pub trait MulBatch<T> {
+    /// Multiplies the matrix with each of the column vectors, sharing the traversal of the matrix over all vectors.
+    /// For exact matrices, the common denominator of each row is computed once rather than once per vector.
+    /// Returns an error if a vector does not have a value for each column.
+    fn mul_batch(&self, vectors: &[Vec<T>]) -> Result<Vec<Vec<T>>>;
+}
@@ -1,15 +1,17 @@
 use crate::fraction::{
     fraction_enum::FractionEnum, fraction_exact::FractionExact, fraction_f64::FractionF64,
+    log_fraction::LogFraction, percentage::Percentage,
 };
 use anyhow::Result;
-use malachite::base::random::Seed;
-use rand::RngCore;
+use malachite::{Integer, base::random::Seed};
+use rand::{Rng, RngCore};
 
 pub trait EbiNumber: Zero + One + Round + Clone + ToNative {}
 
 impl EbiNumber for FractionEnum {}
 impl EbiNumber for FractionF64 {}
 impl EbiNumber for FractionExact {}
+impl EbiNumber for LogFraction {}
 impl EbiNumber for f32 {}
 impl EbiNumber for f64 {}
 impl EbiNumber for usize {}
@@ -66,6 +68,7 @@ pub trait Signed: Sized {
     }
 }
 
+/// Implementors of an earlier version of this trait need to implement [Round::round_half_to_even], [Round::round_to_multiple_of] and [Round::round_to_decimal_places] as well, as these cannot be derived from floor and ceil.
 pub trait Round: Sized {
     /// Returns the largest integer less than or equal to `self`.
     fn floor(self) -> Self;
@@ -75,6 +78,31 @@ pub trait Round: Sized {
 
     ///Returns the nearest integer to self. If a value is half-way between two integers, round away from zero.
     fn round_half_away_from_zero(self) -> Self;
+
+    /// Returns the nearest integer to self. If a value is half-way between two integers, rounds to the even one.
+    /// Unlike rounding half away from zero, this does not bias sums of rounded values.
+    fn round_half_to_even(self) -> Self;
+
+    /// Returns the integer part of self, that is, rounds towards zero.
+    /// By default, values below zero are rounded up, and other values are rounded down.
+    fn trunc(self) -> Self
+    where
+        Self: Zero + PartialOrd,
+    {
+        if self < Self::zero() {
+            self.ceil()
+        } else {
+            self.floor()
+        }
+    }
+
+    /// Returns the multiple of `multiple` nearest to self. If a value is half-way between two multiples, rounds to the even multiple.
+    /// Returns an error if `multiple` is zero or not finite, or if the result does not fit in the type.
+    fn round_to_multiple_of(self, multiple: &Self) -> Result<Self>;
+
+    /// Returns the number with at most `decimal_places` decimals nearest to self. If a value is half-way, rounds to an even last decimal.
+    /// Floats are rounded as the exact value that they represent, and then back to the nearest float, such that exact and approximate values round alike.
+    fn round_to_decimal_places(self, decimal_places: u32) -> Self;
 }
 
 pub trait Recip: Sized {
@@ -82,6 +110,12 @@ pub trait Recip: Sized {
     fn recip(self) -> Self;
 }
 
+pub trait Pow: Sized {
+    /// Raises the number to an integer power; a negative power takes the reciprocal, and any number to the power zero is one.
+    /// Exact numbers are raised exactly. Returns an error for a negative power of zero.
+    fn pow(&self, exponent: i64) -> Result<Self>;
+}
+
 pub trait OneMinus: Sized {
     fn one_minus(self) -> Self;
 }
@@ -110,7 +144,23 @@ pub trait ChooseRandomly {
     /// If more than a couple of draws are made, consider creating a cache and drawing from it.
     fn choose_randomly(fractions: &Vec<Self>) -> Result<usize>
     where
-        Self: Sized;
+        Self: Sized,
+    {
+        Self::choose_randomly_with(fractions, &mut rand::rng())
+    }
+
+    /// As [ChooseRandomly::choose_randomly], but draws from the given random number generator.
+    /// With a seeded generator, the choices are reproducible.
+    ///
+    /// Implementors need to implement this method or [ChooseRandomly::choose_randomly].
+    /// The default ignores `rng` and calls [ChooseRandomly::choose_randomly], and is thus not reproducible.
+    fn choose_randomly_with<R: Rng + ?Sized>(fractions: &Vec<Self>, rng: &mut R) -> Result<usize>
+    where
+        Self: Sized,
+    {
+        let _ = rng;
+        Self::choose_randomly(fractions)
+    }
 
     fn choose_randomly_create_cache<'a>(
         fractions: impl Iterator<Item = &'a Self>,
@@ -121,7 +171,23 @@ pub trait ChooseRandomly {
 
     fn choose_randomly_cached(cache: &Self::Cache) -> usize
     where
-        Self: Sized;
+        Self: Sized,
+    {
+        Self::choose_randomly_cached_with(cache, &mut rand::rng())
+    }
+
+    /// As [ChooseRandomly::choose_randomly_cached], but draws from the given random number generator.
+    /// With a seeded generator, the choices are reproducible.
+    ///
+    /// Implementors need to implement this method or [ChooseRandomly::choose_randomly_cached].
+    /// The default ignores `rng` and calls [ChooseRandomly::choose_randomly_cached], and is thus not reproducible.
+    fn choose_randomly_cached_with<R: Rng + ?Sized>(cache: &Self::Cache, rng: &mut R) -> usize
+    where
+        Self: Sized,
+    {
+        let _ = rng;
+        Self::choose_randomly_cached(cache)
+    }
 }
 
 pub trait Sqrt {
@@ -161,8 +227,55 @@ pub trait Sqrt {
     }
 }
 
+pub trait NthRoot {
+    /// Returns the n-th root of the absolute value, within 1/10^`decimal_places` of the actual value.
+    /// Returns an error if n is zero.
+    /// Exact values whose numerator and denominator are perfect n-th powers yield their root exactly; approximate values ignore `decimal_places`.
+    fn nth_root_abs(&self, n: u32, decimal_places: u32) -> Result<Self>
+    where
+        Self: Sized;
+}
+
+pub trait Exponential {
+    /// Returns the natural logarithm of the value, within 1/10^`precision_decimals` of the actual value.
+    /// Returns an error if the value is not positive.
+    /// Exact values are computed with exact arithmetic, of which the cost grows with `precision_decimals`; approximate values ignore `precision_decimals`.
+    fn ln(&self, precision_decimals: u32) -> Result<Self>
+    where
+        Self: Sized;
+
+    /// Returns e to the power of the value, within 1/10^`precision_decimals` of the actual value.
+    /// Exact values are computed with exact arithmetic, of which the cost grows with `precision_decimals` and with the value itself; approximate values ignore `precision_decimals`.
+    fn exp(&self, precision_decimals: u32) -> Result<Self>
+    where
+        Self: Sized;
+}
+
 pub trait ToNative {
     /// Returns the nearest usize to `self`. If a value is half-way between two usizes, rounds away from zero.
     /// In case there is no sensible notion of 'nearest', returns `usize::MAX`.
     fn to_usize(&self) -> usize;
 }
+
+pub trait ToInteger {
+    /// Returns the largest usize less than or equal to `self`, without going through a float.
+    /// Approximate values within the approximation tolerance of an integer are considered to be that integer, as in [ToInteger::try_to_integer].
+    /// Saturates: negative values yield 0 and values beyond usize::MAX yield usize::MAX.
+    /// In case there is no sensible notion of 'floor', returns `usize::MAX`.
+    fn to_usize_floor(&self) -> usize;
+
+    /// Returns the nearest i64 to `self`, without going through a float. If a value is half-way between two integers, rounds away from zero.
+    /// Saturates: values beyond the range of i64 yield i64::MIN or i64::MAX.
+    /// In case there is no sensible notion of 'nearest', returns `i64::MAX`.
+    fn to_i64_round(&self) -> i64;
+
+    /// Returns `self` as an integer of arbitrary size, which cannot overflow.
+    /// Returns an error if `self` is not an integer; approximate values within the approximation tolerance of an integer are considered to be that integer.
+    fn try_to_integer(&self) -> Result<Integer>;
+}
+
+pub trait ToPercentage {
+    /// Returns the value as a percentage with at most `max_decimals` decimals, for display.
+    /// Values outside of [0, 1] saturate to 0% or 100%; approximate values that are outside by less than the approximation tolerance are not considered to be saturated.
+    fn to_percentage_points(&self, max_decimals: u32) -> Percentage;
+}
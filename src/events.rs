@@ -0,0 +1,19 @@
+//! Structured events that show where exact arithmetic becomes expensive, emitted through [tracing](https://docs.rs/tracing) if the `tracing` feature is enabled.
+//!
+//! All events are emitted at the debug level:
+//! - `exact matrix product`, with the dimensions, whether the product was tiled, and the number of bits of the largest common denominator;
+//! - `falling back to general elimination`, when a system is neither tridiagonal nor banded;
+//! - `singular matrix`, with the rank and the row of the first zero pivot;
+//! - `exact size limit exceeded`, when an [ExactSizeGuard](crate::size_guard::ExactSizeGuard) aborts a computation.
+//!
+//! Without the feature, the arguments of the events are not evaluated.
+
+/// Emits a debug event if the `tracing` feature is enabled.
+macro_rules! debug_event {
+    ($($arg:tt)+) => {
+        #[cfg(feature = "tracing")]
+        ::tracing::debug!($($arg)+)
+    };
+}
+
+pub(crate) use debug_event;
@@ -1,12 +1,21 @@
 //======================== exactness tools ========================//
 
 use anyhow::Result;
+use std::borrow::Cow;
+use std::cell::Cell;
+use std::marker::PhantomData;
 use std::sync::atomic::AtomicBool;
 
 static EXACT: AtomicBool = AtomicBool::new(true);
 
+thread_local! {
+    /// The mode of the innermost [ExactnessScope] of this thread, if any.
+    static SCOPED_EXACT: Cell<Option<bool>> = const { Cell::new(None) };
+}
+
 /// Enables or disables exact arithmetic globally.
 /// Exact arithmetic cannot be combined with approximate arithmetic.
+/// An [ExactnessScope] takes precedence over this setting on its thread.
 pub fn set_exact_globally(exact: bool) {
     EXACT.store(exact, std::sync::atomic::Ordering::Relaxed);
 }
@@ -22,7 +31,9 @@ pub fn is_exact_globally() -> bool {
             not(feature = "approximatearithmetic")
         )
     )) {
-        EXACT.load(std::sync::atomic::Ordering::Relaxed)
+        SCOPED_EXACT
+            .with(|scoped| scoped.get())
+            .unwrap_or_else(|| EXACT.load(std::sync::atomic::Ordering::Relaxed))
     } else if cfg!(feature = "exactarithmetic") {
         true
     } else {
@@ -30,6 +41,95 @@ pub fn is_exact_globally() -> bool {
     }
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Exactness {
+    Exact,
+    Approximate,
+}
+
+/// Overrides the global arithmetic mode for the current thread, until the scope is dropped.
+/// Scopes may be nested; dropping a scope restores the mode of the enclosing one.
+/// This allows exact and approximate computations to run concurrently on different threads.
+///
+/// Only has an effect if the mode is selected at runtime, that is, if neither or both of the `exactarithmetic` and `approximatearithmetic` features are enabled.
+#[must_use = "the mode is restored when the scope is dropped"]
+pub struct ExactnessScope {
+    previous: Option<bool>,
+    //the scope belongs to the thread that entered it
+    _thread: PhantomData<*const ()>,
+}
+
+impl ExactnessScope {
+    pub fn enter(exactness: Exactness) -> Self {
+        let previous =
+            SCOPED_EXACT.with(|scoped| scoped.replace(Some(exactness == Exactness::Exact)));
+        Self {
+            previous,
+            _thread: PhantomData,
+        }
+    }
+}
+
+impl Drop for ExactnessScope {
+    fn drop(&mut self) {
+        SCOPED_EXACT.with(|scoped| scoped.set(self.previous));
+    }
+}
+
+/// Whether the `exactarithmetic` feature is enabled for this crate, after feature unification across the dependency graph.
+pub const EXACT_ARITHMETIC_FEATURE: bool = cfg!(feature = "exactarithmetic");
+
+/// Whether the `approximatearithmetic` feature is enabled for this crate, after feature unification across the dependency graph.
+pub const APPROXIMATE_ARITHMETIC_FEATURE: bool = cfg!(feature = "approximatearithmetic");
+
+/// Whether the arithmetic mode is selected at runtime, which is the case if neither or both of the arithmetic features are enabled.
+pub const RUNTIME_MODE: bool = EXACT_ARITHMETIC_FEATURE == APPROXIMATE_ARITHMETIC_FEATURE;
+
+/// The arithmetic mode that the [Fraction](crate::Fraction) and [FractionMatrix](crate::FractionMatrix) aliases operate in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Mode {
+    /// Exact arithmetic, fixed at compile time by the `exactarithmetic` feature.
+    Exact,
+    /// Approximate arithmetic, fixed at compile time by the `approximatearithmetic` feature.
+    Approximate,
+    /// Selected at runtime, with the exactness that currently applies to this thread.
+    Runtime(Exactness),
+}
+
+impl Mode {
+    pub fn is_exact(&self) -> bool {
+        matches!(self, Mode::Exact | Mode::Runtime(Exactness::Exact))
+    }
+}
+
+/// Returns the arithmetic mode this crate is actually running with.
+/// As the features of a crate are unified over the whole dependency graph, a dependency may have changed the mode; applications can use this function to detect or assert the mode.
+pub fn arithmetic_mode() -> Mode {
+    if RUNTIME_MODE {
+        Mode::Runtime(if is_exact_globally() {
+            Exactness::Exact
+        } else {
+            Exactness::Approximate
+        })
+    } else if EXACT_ARITHMETIC_FEATURE {
+        Mode::Exact
+    } else {
+        Mode::Approximate
+    }
+}
+
+/// Returns the name of the struct behind the [Fraction](crate::Fraction) alias: `FractionExact`, `FractionF64` or `FractionEnum`.
+/// The matrix and log-polynomial aliases use the corresponding backend.
+pub fn backend_name() -> &'static str {
+    if RUNTIME_MODE {
+        "FractionEnum"
+    } else if EXACT_ARITHMETIC_FEATURE {
+        "FractionExact"
+    } else {
+        "FractionF64"
+    }
+}
+
 pub trait MaybeExact {
     type Approximate;
     type Exact;
@@ -57,8 +157,116 @@ pub trait MaybeExact {
     fn exact(self) -> Result<Self::Exact>;
 
     /// Attempts to create an object, but will fail if the mode is approximate.
-    fn try_to_exact(exact: Self::Exact) -> Result<Self> where Self: Sized;
+    fn try_to_exact(exact: Self::Exact) -> Result<Self>
+    where
+        Self: Sized;
 
     /// Attempts to create an object, but will fail if the mode is exact.
-    fn try_to_approx(approx: Self::Approximate) -> Result<Self> where Self: Sized;
+    fn try_to_approx(approx: Self::Approximate) -> Result<Self>
+    where
+        Self: Sized;
+
+    /**
+     * Returns the exact value, which is moved out without copying.
+     * Returns an error for approximate values; use [MaybeExact::approx_to_exact] to convert those explicitly.
+     */
+    fn into_exact(self) -> Result<Self::Exact>
+    where
+        Self: Sized,
+    {
+        self.exact()
+    }
+
+    /**
+     * Returns the approximate value, rounding an exact value to the nearest float if the type supports that.
+     * An approximate value is moved out without copying.
+     */
+    fn into_approx(self) -> Result<Self::Approximate>
+    where
+        Self: Sized,
+    {
+        self.approx()
+    }
+
+    /**
+     * Returns the exact value, borrowed if this object is exact.
+     * Returns an error for approximate values; use [MaybeExact::approx_to_exact] to convert those explicitly.
+     */
+    fn exact_cow(&self) -> Result<Cow<'_, Self::Exact>>
+    where
+        Self::Exact: Clone,
+    {
+        self.exact_ref().map(Cow::Borrowed)
+    }
+
+    /**
+     * Converts an approximate value explicitly to the exact value of its floats, without loss; an exact value is copied.
+     * Returns an error for infinite and NaN values, and if the type does not support the conversion.
+     */
+    fn approx_to_exact(&self) -> Result<Self::Exact>
+    where
+        Self::Exact: Clone,
+    {
+        self.exact_ref().cloned()
+    }
+
+    /**
+     * Returns the approximate value: borrowed if this object is approximate, and converted as in [MaybeExact::into_approx] otherwise.
+     */
+    fn approx_cow(&self) -> Result<Cow<'_, Self::Approximate>>
+    where
+        Self::Approximate: Clone,
+    {
+        self.approx_ref().map(Cow::Borrowed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(any(
+        all(feature = "exactarithmetic", feature = "approximatearithmetic"),
+        all(
+            not(feature = "exactarithmetic"),
+            not(feature = "approximatearithmetic")
+        )
+    ))]
+    #[test]
+    fn exactness_scope() {
+        use crate::{
+            exact::{Exactness, ExactnessScope, MaybeExact, is_exact_globally},
+            fraction::fraction_enum::FractionEnum,
+        };
+
+        let global = is_exact_globally();
+        {
+            let _scope = ExactnessScope::enter(Exactness::Approximate);
+            assert!(!FractionEnum::from(1).is_exact());
+            {
+                let _inner = ExactnessScope::enter(Exactness::Exact);
+                assert!(FractionEnum::from((1, 3)).is_exact());
+            }
+            assert!(!is_exact_globally());
+
+            //other threads are not affected
+            let other = std::thread::spawn(is_exact_globally).join().unwrap();
+            assert_eq!(other, global);
+        }
+        assert_eq!(is_exact_globally(), global);
+    }
+
+    #[test]
+    fn arithmetic_mode() {
+        use crate::{
+            exact::{Mode, arithmetic_mode, backend_name, is_exact_globally},
+            fraction::fraction::Fraction,
+        };
+
+        assert_eq!(arithmetic_mode().is_exact(), is_exact_globally());
+        assert!(std::any::type_name::<Fraction>().ends_with(backend_name()));
+        match arithmetic_mode() {
+            Mode::Exact => assert_eq!(backend_name(), "FractionExact"),
+            Mode::Approximate => assert_eq!(backend_name(), "FractionF64"),
+            Mode::Runtime(_) => assert_eq!(backend_name(), "FractionEnum"),
+        }
+    }
 }
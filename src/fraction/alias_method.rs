@@ -0,0 +1,198 @@
+use anyhow::{Result, anyhow};
+use malachite::{
+    Natural,
+    base::{num::arithmetic::traits::Lcm, random::Seed},
+    natural::random::random_naturals_less_than,
+    rational::Rational,
+};
+use rand::Rng;
+
+use crate::fraction::{fraction_exact::FractionExact, fraction_f64::FractionF64};
+
+/// Builds the alias table of Vose's alias method.
+/// Each weight is scaled to `weight * n`, such that a column is full at `total`.
+/// Returns for each column the part that belongs to the column itself, and the index that fills the remainder of the column.
+macro_rules! alias_table {
+    ($scaled:ident, $total:expr, $full:expr) => {{
+        let n = $scaled.len();
+        let mut aliases: Vec<usize> = (0..n).collect();
+        let (mut small, mut large): (Vec<usize>, Vec<usize>) =
+            (0..n).partition(|i| $scaled[*i] < $total);
+        while let (Some(l), Some(g)) = (small.pop(), large.pop()) {
+            aliases[l] = g;
+            //the large column donates the remainder of the small column
+            let remainder = &$total - &$scaled[l];
+            $scaled[g] -= &remainder;
+            if $scaled[g] < $total {
+                small.push(g);
+            } else {
+                large.push(g);
+            }
+        }
+        //the remaining columns are full, up to rounding errors
+        for i in small.into_iter().chain(large) {
+            $scaled[i] = $full;
+        }
+        ($scaled, aliases)
+    }};
+}
+
+/// A cache to draw random indices in constant time per draw, using Vose's alias method.
+///
+/// Creating the cache takes linear time, after which each draw takes a single random column and a single random value.
+/// The binary search of [FractionRandomCache](crate::fraction::choose_randomly::FractionRandomCache) takes logarithmic time per draw instead.
+pub struct FractionRandomCacheAlias {
+    probabilities: Vec<f64>,
+    aliases: Vec<usize>,
+}
+
+impl FractionRandomCacheAlias {
+    /// Creates the cache. The fractions do not need to sum to 1, but need to be non-negative and finite, and at least one needs to be positive.
+    pub fn new<'a>(fractions: impl Iterator<Item = &'a FractionF64>) -> Result<Self> {
+        let weights = fractions.map(|f| f.0).collect::<Vec<_>>();
+        if weights.is_empty() {
+            return Err(anyhow!("cannot take an element of an empty list"));
+        }
+        if weights.iter().any(|w| !w.is_finite() || *w < 0.0) {
+            return Err(anyhow!(
+                "cannot choose randomly with negative or non-finite fractions"
+            ));
+        }
+        let sum: f64 = weights.iter().sum();
+        if sum <= 0.0 {
+            return Err(anyhow!("sum of fractions is zero"));
+        }
+
+        let n = weights.len() as f64;
+        let mut scaled = weights.into_iter().map(|w| w * n / sum).collect::<Vec<_>>();
+        let (probabilities, aliases) = alias_table!(scaled, 1.0, 1.0);
+        Ok(Self {
+            probabilities,
+            aliases,
+        })
+    }
+
+    /// Returns a random index, with a likelihood proportional to the fraction at that index.
+    pub fn choose(&self) -> usize {
+        self.choose_with(&mut rand::rng())
+    }
+
+    /// As [FractionRandomCacheAlias::choose], but draws from the given random number generator.
+    pub fn choose_with<R: Rng + ?Sized>(&self, rng: &mut R) -> usize {
+        let column = rng.random_range(0..self.probabilities.len());
+        if rng.random::<f64>() < self.probabilities[column] {
+            column
+        } else {
+            self.aliases[column]
+        }
+    }
+}
+
+/// A cache to draw random indices in constant time per draw, using Vose's alias method on exact fractions.
+///
+/// The fractions are brought to a common denominator, such that the table consists of integers and the draws follow the distribution exactly.
+pub struct FractionRandomCacheAliasExact {
+    probabilities: Vec<Natural>,
+    aliases: Vec<usize>,
+    total: Natural,
+}
+
+impl FractionRandomCacheAliasExact {
+    /// Creates the cache. The fractions do not need to sum to 1, but need to be non-negative, and at least one needs to be positive.
+    pub fn new<'a>(fractions: impl Iterator<Item = &'a FractionExact>) -> Result<Self> {
+        let fractions = fractions.map(|f| &f.0).collect::<Vec<&Rational>>();
+        if fractions.is_empty() {
+            return Err(anyhow!("cannot take an element of an empty list"));
+        }
+        if fractions.iter().any(|f| **f < 0u32) {
+            return Err(anyhow!("cannot choose randomly with negative fractions"));
+        }
+
+        //numerators over the common denominator
+        let denominator = Rational::from(
+            fractions
+                .iter()
+                .fold(Natural::from(1u32), |lcm, f| lcm.lcm(f.to_denominator())),
+        );
+        let weights = fractions
+            .into_iter()
+            .map(|f| Natural::try_from(f * &denominator).unwrap())
+            .collect::<Vec<_>>();
+        let total: Natural = weights.iter().sum();
+        if total == 0u32 {
+            return Err(anyhow!("sum of fractions is zero"));
+        }
+
+        let n = Natural::from(weights.len());
+        let mut scaled = weights.into_iter().map(|w| w * &n).collect::<Vec<_>>();
+        let (probabilities, aliases) = alias_table!(scaled, total, total.clone());
+        Ok(Self {
+            probabilities,
+            aliases,
+            total,
+        })
+    }
+
+    /// Returns a random index, with a likelihood proportional to the fraction at that index.
+    pub fn choose(&self) -> usize {
+        self.choose_with(&mut rand::rng())
+    }
+
+    /// As [FractionRandomCacheAliasExact::choose], but draws from the given random number generator.
+    pub fn choose_with<R: Rng + ?Sized>(&self, rng: &mut R) -> usize {
+        let column = rng.random_range(0..self.probabilities.len());
+        let mut buf = [0u8; 32];
+        rng.fill_bytes(&mut buf);
+        let value = random_naturals_less_than(Seed::from_bytes(buf), self.total.clone())
+            .next()
+            .unwrap();
+        if value < self.probabilities[column] {
+            column
+        } else {
+            self.aliases[column]
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use crate::fraction::{
+        alias_method::{FractionRandomCacheAlias, FractionRandomCacheAliasExact},
+        fraction_exact::FractionExact,
+        fraction_f64::FractionF64,
+    };
+
+    #[test]
+    fn alias_method() {
+        let mut rng = StdRng::seed_from_u64(1);
+
+        let fractions = [
+            FractionExact::from((1, 6)),
+            FractionExact::from(0),
+            FractionExact::from((1, 2)),
+            FractionExact::from((1, 3)),
+        ];
+        let cache = FractionRandomCacheAliasExact::new(fractions.iter()).unwrap();
+        let mut counts = [0usize; 4];
+        for _ in 0..6000 {
+            counts[cache.choose_with(&mut rng)] += 1;
+        }
+        assert_eq!(counts[1], 0);
+        assert!((800..1200).contains(&counts[0]));
+        assert!((2700..3300).contains(&counts[2]));
+
+        let fractions = [FractionF64::from(3.0), FractionF64::from(1.0)];
+        let cache = FractionRandomCacheAlias::new(fractions.iter()).unwrap();
+        let mut counts = [0usize; 2];
+        for _ in 0..4000 {
+            counts[cache.choose_with(&mut rng)] += 1;
+        }
+        assert!((2800..3200).contains(&counts[0]));
+
+        assert!(FractionRandomCacheAlias::new([].iter()).is_err());
+        assert!(FractionRandomCacheAlias::new([FractionF64::from(-1.0)].iter()).is_err());
+        assert!(FractionRandomCacheAliasExact::new([FractionExact::from(0)].iter()).is_err());
+    }
+}
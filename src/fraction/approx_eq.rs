@@ -0,0 +1,107 @@
+use malachite::{
+    base::{
+        num::{arithmetic::traits::Abs, conversion::traits::RoundingFrom},
+        rounding_modes::RoundingMode,
+    },
+    rational::Rational,
+};
+
+use crate::fraction::{
+    fraction_enum::FractionEnum, fraction_exact::FractionExact, fraction_f64::FractionF64,
+};
+
+/// Equality up to an explicit absolute tolerance, separate from [PartialEq].
+///
+/// [PartialEq] compares approximate values with the fixed [EPSILON](crate::fraction::fraction::EPSILON), which may be too loose or too strict for a particular use.
+/// Here, two values are equal if their difference is at most the tolerance; with a zero tolerance, values must be equal exactly.
+/// NaN is not equal to anything, and values of different backends are never equal.
+pub trait ApproxEq<T = Self> {
+    fn approx_eq(&self, other: &Self, tolerance: &T) -> bool;
+}
+
+pub(crate) fn f64_approx_eq(x: f64, y: f64, tolerance: f64) -> bool {
+    //equal infinities have an undefined difference
+    x == y || (x - y).abs() <= tolerance
+}
+
+pub(crate) fn rational_approx_eq(x: &Rational, y: &Rational, tolerance: &Rational) -> bool {
+    x == y || (x - y).abs() <= *tolerance
+}
+
+impl ApproxEq for FractionF64 {
+    fn approx_eq(&self, other: &Self, tolerance: &Self) -> bool {
+        f64_approx_eq(self.0, other.0, tolerance.0)
+    }
+}
+
+impl ApproxEq for FractionExact {
+    fn approx_eq(&self, other: &Self, tolerance: &Self) -> bool {
+        rational_approx_eq(&self.0, &other.0, &tolerance.0)
+    }
+}
+
+impl FractionEnum {
+    /// Returns the tolerance as a float, also if it is exact.
+    pub(crate) fn approx_tolerance(&self) -> Option<f64> {
+        match self {
+            FractionEnum::Exact(f) => Some(f64::rounding_from(f, RoundingMode::Nearest).0),
+            FractionEnum::Approx(f) => Some(*f),
+            FractionEnum::CannotCombineExactAndApprox => None,
+        }
+    }
+
+    /// Returns the tolerance as a rational, also if it is approximate and finite.
+    pub(crate) fn exact_tolerance(&self) -> Option<Rational> {
+        match self {
+            FractionEnum::Exact(f) => Some(f.clone()),
+            FractionEnum::Approx(f) => Rational::try_from(*f).ok(),
+            FractionEnum::CannotCombineExactAndApprox => None,
+        }
+    }
+}
+
+/// The tolerance may be exact or approximate, regardless of the values.
+impl ApproxEq for FractionEnum {
+    fn approx_eq(&self, other: &Self, tolerance: &Self) -> bool {
+        match (self, other) {
+            (FractionEnum::Exact(x), FractionEnum::Exact(y)) => tolerance
+                .exact_tolerance()
+                .is_some_and(|tolerance| rational_approx_eq(x, y, &tolerance)),
+            (FractionEnum::Approx(x), FractionEnum::Approx(y)) => tolerance
+                .approx_tolerance()
+                .is_some_and(|tolerance| f64_approx_eq(*x, *y, tolerance)),
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use malachite::rational::Rational;
+
+    use crate::fraction::{
+        approx_eq::ApproxEq, fraction_enum::FractionEnum, fraction_exact::FractionExact,
+        fraction_f64::FractionF64,
+    };
+
+    #[test]
+    fn approx_eq() {
+        let x = FractionF64::from(1.0);
+        let y = FractionF64::from(1.0 + 1e-12);
+        assert!(x.approx_eq(&y, &1e-9.into()));
+        assert!(!x.approx_eq(&y, &1e-15.into()));
+        assert!(!x.approx_eq(&y, &0.0.into()));
+        assert!(FractionF64::from(f64::INFINITY).approx_eq(&f64::INFINITY.into(), &0.0.into()));
+        assert!(!FractionF64::from(f64::NAN).approx_eq(&f64::NAN.into(), &1.0.into()));
+
+        let third = FractionExact::from((1, 3));
+        assert!(third.approx_eq(&(333, 1000).into(), &(1, 1000).into()));
+        assert!(!third.approx_eq(&(333, 1000).into(), &(1, 10000).into()));
+
+        let x = FractionEnum::Exact(Rational::from_signeds(1, 3));
+        let y = FractionEnum::Exact(Rational::from_signeds(1, 4));
+        assert!(x.approx_eq(&y, &FractionEnum::Approx(0.1)));
+        assert!(!x.approx_eq(&y, &FractionEnum::Exact(Rational::from_signeds(1, 13))));
+        assert!(!x.approx_eq(&FractionEnum::Approx(1.0 / 3.0), &FractionEnum::Approx(1.0)));
+    }
+}
@@ -4,7 +4,10 @@ use crate::fraction::{
 use anyhow::{Result, anyhow};
 use malachite::{
     Rational,
-    base::{num::conversion::traits::RoundingFrom, rounding_modes::RoundingMode},
+    base::{
+        num::conversion::traits::{ConvertibleFrom, RoundingFrom},
+        rounding_modes::RoundingMode,
+    },
 };
 
 pub trait Approximate {
@@ -48,3 +51,68 @@ impl Approximate for FractionEnum {
         }
     }
 }
+
+/// Returns the value as an f64, rounded in the given direction.
+fn to_f64_with(value: &Rational, mode: RoundingMode) -> Result<f64> {
+    if mode == RoundingMode::Exact && !f64::convertible_from(value) {
+        return Err(anyhow!("{} cannot be represented exactly as an f64", value));
+    }
+    Ok(f64::rounding_from(value, mode).0)
+}
+
+impl FractionExact {
+    /// Returns the value as an f64, rounded with the given mode.
+    /// With `Floor` and `Ceiling`, the result is a guaranteed lower or upper bound of the value; beyond the finite range, they return the largest finite float or an infinity.
+    /// Returns an error if the mode is `Exact` and the value is not representable as an f64.
+    pub fn to_f64_with(&self, mode: RoundingMode) -> Result<f64> {
+        to_f64_with(&self.0, mode)
+    }
+}
+
+impl FractionEnum {
+    /// Returns the value as an f64, rounded with the given mode.
+    /// Approximate values are returned as they are, except that the mode `Exact` never fails for them.
+    /// Returns an error if the value combines exact and approximate arithmetic, or if the mode is `Exact` and an exact value is not representable as an f64.
+    pub fn to_f64_with(&self, mode: RoundingMode) -> Result<f64> {
+        match self {
+            FractionEnum::Exact(f) => to_f64_with(f, mode),
+            FractionEnum::Approx(f) => Ok(*f),
+            FractionEnum::CannotCombineExactAndApprox => {
+                Err(anyhow!("cannot combine approximate and exact arithmetic"))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use malachite::{Rational, base::rounding_modes::RoundingMode};
+
+    use crate::fraction::{fraction_enum::FractionEnum, fraction_exact::FractionExact};
+
+    #[test]
+    fn to_f64_with() {
+        let third = FractionExact::from((1, 3));
+        let lower = third.to_f64_with(RoundingMode::Floor).unwrap();
+        let upper = third.to_f64_with(RoundingMode::Ceiling).unwrap();
+        assert!(lower < upper);
+        assert_eq!(upper, lower.next_up());
+        assert!(Rational::try_from(lower).unwrap() < third.0);
+        assert!(Rational::try_from(upper).unwrap() > third.0);
+        assert!(third.to_f64_with(RoundingMode::Exact).is_err());
+
+        let half = FractionExact::from((1, 2));
+        assert_eq!(half.to_f64_with(RoundingMode::Exact).unwrap(), 0.5);
+        assert_eq!(
+            FractionEnum::Approx(0.1)
+                .to_f64_with(RoundingMode::Floor)
+                .unwrap(),
+            0.1
+        );
+        assert!(
+            FractionEnum::CannotCombineExactAndApprox
+                .to_f64_with(RoundingMode::Nearest)
+                .is_err()
+        );
+    }
+}
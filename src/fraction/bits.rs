@@ -0,0 +1,79 @@
+use malachite::{base::num::logic::traits::SignificantBits, rational::Rational};
+
+use crate::fraction::{fraction_enum::FractionEnum, fraction_exact::FractionExact};
+
+/// Returns the number of bits of the numerator and of the denominator, the sign excluded.
+pub(crate) fn bits(value: &Rational) -> (u64, u64) {
+    (
+        value.numerator_ref().significant_bits(),
+        value.denominator_ref().significant_bits(),
+    )
+}
+
+impl FractionExact {
+    /// Returns the number of bits of the absolute value of the numerator; zero for zero.
+    pub fn numerator_bits(&self) -> u64 {
+        bits(&self.0).0
+    }
+
+    /// Returns the number of bits of the denominator, which is at least one.
+    pub fn denominator_bits(&self) -> u64 {
+        bits(&self.0).1
+    }
+
+    /// Returns the number of bits of the numerator and the denominator together.
+    /// This is a measure for the cost of arithmetic with the value, and grows during iterated exact computations;
+    /// [FractionExact::limit_denominator] bounds it again.
+    pub fn total_bits(&self) -> u64 {
+        let (numerator, denominator) = bits(&self.0);
+        numerator + denominator
+    }
+}
+
+impl FractionEnum {
+    /// Returns the number of bits of the absolute value of the numerator of an exact value.
+    /// Returns None for approximate values, which have a fixed size.
+    pub fn numerator_bits(&self) -> Option<u64> {
+        self.exact_bits().map(|(numerator, _)| numerator)
+    }
+
+    /// Returns the number of bits of the denominator of an exact value.
+    /// Returns None for approximate values, which have a fixed size.
+    pub fn denominator_bits(&self) -> Option<u64> {
+        self.exact_bits().map(|(_, denominator)| denominator)
+    }
+
+    /// Returns the number of bits of the numerator and the denominator of an exact value together.
+    /// Returns None for approximate values, which have a fixed size.
+    pub fn total_bits(&self) -> Option<u64> {
+        self.exact_bits()
+            .map(|(numerator, denominator)| numerator + denominator)
+    }
+
+    fn exact_bits(&self) -> Option<(u64, u64)> {
+        match self {
+            FractionEnum::Exact(f) => Some(bits(f)),
+            FractionEnum::Approx(_) | FractionEnum::CannotCombineExactAndApprox => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use malachite::rational::Rational;
+
+    use crate::fraction::{fraction_enum::FractionEnum, fraction_exact::FractionExact};
+
+    #[test]
+    fn bits() {
+        let f = FractionExact::from((-255, 256));
+        assert_eq!(f.numerator_bits(), 8);
+        assert_eq!(f.denominator_bits(), 9);
+        assert_eq!(f.total_bits(), 17);
+        assert_eq!(FractionExact::from(0).total_bits(), 1);
+
+        let e = FractionEnum::Exact(Rational::from_signeds(3, 4));
+        assert_eq!(e.total_bits(), Some(5));
+        assert_eq!(FractionEnum::Approx(0.75).total_bits(), None);
+    }
+}
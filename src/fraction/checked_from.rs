@@ -0,0 +1,157 @@
+use anyhow::{Result, anyhow};
+use malachite::rational::Rational;
+
+use crate::{
+    exact::is_exact_globally,
+    fraction::{
+        approximate::Approximate, fraction_enum::FractionEnum, fraction_exact::FractionExact,
+        fraction_f64::FractionF64,
+    },
+};
+
+/// A conversion from a primitive or a (numerator, denominator) pair that returns an error instead of panicking or producing a non-finite value.
+///
+/// Exact fractions hold any primitive value exactly, including i128::MIN and u128::MAX.
+/// Approximate fractions are rounded once to the nearest float, also for pairs, rather than dividing two rounded values.
+/// A zero denominator is an error.
+pub trait CheckedFrom<T>: Sized {
+    fn checked_from(value: T) -> Result<Self>;
+}
+
+/// Returns the exact quotient of the pair, or an error if the denominator is zero.
+fn checked_quotient(numerator: Rational, denominator: Rational) -> Result<Rational> {
+    if denominator == 0u32 {
+        return Err(anyhow!("cannot create a fraction with a zero denominator"));
+    }
+    Ok(numerator / denominator)
+}
+
+macro_rules! checked_from_pair {
+    ($t:ident, $u:ident) => {
+        impl CheckedFrom<($t, $u)> for FractionExact {
+            fn checked_from(value: ($t, $u)) -> Result<Self> {
+                Ok(Self(checked_quotient(
+                    Rational::from(value.0),
+                    Rational::from(value.1),
+                )?))
+            }
+        }
+
+        impl CheckedFrom<($t, $u)> for FractionF64 {
+            fn checked_from(value: ($t, $u)) -> Result<Self> {
+                Ok(Self(
+                    checked_quotient(Rational::from(value.0), Rational::from(value.1))?
+                        .approximate()?,
+                ))
+            }
+        }
+
+        impl CheckedFrom<($t, $u)> for FractionEnum {
+            fn checked_from(value: ($t, $u)) -> Result<Self> {
+                if is_exact_globally() {
+                    Ok(FractionEnum::Exact(FractionExact::checked_from(value)?.0))
+                } else {
+                    Ok(FractionEnum::Approx(FractionF64::checked_from(value)?.0))
+                }
+            }
+        }
+    };
+}
+
+macro_rules! checked_from {
+    ($t:ident) => {
+        impl CheckedFrom<$t> for FractionExact {
+            fn checked_from(value: $t) -> Result<Self> {
+                Ok(Self(Rational::from(value)))
+            }
+        }
+
+        impl CheckedFrom<$t> for FractionF64 {
+            fn checked_from(value: $t) -> Result<Self> {
+                Ok(Self(Rational::from(value).approximate()?))
+            }
+        }
+
+        impl CheckedFrom<$t> for FractionEnum {
+            fn checked_from(value: $t) -> Result<Self> {
+                if is_exact_globally() {
+                    Ok(FractionEnum::Exact(Rational::from(value)))
+                } else {
+                    Ok(FractionEnum::Approx(FractionF64::checked_from(value)?.0))
+                }
+            }
+        }
+
+        checked_from_pair!($t, usize);
+        checked_from_pair!($t, u128);
+        checked_from_pair!($t, u64);
+        checked_from_pair!($t, u32);
+        checked_from_pair!($t, u16);
+        checked_from_pair!($t, u8);
+        checked_from_pair!($t, i128);
+        checked_from_pair!($t, i64);
+        checked_from_pair!($t, i32);
+        checked_from_pair!($t, i16);
+        checked_from_pair!($t, i8);
+    };
+}
+
+checked_from!(usize);
+checked_from!(u128);
+checked_from!(u64);
+checked_from!(u32);
+checked_from!(u16);
+checked_from!(u8);
+checked_from!(i128);
+checked_from!(i64);
+checked_from!(i32);
+checked_from!(i16);
+checked_from!(i8);
+
+#[cfg(test)]
+mod tests {
+    use malachite::rational::Rational;
+
+    use crate::{
+        MaybeExact, Signed,
+        fraction::{
+            checked_from::CheckedFrom, fraction_enum::FractionEnum, fraction_exact::FractionExact,
+            fraction_f64::FractionF64,
+        },
+    };
+
+    #[test]
+    fn checked_from_extremes() {
+        let min = FractionExact::checked_from(i128::MIN).unwrap();
+        assert_eq!(min.0, Rational::from(i128::MIN));
+        let max = FractionExact::checked_from(u128::MAX).unwrap();
+        assert_eq!(max.0, Rational::from(u128::MAX));
+
+        //-(i128::MIN) does not fit in an i128, but does in a fraction
+        let x = FractionExact::checked_from((i128::MIN, -1i128)).unwrap();
+        assert_eq!(x.0, Rational::from(u128::MAX / 2 + 1));
+        let x = FractionExact::checked_from((1u8, i128::MIN)).unwrap();
+        assert!(x.is_negative());
+        let x = FractionExact::checked_from((u128::MAX, u128::MAX)).unwrap();
+        assert_eq!(x, FractionExact::from(1));
+
+        let x = FractionF64::checked_from((i128::MIN, -1i128)).unwrap();
+        assert_eq!(x.0, 2f64.powi(127));
+        let x = FractionF64::checked_from((i128::MIN, i128::MAX)).unwrap();
+        assert_eq!(x.0, -1.0);
+        let x = FractionF64::checked_from(u128::MAX).unwrap();
+        assert_eq!(x.0, 2f64.powi(128));
+
+        let x = FractionEnum::checked_from((i64::MIN, -1i8)).unwrap();
+        assert!(x.is_positive());
+        assert!(!x.is_exact() || x.exact().unwrap() == 1u64 << 63);
+    }
+
+    #[test]
+    fn checked_from_zero_denominator() {
+        assert!(FractionExact::checked_from((1u8, 0u8)).is_err());
+        assert!(FractionExact::checked_from((0i128, 0i8)).is_err());
+        assert!(FractionF64::checked_from((i128::MIN, 0u128)).is_err());
+        assert!(FractionEnum::checked_from((1usize, 0i64)).is_err());
+    }
+}
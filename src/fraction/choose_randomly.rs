@@ -2,7 +2,7 @@ use anyhow::{Context, Result, anyhow};
 use malachite::{
     Natural, base::random::Seed, natural::random::random_naturals_less_than, rational::Rational,
 };
-use rand::{Rng, RngCore};
+use rand::Rng;
 
 use crate::{
     ebi_number::{ChooseRandomly, Zero},
@@ -10,6 +10,7 @@ use crate::{
     fraction::{
         fraction_enum::FractionEnum, fraction_exact::FractionExact, fraction_f64::FractionF64,
     },
+    vector::cumulative,
 };
 
 #[cfg(any(
@@ -35,7 +36,10 @@ pub enum FractionRandomCacheEnum {
 impl ChooseRandomly for FractionEnum {
     type Cache = FractionRandomCacheEnum;
 
-    fn choose_randomly(fractions: &Vec<FractionEnum>) -> Result<usize> {
+    fn choose_randomly_with<R: Rng + ?Sized>(
+        fractions: &Vec<FractionEnum>,
+        rng: &mut R,
+    ) -> Result<usize> {
         if fractions.is_empty() {
             return Err(anyhow!("cannot take an element of an empty list"));
         }
@@ -56,8 +60,6 @@ impl ChooseRandomly for FractionEnum {
             true
         });
 
-        let mut rng = rand::rng();
-
         //select a random value
         let rand_val = if sum.is_exact() {
             let mut buf = [0u8; 32];
@@ -156,14 +158,16 @@ impl ChooseRandomly for FractionEnum {
         }
     }
 
-    fn choose_randomly_cached(cache: &FractionRandomCacheEnum) -> usize
+    fn choose_randomly_cached_with<R: Rng + ?Sized>(
+        cache: &FractionRandomCacheEnum,
+        rng: &mut R,
+    ) -> usize
     where
         Self: Sized,
     {
         match cache {
             FractionRandomCacheEnum::Exact(cumulative_probabilities, highest_denom) => {
                 //select a random value
-                let mut rng = rand::rng();
                 let mut buf = [0u8; 32];
                 rng.fill_bytes(&mut buf);
                 let seed = Seed::from_bytes(buf);
@@ -185,7 +189,6 @@ impl ChooseRandomly for FractionEnum {
             }
             FractionRandomCacheEnum::Approx(cumulative_probabilities) => {
                 //select a random value
-                let mut rng = rand::rng();
                 let rand_val = rng.random_range(0.0..=*cumulative_probabilities.last().unwrap());
 
                 match cumulative_probabilities.binary_search_by(|probe| probe.total_cmp(&rand_val))
@@ -205,7 +208,10 @@ pub struct FractionRandomCacheExact {
 impl ChooseRandomly for FractionExact {
     type Cache = FractionRandomCacheExact;
 
-    fn choose_randomly(fractions: &Vec<FractionExact>) -> Result<usize> {
+    fn choose_randomly_with<R: Rng + ?Sized>(
+        fractions: &Vec<FractionExact>,
+        rng: &mut R,
+    ) -> Result<usize> {
         if fractions.is_empty() {
             return Err(anyhow!("cannot take an element of an empty list"));
         }
@@ -224,7 +230,6 @@ impl ChooseRandomly for FractionExact {
         });
 
         //select a random value
-        let mut rng = rand::rng();
         let mut buf = [0u8; 32];
         rng.fill_bytes(&mut buf);
         let seed = Seed::from_bytes(buf);
@@ -256,38 +261,40 @@ impl ChooseRandomly for FractionExact {
     }
 
     fn choose_randomly_create_cache<'a>(
-        mut fractions: impl Iterator<Item = &'a Self>,
+        fractions: impl Iterator<Item = &'a Self>,
     ) -> Result<FractionRandomCacheExact>
     where
         Self: Sized,
         Self: 'a,
     {
-        if let Some(first) = fractions.next() {
-            let mut cumulative_probabilities = vec![first.clone()];
-            let mut highest_denom = first.0.to_denominator();
-
-            while let Some(fraction) = fractions.next() {
-                highest_denom = highest_denom.max(fraction.0.to_denominator());
-
-                cumulative_probabilities.push(fraction + cumulative_probabilities.last().unwrap());
-            }
-            let highest_denom = highest_denom.clone();
-
-            Ok(FractionRandomCacheExact {
-                cumulative_probabilities,
-                highest_denom,
-            })
-        } else {
-            Err(anyhow!("cannot take an element of an empty list"))
+        let mut highest_denom = Natural::from(1u32);
+        let cumulative_probabilities = cumulative(
+            fractions.inspect(|fraction| {
+                let denom = fraction.0.to_denominator();
+                if denom > highest_denom {
+                    highest_denom = denom;
+                }
+            }),
+            |sum, fraction| sum + fraction,
+        );
+        if cumulative_probabilities.is_empty() {
+            return Err(anyhow!("cannot take an element of an empty list"));
         }
+
+        Ok(FractionRandomCacheExact {
+            cumulative_probabilities,
+            highest_denom,
+        })
     }
 
-    fn choose_randomly_cached(cache: &FractionRandomCacheExact) -> usize
+    fn choose_randomly_cached_with<R: Rng + ?Sized>(
+        cache: &FractionRandomCacheExact,
+        rng: &mut R,
+    ) -> usize
     where
         Self: Sized,
     {
         //select a random value
-        let mut rng = rand::rng();
         let mut buf = [0u8; 32];
         rng.fill_bytes(&mut buf);
         let seed = Seed::from_bytes(buf);
@@ -316,7 +323,10 @@ pub struct FractionRandomCacheF64 {
 impl ChooseRandomly for FractionF64 {
     type Cache = FractionRandomCacheF64;
 
-    fn choose_randomly(fractions: &Vec<FractionF64>) -> Result<usize> {
+    fn choose_randomly_with<R: Rng + ?Sized>(
+        fractions: &Vec<FractionF64>,
+        rng: &mut R,
+    ) -> Result<usize> {
         if fractions.is_empty() {
             return Err(anyhow!("cannot take an element of an empty list"));
         }
@@ -332,7 +342,6 @@ impl ChooseRandomly for FractionF64 {
         });
 
         //select a random value
-        let mut rng = rand::rng();
         let rand_val = FractionF64(rng.random_range(0.0..=1.0));
 
         let mut cum_prob = FractionF64::zero();
@@ -346,33 +355,30 @@ impl ChooseRandomly for FractionF64 {
     }
 
     fn choose_randomly_create_cache<'a>(
-        mut fractions: impl Iterator<Item = &'a Self>,
+        fractions: impl Iterator<Item = &'a Self>,
     ) -> Result<FractionRandomCacheF64>
     where
         Self: Sized,
         Self: 'a,
     {
-        if let Some(first) = fractions.next() {
-            let mut cumulative_probabilities = vec![*first];
-
-            while let Some(fraction) = fractions.next() {
-                cumulative_probabilities.push(fraction + cumulative_probabilities.last().unwrap());
-            }
-
-            Ok(FractionRandomCacheF64 {
-                cumulative_probabilities,
-            })
-        } else {
-            Err(anyhow!("cannot take an element of an empty list"))
+        let cumulative_probabilities = cumulative(fractions, |sum, fraction| sum + fraction);
+        if cumulative_probabilities.is_empty() {
+            return Err(anyhow!("cannot take an element of an empty list"));
         }
+
+        Ok(FractionRandomCacheF64 {
+            cumulative_probabilities,
+        })
     }
 
-    fn choose_randomly_cached(cache: &FractionRandomCacheF64) -> usize
+    fn choose_randomly_cached_with<R: Rng + ?Sized>(
+        cache: &FractionRandomCacheF64,
+        rng: &mut R,
+    ) -> usize
     where
         Self: Sized,
     {
         //select a random value
-        let mut rng = rand::rng();
         let rand_val = FractionF64::from(
             rng.random_range(
                 0.0..=*cache
@@ -389,3 +395,67 @@ impl ChooseRandomly for FractionF64 {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use crate::{
+        ebi_number::ChooseRandomly,
+        fraction::{
+            fraction_enum::FractionEnum, fraction_exact::FractionExact, fraction_f64::FractionF64,
+        },
+    };
+
+    fn draws<T: ChooseRandomly>(fractions: &Vec<T>, seed: u64) -> Vec<usize> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let cache = T::choose_randomly_create_cache(fractions.iter()).unwrap();
+        (0..20)
+            .flat_map(|_| {
+                [
+                    T::choose_randomly_with(fractions, &mut rng).unwrap(),
+                    T::choose_randomly_cached_with(&cache, &mut rng),
+                ]
+            })
+            .collect()
+    }
+
+    #[test]
+    fn choose_randomly_reproducible() {
+        let exact = vec![FractionExact::from((1, 3)), FractionExact::from((2, 3))];
+        assert_eq!(draws(&exact, 7), draws(&exact, 7));
+        assert!(draws(&exact, 7).iter().all(|i| *i < 2));
+
+        let approx = vec![FractionF64::from(0.25), FractionF64::from(0.75)];
+        assert_eq!(draws(&approx, 7), draws(&approx, 7));
+
+        let fractions = vec![FractionEnum::from((1, 4)), FractionEnum::from((3, 4))];
+        assert_eq!(draws(&fractions, 3), draws(&fractions, 3));
+    }
+
+    #[test]
+    fn choose_randomly_default_with() {
+        //a type that only implements the methods of the original trait
+        struct First;
+
+        impl ChooseRandomly for First {
+            type Cache = ();
+
+            fn choose_randomly(_: &Vec<Self>) -> anyhow::Result<usize> {
+                Ok(0)
+            }
+
+            fn choose_randomly_create_cache<'a>(
+                _: impl Iterator<Item = &'a Self>,
+            ) -> anyhow::Result<Self::Cache> {
+                Ok(())
+            }
+
+            fn choose_randomly_cached(_: &Self::Cache) -> usize {
+                0
+            }
+        }
+
+        assert_eq!(draws(&vec![First, First], 1), vec![0; 40]);
+    }
+}
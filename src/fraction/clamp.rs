@@ -0,0 +1,166 @@
+use anyhow::{Result, anyhow};
+use malachite::{
+    base::num::basic::traits::{One as MOne, Zero as MZero},
+    rational::Rational,
+};
+use std::cmp::Ordering;
+
+use crate::{
+    One, Zero,
+    fraction::{
+        fraction_enum::FractionEnum, fraction_exact::FractionExact, fraction_f64::FractionF64,
+    },
+    try_ops::TryCmp,
+};
+
+/// Restricting values to an interval.
+pub trait Clamp: Sized {
+    /// Returns the value restricted to [`lower`, `upper`].
+    /// Returns an error if `lower` exceeds `upper`, or if the values cannot be compared.
+    ///
+    /// Named differently from [Ord::clamp], which takes its arguments by value and panics instead.
+    fn clamp_between(&self, lower: &Self, upper: &Self) -> Result<Self>;
+
+    /// Returns the value restricted to [0, 1], for instance to repair a probability such as 1.0000000002 that was computed with floats.
+    /// Approximate values that are considered zero or one (see [EPSILON](crate::fraction::fraction::EPSILON)) become exactly zero or one.
+    fn clamp_to_unit_interval(&self) -> Self;
+}
+
+/// Returns the smallest of the values; of equal values, the first.
+/// Returns an error if the values cannot be compared, such as an exact and an approximate [FractionEnum].
+pub fn min<F: TryCmp + Clone>(a: &F, b: &F) -> Result<F> {
+    Ok(if b.try_cmp(a)? == Ordering::Less {
+        b.clone()
+    } else {
+        a.clone()
+    })
+}
+
+/// Returns the largest of the values; of equal values, the first.
+/// Returns an error if the values cannot be compared, such as an exact and an approximate [FractionEnum].
+pub fn max<F: TryCmp + Clone>(a: &F, b: &F) -> Result<F> {
+    Ok(if b.try_cmp(a)? == Ordering::Greater {
+        b.clone()
+    } else {
+        a.clone()
+    })
+}
+
+fn clamp_between<F: TryCmp + Clone + std::fmt::Display>(
+    value: &F,
+    lower: &F,
+    upper: &F,
+) -> Result<F> {
+    if lower.try_cmp(upper)? == Ordering::Greater {
+        return Err(anyhow!(
+            "cannot clamp to [{}, {}], as its lower bound exceeds its upper bound",
+            lower,
+            upper
+        ));
+    }
+    min(&max(value, lower)?, upper)
+}
+
+fn clamp_f64_to_unit_interval(value: f64) -> f64 {
+    if value < 0.0 || value.is_zero() {
+        0.0
+    } else if value > 1.0 || value.is_one() {
+        1.0
+    } else {
+        value
+    }
+}
+
+fn clamp_rational_to_unit_interval(value: &Rational) -> Rational {
+    if *value < Rational::ZERO {
+        Rational::ZERO
+    } else if *value > Rational::ONE {
+        Rational::ONE
+    } else {
+        value.clone()
+    }
+}
+
+impl Clamp for FractionF64 {
+    fn clamp_between(&self, lower: &Self, upper: &Self) -> Result<Self> {
+        clamp_between(self, lower, upper)
+    }
+
+    fn clamp_to_unit_interval(&self) -> Self {
+        Self(clamp_f64_to_unit_interval(self.0))
+    }
+}
+
+impl Clamp for FractionExact {
+    fn clamp_between(&self, lower: &Self, upper: &Self) -> Result<Self> {
+        clamp_between(self, lower, upper)
+    }
+
+    fn clamp_to_unit_interval(&self) -> Self {
+        Self(clamp_rational_to_unit_interval(&self.0))
+    }
+}
+
+impl Clamp for FractionEnum {
+    fn clamp_between(&self, lower: &Self, upper: &Self) -> Result<Self> {
+        clamp_between(self, lower, upper)
+    }
+
+    fn clamp_to_unit_interval(&self) -> Self {
+        match self {
+            FractionEnum::Exact(f) => FractionEnum::Exact(clamp_rational_to_unit_interval(f)),
+            FractionEnum::Approx(f) => FractionEnum::Approx(clamp_f64_to_unit_interval(*f)),
+            FractionEnum::CannotCombineExactAndApprox => FractionEnum::CannotCombineExactAndApprox,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use malachite::rational::Rational;
+
+    use crate::fraction::{
+        clamp::{Clamp, max, min},
+        fraction_enum::FractionEnum,
+        fraction_exact::FractionExact,
+        fraction_f64::FractionF64,
+    };
+
+    #[test]
+    fn clamp() {
+        let lower = FractionExact::from((1, 4));
+        let upper = FractionExact::from((3, 4));
+        assert_eq!(
+            FractionExact::from(1)
+                .clamp_between(&lower, &upper)
+                .unwrap(),
+            upper
+        );
+        assert_eq!(
+            FractionExact::from((1, 2))
+                .clamp_between(&lower, &upper)
+                .unwrap(),
+            FractionExact::from((1, 2))
+        );
+        assert!(lower.clamp_between(&upper, &lower).is_err());
+
+        assert_eq!(
+            FractionF64::from(1.0000000002).clamp_to_unit_interval(),
+            FractionF64::from(1.0)
+        );
+        assert_eq!(FractionF64::from(1e-15).clamp_to_unit_interval().0, 0.0);
+        assert_eq!(
+            FractionExact::from((-1, 3)).clamp_to_unit_interval(),
+            FractionExact::from(0)
+        );
+
+        let exact = FractionEnum::Exact(Rational::from_signeds(1, 3));
+        let approx = FractionEnum::Approx(0.5);
+        assert_eq!(min(&exact, &exact).unwrap(), exact);
+        assert!(max(&exact, &approx).is_err());
+        assert_eq!(
+            max(&FractionExact::from(2), &FractionExact::from(3)).unwrap(),
+            FractionExact::from(3)
+        );
+    }
+}
@@ -0,0 +1,337 @@
+use anyhow::{Result, anyhow};
+use malachite::{
+    Integer, Natural,
+    base::{
+        num::{
+            arithmetic::traits::{DivExact, Gcd, UnsignedAbs},
+            basic::traits::Zero,
+        },
+        random::Seed,
+    },
+    natural::random::random_naturals_less_than,
+    rational::Rational,
+};
+use rand::Rng;
+use std::ops::Mul;
+
+use crate::{
+    ebi_matrix::EbiMatrix,
+    fraction::{fraction_exact::FractionExact, gcd::over_common_denominator},
+    matrix::fraction_matrix_exact::FractionMatrixExact,
+};
+
+/// A vector of exact fractions that share a single denominator.
+///
+/// Probability vectors obtained by normalising counts naturally have this form.
+/// Keeping integer numerators avoids a gcd computation per element in sums, dot products, sampling and matrix products.
+/// The vector is kept in lowest terms: the denominator is positive and shares no factor with all of the numerators.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CommonDenomVector {
+    numerators: Vec<Integer>,
+    denominator: Natural,
+}
+
+impl CommonDenomVector {
+    /// Creates a vector from numerators and their shared denominator.
+    pub fn new(numerators: Vec<Integer>, denominator: Natural) -> Result<Self> {
+        if denominator == 0u32 {
+            return Err(anyhow!("denominator cannot be zero"));
+        }
+        let mut result = Self {
+            numerators,
+            denominator,
+        };
+        result.reduce();
+        Ok(result)
+    }
+
+    /// Creates the probability vector of the given counts: each count divided by the sum of all counts.
+    pub fn from_counts(counts: Vec<Natural>) -> Result<Self> {
+        let denominator: Natural = counts.iter().sum();
+        if denominator == 0u32 {
+            return Err(anyhow!("sum of counts is zero"));
+        }
+        Self::new(counts.into_iter().map(Integer::from).collect(), denominator)
+    }
+
+    pub fn len(&self) -> usize {
+        self.numerators.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.numerators.is_empty()
+    }
+
+    pub fn numerators(&self) -> &[Integer] {
+        &self.numerators
+    }
+
+    pub fn denominator(&self) -> &Natural {
+        &self.denominator
+    }
+
+    /// Returns the fraction at the given index, if it exists.
+    pub fn get(&self, index: usize) -> Option<FractionExact> {
+        Some(FractionExact(Rational::from_integers(
+            self.numerators.get(index)?.clone(),
+            Integer::from(&self.denominator),
+        )))
+    }
+
+    /// Returns the sum of all elements.
+    pub fn sum(&self) -> FractionExact {
+        FractionExact(Rational::from_integers(
+            self.numerators.iter().sum(),
+            Integer::from(&self.denominator),
+        ))
+    }
+
+    /// Scales the vector such that its elements sum to one.
+    pub fn normalise(&mut self) -> Result<()> {
+        let mut total: Integer = self.numerators.iter().sum();
+        if total == 0u32 {
+            return Err(anyhow!("sum of fractions is zero"));
+        }
+        if total < 0u32 {
+            self.numerators.iter_mut().for_each(|n| *n = -&*n);
+            total = -total;
+        }
+        self.denominator = total.unsigned_abs();
+        self.reduce();
+        Ok(())
+    }
+
+    /// Returns the dot product of the two vectors.
+    pub fn dot(&self, other: &Self) -> Result<FractionExact> {
+        if self.len() != other.len() {
+            return Err(anyhow!(
+                "cannot take the dot product of vectors of sizes {} and {}",
+                self.len(),
+                other.len()
+            ));
+        }
+        let numerator: Integer = self
+            .numerators
+            .iter()
+            .zip(other.numerators.iter())
+            .map(|(a, b)| a * b)
+            .sum();
+        Ok(FractionExact(Rational::from_integers(
+            numerator,
+            Integer::from(&self.denominator * &other.denominator),
+        )))
+    }
+
+    /// Returns a random index, with a likelihood proportional to the value at that index.
+    /// All values need to be non-negative, and at least one needs to be positive.
+    pub fn choose_randomly(&self) -> Result<usize> {
+        self.choose_randomly_with(&mut rand::rng())
+    }
+
+    /// As [CommonDenomVector::choose_randomly], but draws from the given random number generator.
+    pub fn choose_randomly_with<R: Rng + ?Sized>(&self, rng: &mut R) -> Result<usize> {
+        if self.numerators.iter().any(|n| *n < 0u32) {
+            return Err(anyhow!("cannot choose randomly with negative fractions"));
+        }
+        let total: Integer = self.numerators.iter().sum();
+        if total == 0u32 {
+            return Err(anyhow!("sum of fractions is zero"));
+        }
+
+        //the shared denominator cancels out: draw a random numerator below the total
+        let mut buf = [0u8; 32];
+        rng.fill_bytes(&mut buf);
+        let seed = Seed::from_bytes(buf);
+        let rand_val = Integer::from(
+            random_naturals_less_than(seed, total.unsigned_abs())
+                .next()
+                .unwrap(),
+        );
+
+        let mut cum = Integer::ZERO;
+        for (index, numerator) in self.numerators.iter().enumerate() {
+            cum += numerator;
+            if rand_val < cum {
+                return Ok(index);
+            }
+        }
+        Ok(self.numerators.len() - 1)
+    }
+
+    /// Divides out the greatest common divisor of the denominator and all numerators.
+    fn reduce(&mut self) {
+        let gcd = self
+            .numerators
+            .iter()
+            .fold(self.denominator.clone(), |gcd, n| {
+                gcd.gcd(n.unsigned_abs_ref())
+            });
+        if gcd > 1u32 {
+            let gcd_integer = Integer::from(&gcd);
+            self.numerators
+                .iter_mut()
+                .for_each(|n| *n = (&*n).div_exact(&gcd_integer));
+            self.denominator = (&self.denominator).div_exact(&gcd);
+        }
+    }
+}
+
+impl From<&[FractionExact]> for CommonDenomVector {
+    fn from(value: &[FractionExact]) -> Self {
+        let (numerators, denominator) = over_common_denominator(value.iter().map(|f| &f.0));
+        Self {
+            numerators,
+            denominator,
+        }
+    }
+}
+
+impl From<Vec<FractionExact>> for CommonDenomVector {
+    fn from(value: Vec<FractionExact>) -> Self {
+        Self::from(value.as_slice())
+    }
+}
+
+impl From<&CommonDenomVector> for Vec<FractionExact> {
+    fn from(value: &CommonDenomVector) -> Self {
+        let denominator = Integer::from(&value.denominator);
+        value
+            .numerators
+            .iter()
+            .map(|n| FractionExact(Rational::from_integers_ref(n, &denominator)))
+            .collect()
+    }
+}
+
+impl From<CommonDenomVector> for Vec<FractionExact> {
+    fn from(value: CommonDenomVector) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl Mul<&FractionMatrixExact> for &CommonDenomVector {
+    type Output = Result<Vec<FractionExact>>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: &FractionMatrixExact) -> Self::Output {
+        if self.len() != rhs.number_of_rows() {
+            return Err(anyhow!(
+                "cannot multiply a vector of size {} with a matrix of size {}x{}",
+                self.len(),
+                rhs.number_of_rows(),
+                rhs.number_of_columns(),
+            ));
+        }
+
+        //accumulate with integer weights, and divide by the shared denominator once per column
+        let mut result = vec![Rational::ZERO; rhs.number_of_columns()];
+        for (row, numerator) in self.numerators.iter().enumerate() {
+            if *numerator == 0u32 {
+                continue;
+            }
+            let numerator = Rational::from(numerator);
+            for (column, sum) in result.iter_mut().enumerate() {
+                *sum += &rhs.values[rhs.index(row, column)] * &numerator;
+            }
+        }
+        let denominator = Rational::from(&self.denominator);
+        Ok(result
+            .into_iter()
+            .map(|f| FractionExact(f / &denominator))
+            .collect())
+    }
+}
+
+impl Mul<&CommonDenomVector> for &FractionMatrixExact {
+    type Output = Result<Vec<FractionExact>>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: &CommonDenomVector) -> Self::Output {
+        if self.number_of_columns() != rhs.len() {
+            return Err(anyhow!(
+                "cannot multiply matrix of size {}x{} with a vector of size {}",
+                self.number_of_rows(),
+                self.number_of_columns(),
+                rhs.len(),
+            ));
+        }
+
+        let numerators = rhs
+            .numerators
+            .iter()
+            .map(Rational::from)
+            .collect::<Vec<_>>();
+        let denominator = Rational::from(&rhs.denominator);
+        Ok((0..self.number_of_rows())
+            .map(|row| {
+                let mut sum = Rational::ZERO;
+                for (column, numerator) in numerators.iter().enumerate() {
+                    if *numerator != 0u32 {
+                        sum += &self.values[self.index(row, column)] * numerator;
+                    }
+                }
+                FractionExact(sum / &denominator)
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use malachite::Natural;
+
+    use crate::{
+        fraction::{common_denom_vector::CommonDenomVector, fraction_exact::FractionExact},
+        matrix::fraction_matrix_exact::FractionMatrixExact,
+    };
+
+    #[test]
+    fn common_denom_round_trip() {
+        let v = vec![
+            FractionExact::from((1, 6)),
+            FractionExact::from((-1, 4)),
+            FractionExact::from(2),
+        ];
+        let c = CommonDenomVector::from(v.clone());
+        assert_eq!(*c.denominator(), Natural::from(12u32));
+        assert_eq!(c.get(1), Some(FractionExact::from((-1, 4))));
+        assert_eq!(Vec::<FractionExact>::from(c), v);
+    }
+
+    #[test]
+    fn common_denom_counts() {
+        let mut c = CommonDenomVector::from_counts(vec![
+            Natural::from(2u32),
+            Natural::from(4u32),
+            Natural::from(0u32),
+        ])
+        .unwrap();
+        assert_eq!(*c.denominator(), Natural::from(3u32));
+        assert_eq!(c.sum(), FractionExact::from(1));
+        assert!(c.choose_randomly().unwrap() < 2);
+
+        let d = CommonDenomVector::from(vec![
+            FractionExact::from((1, 2)),
+            FractionExact::from((1, 2)),
+            FractionExact::from(3),
+        ]);
+        assert_eq!(c.dot(&d).unwrap(), FractionExact::from((1, 2)));
+
+        c.normalise().unwrap();
+        assert_eq!(c.sum(), FractionExact::from(1));
+        assert!(CommonDenomVector::from_counts(vec![Natural::from(0u32)]).is_err());
+    }
+
+    #[test]
+    fn common_denom_mul() {
+        let m: FractionMatrixExact =
+            vec![vec![1.into(), (1, 3).into()], vec![(2, 5).into(), 0.into()]]
+                .try_into()
+                .unwrap();
+        let v = vec![FractionExact::from((1, 4)), FractionExact::from((3, 4))];
+        let c = CommonDenomVector::from(v.clone());
+
+        assert_eq!((&c * &m).unwrap(), (&v * &m).unwrap());
+        assert_eq!((&m * &c).unwrap(), (&m * &v).unwrap());
+    }
+}
@@ -0,0 +1,105 @@
+use anyhow::{Result, anyhow};
+use malachite::{
+    Integer,
+    base::num::arithmetic::traits::UnsignedAbs,
+    rational::{
+        Rational,
+        conversion::traits::{ContinuedFraction, Convergents},
+    },
+};
+
+use crate::fraction::fraction_exact::FractionExact;
+
+impl FractionExact {
+    /// Returns the coefficients of the continued fraction of this value: its floor, followed by positive integers.
+    /// The expansion of a fraction is finite, and its last coefficient is at least 2, unless the fraction is an integer.
+    /// For instance, 415/93 = 4 + 1/(2 + 1/(6 + 1/7)) yields 4, 2, 6, 7.
+    pub fn continued_fraction(&self) -> impl Iterator<Item = Integer> {
+        let (floor, coefficients) = (&self.0).continued_fraction();
+        std::iter::once(floor).chain(coefficients.map(Integer::from))
+    }
+
+    /// Returns the convergents of the continued fraction of this value: the fractions obtained by truncating the continued fraction after each coefficient.
+    /// Each convergent is closer to the value than the previous ones, making them the best rational approximations for their denominators; the last convergent is the value itself.
+    pub fn convergents(&self) -> impl Iterator<Item = FractionExact> {
+        (&self.0).convergents().map(FractionExact)
+    }
+
+    /// Returns the value of the continued fraction with the given coefficients, of which the first is the integer part and the others need to be positive.
+    /// Returns an error if there are no coefficients, or if any coefficient but the first is not positive.
+    pub fn from_continued_fraction(
+        coefficients: impl IntoIterator<Item = Integer>,
+    ) -> Result<Self> {
+        let mut coefficients = coefficients.into_iter();
+        let floor = coefficients
+            .next()
+            .ok_or_else(|| anyhow!("a continued fraction needs at least one coefficient"))?;
+        let coefficients = coefficients
+            .map(|coefficient| {
+                if coefficient > 0u32 {
+                    Ok(coefficient.unsigned_abs())
+                } else {
+                    Err(anyhow!(
+                        "coefficient {} of a continued fraction is not positive",
+                        coefficient
+                    ))
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self(Rational::from_continued_fraction(
+            floor,
+            coefficients.into_iter(),
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use malachite::Integer;
+
+    use crate::fraction::fraction_exact::FractionExact;
+
+    #[test]
+    fn continued_fraction() {
+        let x = FractionExact::from((415, 93));
+        let coefficients = x.continued_fraction().collect::<Vec<_>>();
+        assert_eq!(
+            coefficients,
+            vec![
+                Integer::from(4),
+                Integer::from(2),
+                Integer::from(6),
+                Integer::from(7)
+            ]
+        );
+        assert_eq!(
+            FractionExact::from_continued_fraction(coefficients).unwrap(),
+            x
+        );
+        assert_eq!(
+            x.convergents().collect::<Vec<_>>(),
+            vec![
+                FractionExact::from(4),
+                FractionExact::from((9, 2)),
+                FractionExact::from((58, 13)),
+                x
+            ]
+        );
+
+        let y = FractionExact::from((-7, 3));
+        assert_eq!(
+            y.continued_fraction().collect::<Vec<_>>(),
+            vec![Integer::from(-3), Integer::from(1), Integer::from(2)]
+        );
+        assert_eq!(
+            FractionExact::from_continued_fraction(y.continued_fraction()).unwrap(),
+            y
+        );
+
+        assert!(FractionExact::from_continued_fraction(vec![]).is_err());
+        assert!(
+            FractionExact::from_continued_fraction(vec![Integer::from(1), Integer::from(0)])
+                .is_err()
+        );
+    }
+}
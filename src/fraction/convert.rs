@@ -0,0 +1,215 @@
+//! Conversions between the fraction backends.
+//!
+//! Exact values are rounded to the nearest float, and floats are converted to the exact value of their binary representation,
+//! such that converting a float to an exact value and back yields the same float.
+//! A [FractionEnum] converts into the backend of its variant.
+
+use anyhow::{Error, Result, anyhow};
+
+use crate::{
+    exact::is_exact_globally,
+    fraction::{
+        exact::{float_to_rational, rational_to_f64},
+        fraction_enum::FractionEnum,
+        fraction_exact::FractionExact,
+        fraction_f64::FractionF64,
+    },
+};
+
+/// Rounds to the nearest float; beyond the finite range, to an infinity.
+impl From<&FractionExact> for FractionF64 {
+    fn from(value: &FractionExact) -> Self {
+        Self(rational_to_f64(&value.0))
+    }
+}
+
+impl From<FractionExact> for FractionF64 {
+    fn from(value: FractionExact) -> Self {
+        Self::from(&value)
+    }
+}
+
+/// Converts the float to the exact (dyadic) value it represents, such as 3602879701896397/36028797018963968 for 0.1.
+/// Returns an error for infinite and NaN values.
+impl TryFrom<&FractionF64> for FractionExact {
+    type Error = Error;
+
+    fn try_from(value: &FractionF64) -> Result<Self> {
+        Self::try_from(value.0)
+    }
+}
+
+impl TryFrom<FractionF64> for FractionExact {
+    type Error = Error;
+
+    fn try_from(value: FractionF64) -> Result<Self> {
+        Self::try_from(&value)
+    }
+}
+
+/// Converts the float to the exact (dyadic) value it represents, which is both faster and more precise than parsing its decimal representation.
+/// Returns an error for infinite and NaN values.
+impl TryFrom<f64> for FractionExact {
+    type Error = Error;
+
+    fn try_from(value: f64) -> Result<Self> {
+        Ok(Self(float_to_rational(value)?))
+    }
+}
+
+/// In exact mode, converts the float to the exact (dyadic) value it represents; in approximate mode, keeps the float.
+/// Returns an error for infinite and NaN values in both modes.
+impl TryFrom<f64> for FractionEnum {
+    type Error = Error;
+
+    fn try_from(value: f64) -> Result<Self> {
+        if is_exact_globally() {
+            Ok(FractionEnum::Exact(float_to_rational(value)?))
+        } else if value.is_finite() {
+            Ok(FractionEnum::Approx(value))
+        } else {
+            Err(anyhow!("{} cannot be represented as a fraction", value))
+        }
+    }
+}
+
+/// Keeps the value exact, regardless of the global arithmetic mode.
+impl From<FractionExact> for FractionEnum {
+    fn from(value: FractionExact) -> Self {
+        FractionEnum::Exact(value.0)
+    }
+}
+
+impl From<&FractionExact> for FractionEnum {
+    fn from(value: &FractionExact) -> Self {
+        FractionEnum::Exact(value.0.clone())
+    }
+}
+
+/// Keeps the value approximate, regardless of the global arithmetic mode.
+impl From<FractionF64> for FractionEnum {
+    fn from(value: FractionF64) -> Self {
+        FractionEnum::Approx(value.0)
+    }
+}
+
+impl From<&FractionF64> for FractionEnum {
+    fn from(value: &FractionF64) -> Self {
+        FractionEnum::Approx(value.0)
+    }
+}
+
+/// Approximate values are converted to the exact value of their float.
+/// Returns an error for infinite and NaN values, and if the value combines exact and approximate arithmetic.
+impl TryFrom<&FractionEnum> for FractionExact {
+    type Error = Error;
+
+    fn try_from(value: &FractionEnum) -> Result<Self> {
+        match value {
+            FractionEnum::Exact(f) => Ok(Self(f.clone())),
+            FractionEnum::Approx(f) => Ok(Self(float_to_rational(*f)?)),
+            FractionEnum::CannotCombineExactAndApprox => {
+                Err(anyhow!("cannot combine exact and approximate arithmetic"))
+            }
+        }
+    }
+}
+
+impl TryFrom<FractionEnum> for FractionExact {
+    type Error = Error;
+
+    fn try_from(value: FractionEnum) -> Result<Self> {
+        match value {
+            FractionEnum::Exact(f) => Ok(Self(f)),
+            _ => Self::try_from(&value),
+        }
+    }
+}
+
+/// Exact values are rounded to the nearest float.
+/// Returns an error if the value combines exact and approximate arithmetic.
+impl TryFrom<&FractionEnum> for FractionF64 {
+    type Error = Error;
+
+    fn try_from(value: &FractionEnum) -> Result<Self> {
+        match value {
+            FractionEnum::Exact(f) => Ok(Self(rational_to_f64(f))),
+            FractionEnum::Approx(f) => Ok(Self(*f)),
+            FractionEnum::CannotCombineExactAndApprox => {
+                Err(anyhow!("cannot combine exact and approximate arithmetic"))
+            }
+        }
+    }
+}
+
+impl TryFrom<FractionEnum> for FractionF64 {
+    type Error = Error;
+
+    fn try_from(value: FractionEnum) -> Result<Self> {
+        Self::try_from(&value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use malachite::rational::Rational;
+
+    use crate::fraction::{
+        approximate::Approximate, fraction_enum::FractionEnum, fraction_exact::FractionExact,
+        fraction_f64::FractionF64,
+    };
+
+    #[test]
+    fn convert() {
+        let third = FractionExact::from((1, 3));
+        assert_eq!(FractionF64::from(&third), FractionF64::from(1.0 / 3.0));
+
+        //floats convert to their exact binary value, and back to the same float
+        let tenth = FractionExact::try_from(FractionF64::from(0.1)).unwrap();
+        assert_ne!(tenth, FractionExact::from((1, 10)));
+        assert_eq!(
+            tenth,
+            FractionExact::from((3602879701896397u64, 36028797018963968u64))
+        );
+        assert_eq!(FractionF64::from(tenth), FractionF64::from(0.1));
+        assert!(FractionExact::try_from(FractionF64::from(f64::NAN)).is_err());
+
+        let e = FractionEnum::from(third.clone());
+        assert_eq!(e, FractionEnum::Exact(Rational::from_signeds(1, 3)));
+        assert_eq!(FractionExact::try_from(&e).unwrap(), third);
+        assert_eq!(
+            FractionF64::try_from(e).unwrap(),
+            FractionF64::from(1.0 / 3.0)
+        );
+        assert_eq!(
+            FractionEnum::from(FractionF64::from(0.5)),
+            FractionEnum::Approx(0.5)
+        );
+        assert_eq!(
+            FractionExact::try_from(FractionEnum::Approx(0.5)).unwrap(),
+            FractionExact::from((1, 2))
+        );
+        assert!(FractionF64::try_from(FractionEnum::CannotCombineExactAndApprox).is_err());
+    }
+
+    #[test]
+    fn try_from_f64() {
+        assert_eq!(
+            FractionExact::try_from(0.375).unwrap(),
+            FractionExact::from((3, 8))
+        );
+        assert_eq!(
+            FractionExact::try_from(-1e-300)
+                .unwrap()
+                .approximate()
+                .unwrap(),
+            -1e-300
+        );
+        assert!(FractionExact::try_from(f64::INFINITY).is_err());
+        assert!(FractionExact::try_from(f64::NAN).is_err());
+
+        let e = FractionEnum::try_from(0.25).unwrap();
+        assert_eq!(e, FractionEnum::from((1, 4)));
+        assert!(FractionEnum::try_from(f64::NEG_INFINITY).is_err());
+    }
+}
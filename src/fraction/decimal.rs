@@ -0,0 +1,331 @@
+use anyhow::{Result, anyhow};
+#[cfg(feature = "bigdecimal")]
+use bigdecimal::{
+    BigDecimal,
+    num_bigint::{BigInt, BigUint, Sign},
+};
+use malachite::{
+    Integer, Natural,
+    base::{
+        num::{
+            arithmetic::traits::Pow,
+            conversion::traits::{IsInteger, RoundingFrom},
+        },
+        rounding_modes::RoundingMode,
+    },
+    rational::Rational,
+};
+#[cfg(feature = "rust_decimal")]
+use rust_decimal::Decimal;
+
+use crate::{
+    exact::is_exact_globally,
+    fraction::{
+        fraction_enum::FractionEnum, fraction_exact::FractionExact, fraction_f64::FractionF64,
+    },
+};
+
+/// Conversion of fractions into decimal types with a fixed number of decimal places.
+/// As not every fraction has a finite decimal expansion, the caller chooses how many decimal places to keep and how to round the remainder.
+pub trait ToDecimal {
+    /// Returns the value as a `rust_decimal::Decimal` with `decimal_places` digits after the decimal point.
+    /// Fails if the value does not fit in a `Decimal`, if the value is not finite, or if `rounding` is `Exact` and rounding is necessary.
+    #[cfg(feature = "rust_decimal")]
+    fn to_decimal(&self, decimal_places: u32, rounding: RoundingMode) -> Result<Decimal>;
+
+    /// Returns the value as a `bigdecimal::BigDecimal` with `decimal_places` digits after the decimal point.
+    /// Fails if the value is not finite, or if `rounding` is `Exact` and rounding is necessary.
+    #[cfg(feature = "bigdecimal")]
+    fn to_big_decimal(&self, decimal_places: u64, rounding: RoundingMode) -> Result<BigDecimal>;
+}
+
+impl ToDecimal for Rational {
+    #[cfg(feature = "rust_decimal")]
+    fn to_decimal(&self, decimal_places: u32, rounding: RoundingMode) -> Result<Decimal> {
+        if decimal_places > Decimal::MAX_SCALE {
+            return Err(anyhow!(
+                "a decimal supports at most {} decimal places",
+                Decimal::MAX_SCALE
+            ));
+        }
+        let mantissa = to_scaled_integer(self, decimal_places.into(), rounding)?;
+        let mantissa = i128::try_from(&mantissa)
+            .map_err(|_| anyhow!("{} does not fit in a decimal", self))?;
+        Decimal::try_from_i128_with_scale(mantissa, decimal_places)
+            .map_err(|_| anyhow!("{} does not fit in a decimal", self))
+    }
+
+    #[cfg(feature = "bigdecimal")]
+    fn to_big_decimal(&self, decimal_places: u64, rounding: RoundingMode) -> Result<BigDecimal> {
+        let scale = i64::try_from(decimal_places)
+            .map_err(|_| anyhow!("too many decimal places requested"))?;
+        let mantissa = to_scaled_integer(self, decimal_places, rounding)?;
+        Ok(BigDecimal::new(integer_to_big_int(&mantissa), scale))
+    }
+}
+
+impl ToDecimal for FractionExact {
+    #[cfg(feature = "rust_decimal")]
+    fn to_decimal(&self, decimal_places: u32, rounding: RoundingMode) -> Result<Decimal> {
+        self.0.to_decimal(decimal_places, rounding)
+    }
+
+    #[cfg(feature = "bigdecimal")]
+    fn to_big_decimal(&self, decimal_places: u64, rounding: RoundingMode) -> Result<BigDecimal> {
+        self.0.to_big_decimal(decimal_places, rounding)
+    }
+}
+
+impl ToDecimal for f64 {
+    #[cfg(feature = "rust_decimal")]
+    fn to_decimal(&self, decimal_places: u32, rounding: RoundingMode) -> Result<Decimal> {
+        float_to_rational(*self)?.to_decimal(decimal_places, rounding)
+    }
+
+    #[cfg(feature = "bigdecimal")]
+    fn to_big_decimal(&self, decimal_places: u64, rounding: RoundingMode) -> Result<BigDecimal> {
+        float_to_rational(*self)?.to_big_decimal(decimal_places, rounding)
+    }
+}
+
+impl ToDecimal for FractionF64 {
+    #[cfg(feature = "rust_decimal")]
+    fn to_decimal(&self, decimal_places: u32, rounding: RoundingMode) -> Result<Decimal> {
+        self.0.to_decimal(decimal_places, rounding)
+    }
+
+    #[cfg(feature = "bigdecimal")]
+    fn to_big_decimal(&self, decimal_places: u64, rounding: RoundingMode) -> Result<BigDecimal> {
+        self.0.to_big_decimal(decimal_places, rounding)
+    }
+}
+
+impl ToDecimal for FractionEnum {
+    #[cfg(feature = "rust_decimal")]
+    fn to_decimal(&self, decimal_places: u32, rounding: RoundingMode) -> Result<Decimal> {
+        match self {
+            FractionEnum::Exact(f) => f.to_decimal(decimal_places, rounding),
+            FractionEnum::Approx(f) => f.to_decimal(decimal_places, rounding),
+            FractionEnum::CannotCombineExactAndApprox => {
+                Err(anyhow!("cannot combine exact and approximate arithmetic"))
+            }
+        }
+    }
+
+    #[cfg(feature = "bigdecimal")]
+    fn to_big_decimal(&self, decimal_places: u64, rounding: RoundingMode) -> Result<BigDecimal> {
+        match self {
+            FractionEnum::Exact(f) => f.to_big_decimal(decimal_places, rounding),
+            FractionEnum::Approx(f) => f.to_big_decimal(decimal_places, rounding),
+            FractionEnum::CannotCombineExactAndApprox => {
+                Err(anyhow!("cannot combine exact and approximate arithmetic"))
+            }
+        }
+    }
+}
+
+/// Returns `value * 10^decimal_places`, rounded to an integer.
+fn to_scaled_integer(
+    value: &Rational,
+    decimal_places: u64,
+    rounding: RoundingMode,
+) -> Result<Integer> {
+    let scaled = value * Rational::from(Natural::from(10u64).pow(decimal_places));
+    if rounding == RoundingMode::Exact && !scaled.is_integer() {
+        return Err(anyhow!(
+            "{} cannot be represented exactly with {} decimal places",
+            value,
+            decimal_places
+        ));
+    }
+    Ok(Integer::rounding_from(scaled, rounding).0)
+}
+
+fn float_to_rational(value: f64) -> Result<Rational> {
+    Rational::try_from(value).map_err(|_| anyhow!("{} cannot be represented as a decimal", value))
+}
+
+/// Returns `mantissa / 10^scale`; a negative scale multiplies by a power of ten.
+fn from_scaled_integer(mantissa: Integer, scale: i64) -> Rational {
+    let power = Rational::from(Natural::from(10u64).pow(scale.unsigned_abs()));
+    if scale >= 0 {
+        Rational::from(mantissa) / power
+    } else {
+        Rational::from(mantissa) * power
+    }
+}
+
+//======================== rust_decimal ========================//
+
+#[cfg(feature = "rust_decimal")]
+impl From<&Decimal> for FractionExact {
+    fn from(value: &Decimal) -> Self {
+        Self(from_scaled_integer(
+            Integer::from(value.mantissa()),
+            value.scale().into(),
+        ))
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl From<Decimal> for FractionExact {
+    fn from(value: Decimal) -> Self {
+        Self::from(&value)
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl From<&Decimal> for FractionF64 {
+    fn from(value: &Decimal) -> Self {
+        Self(f64::rounding_from(FractionExact::from(value).0, RoundingMode::Nearest).0)
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl From<Decimal> for FractionF64 {
+    fn from(value: Decimal) -> Self {
+        Self::from(&value)
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl From<&Decimal> for FractionEnum {
+    fn from(value: &Decimal) -> Self {
+        if is_exact_globally() {
+            FractionEnum::Exact(FractionExact::from(value).0)
+        } else {
+            FractionEnum::Approx(FractionF64::from(value).0)
+        }
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl From<Decimal> for FractionEnum {
+    fn from(value: Decimal) -> Self {
+        Self::from(&value)
+    }
+}
+
+//======================== bigdecimal ========================//
+
+#[cfg(feature = "bigdecimal")]
+impl From<&BigDecimal> for FractionExact {
+    fn from(value: &BigDecimal) -> Self {
+        let (mantissa, scale) = value.as_bigint_and_exponent();
+        Self(from_scaled_integer(big_int_to_integer(&mantissa), scale))
+    }
+}
+
+#[cfg(feature = "bigdecimal")]
+impl From<BigDecimal> for FractionExact {
+    fn from(value: BigDecimal) -> Self {
+        Self::from(&value)
+    }
+}
+
+#[cfg(feature = "bigdecimal")]
+impl From<&BigDecimal> for FractionF64 {
+    fn from(value: &BigDecimal) -> Self {
+        Self(f64::rounding_from(FractionExact::from(value).0, RoundingMode::Nearest).0)
+    }
+}
+
+#[cfg(feature = "bigdecimal")]
+impl From<BigDecimal> for FractionF64 {
+    fn from(value: BigDecimal) -> Self {
+        Self::from(&value)
+    }
+}
+
+#[cfg(feature = "bigdecimal")]
+impl From<&BigDecimal> for FractionEnum {
+    fn from(value: &BigDecimal) -> Self {
+        if is_exact_globally() {
+            FractionEnum::Exact(FractionExact::from(value).0)
+        } else {
+            FractionEnum::Approx(FractionF64::from(value).0)
+        }
+    }
+}
+
+#[cfg(feature = "bigdecimal")]
+impl From<BigDecimal> for FractionEnum {
+    fn from(value: BigDecimal) -> Self {
+        Self::from(&value)
+    }
+}
+
+#[cfg(feature = "bigdecimal")]
+fn big_int_to_integer(value: &BigInt) -> Integer {
+    let (sign, limbs) = value.to_u64_digits();
+    let magnitude = Integer::from(Natural::from_owned_limbs_asc(limbs));
+    if sign == Sign::Minus {
+        -magnitude
+    } else {
+        magnitude
+    }
+}
+
+#[cfg(feature = "bigdecimal")]
+fn integer_to_big_int(value: &Integer) -> BigInt {
+    let digits = value
+        .unsigned_abs_ref()
+        .to_limbs_asc()
+        .into_iter()
+        .flat_map(|limb| [limb as u32, (limb >> 32) as u32])
+        .collect();
+    let sign = if *value < 0 { Sign::Minus } else { Sign::Plus };
+    BigInt::from_biguint(sign, BigUint::new(digits))
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn rust_decimal_round_trip() {
+        use crate::fraction::{
+            decimal::ToDecimal, fraction_exact::FractionExact, fraction_f64::FractionF64,
+        };
+        use malachite::base::rounding_modes::RoundingMode;
+        use rust_decimal::Decimal;
+        use std::str::FromStr;
+
+        let d = Decimal::from_str("-12.375").unwrap();
+        assert_eq!(FractionExact::from(d), FractionExact::from((-99, 8)));
+        assert_eq!(FractionF64::from(d), FractionF64::from(-12.375));
+
+        let third = FractionExact::from((1, 3));
+        assert_eq!(
+            third.to_decimal(4, RoundingMode::Nearest).unwrap(),
+            Decimal::from_str("0.3333").unwrap()
+        );
+        assert_eq!(
+            third.to_decimal(4, RoundingMode::Ceiling).unwrap(),
+            Decimal::from_str("0.3334").unwrap()
+        );
+        assert!(third.to_decimal(4, RoundingMode::Exact).is_err());
+        assert!(FractionF64::from(f64::NAN).to_decimal(4, RoundingMode::Nearest).is_err());
+    }
+
+    #[cfg(feature = "bigdecimal")]
+    #[test]
+    fn big_decimal_round_trip() {
+        use crate::fraction::{decimal::ToDecimal, fraction_exact::FractionExact};
+        use bigdecimal::BigDecimal;
+        use malachite::base::rounding_modes::RoundingMode;
+        use std::str::FromStr;
+
+        let d = BigDecimal::from_str("-123456789012345678901234567890.5").unwrap();
+        let f = FractionExact::from(&d);
+        assert_eq!(f.to_big_decimal(1, RoundingMode::Exact).unwrap(), d);
+
+        let d = BigDecimal::from_str("12e3").unwrap();
+        assert_eq!(FractionExact::from(d), FractionExact::from(12000));
+
+        let two_thirds = FractionExact::from((2, 3));
+        assert_eq!(
+            two_thirds.to_big_decimal(3, RoundingMode::Floor).unwrap(),
+            BigDecimal::from_str("0.666").unwrap()
+        );
+    }
+}
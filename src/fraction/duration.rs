@@ -0,0 +1,167 @@
+use anyhow::{Result, anyhow};
+use malachite::{
+    Natural,
+    base::{
+        num::conversion::traits::{IsInteger, RoundingFrom},
+        rounding_modes::RoundingMode,
+    },
+    rational::Rational,
+};
+use std::time::Duration;
+
+use crate::{
+    exact::is_exact_globally,
+    fraction::{
+        exact::float_to_rational, fraction_enum::FractionEnum, fraction_exact::FractionExact,
+        fraction_f64::FractionF64,
+    },
+};
+
+const NANOSECONDS_PER_SECOND: u64 = 1_000_000_000;
+
+/// Conversion of a number of seconds into a [Duration].
+/// A duration has a resolution of one nanosecond, thus the caller chooses how to round the remainder.
+pub trait ToDuration {
+    /// Returns the value, in seconds, as a duration.
+    /// Fails if the value is negative or not finite, if it does not fit in a duration, or if `rounding` is `Exact` and rounding is necessary.
+    fn to_duration(&self, rounding: RoundingMode) -> Result<Duration>;
+}
+
+impl ToDuration for Rational {
+    fn to_duration(&self, rounding: RoundingMode) -> Result<Duration> {
+        if *self < 0u32 {
+            return Err(anyhow!(
+                "cannot convert the negative value {} to a duration",
+                self
+            ));
+        }
+        let nanoseconds = self * Rational::from(NANOSECONDS_PER_SECOND);
+        if rounding == RoundingMode::Exact && !nanoseconds.is_integer() {
+            return Err(anyhow!(
+                "{} seconds cannot be represented exactly as a duration",
+                self
+            ));
+        }
+        let nanoseconds = Natural::rounding_from(nanoseconds, rounding).0;
+        let seconds = u64::try_from(&(&nanoseconds / Natural::from(NANOSECONDS_PER_SECOND)))
+            .map_err(|_| anyhow!("{} seconds does not fit in a duration", self))?;
+        let remainder = u32::try_from(&(nanoseconds % Natural::from(NANOSECONDS_PER_SECOND)))
+            .map_err(|_| anyhow!("{} seconds does not fit in a duration", self))?;
+        Ok(Duration::new(seconds, remainder))
+    }
+}
+
+impl ToDuration for FractionExact {
+    fn to_duration(&self, rounding: RoundingMode) -> Result<Duration> {
+        self.0.to_duration(rounding)
+    }
+}
+
+impl ToDuration for FractionF64 {
+    fn to_duration(&self, rounding: RoundingMode) -> Result<Duration> {
+        float_to_rational(self.0)?.to_duration(rounding)
+    }
+}
+
+impl ToDuration for FractionEnum {
+    fn to_duration(&self, rounding: RoundingMode) -> Result<Duration> {
+        match self {
+            FractionEnum::Exact(f) => f.to_duration(rounding),
+            FractionEnum::Approx(f) => FractionF64(*f).to_duration(rounding),
+            FractionEnum::CannotCombineExactAndApprox => {
+                Err(anyhow!("cannot combine exact and approximate arithmetic"))
+            }
+        }
+    }
+}
+
+/// Returns the duration in seconds, exactly.
+fn duration_to_rational(value: Duration) -> Rational {
+    Rational::from(value.as_secs())
+        + Rational::from_unsigneds(value.subsec_nanos() as u64, NANOSECONDS_PER_SECOND)
+}
+
+/// The number of seconds of the duration, exactly.
+impl From<Duration> for FractionExact {
+    fn from(value: Duration) -> Self {
+        Self(duration_to_rational(value))
+    }
+}
+
+/// The number of seconds of the duration, rounded to the nearest float.
+impl From<Duration> for FractionF64 {
+    fn from(value: Duration) -> Self {
+        Self(value.as_secs_f64())
+    }
+}
+
+/// The number of seconds of the duration, exactly in exact mode.
+impl From<Duration> for FractionEnum {
+    fn from(value: Duration) -> Self {
+        if is_exact_globally() {
+            FractionEnum::Exact(duration_to_rational(value))
+        } else {
+            FractionEnum::Approx(value.as_secs_f64())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use malachite::base::rounding_modes::RoundingMode;
+    use std::time::Duration;
+
+    use crate::fraction::{
+        duration::ToDuration, fraction_enum::FractionEnum, fraction_exact::FractionExact,
+        fraction_f64::FractionF64,
+    };
+
+    #[test]
+    fn duration() {
+        let d = Duration::new(3, 250_000_001);
+        let f = FractionExact::from(d);
+        assert_eq!(f, FractionExact::from((3_250_000_001u64, 1_000_000_000u64)));
+        assert_eq!(f.to_duration(RoundingMode::Exact).unwrap(), d);
+        assert_eq!(FractionF64::from(d).0, 3.250000001);
+
+        let third = FractionExact::from((1, 3));
+        assert_eq!(
+            third.to_duration(RoundingMode::Floor).unwrap(),
+            Duration::from_nanos(333_333_333)
+        );
+        assert_eq!(
+            third.to_duration(RoundingMode::Ceiling).unwrap(),
+            Duration::from_nanos(333_333_334)
+        );
+        assert!(third.to_duration(RoundingMode::Exact).is_err());
+
+        assert_eq!(
+            FractionF64::from(0.5)
+                .to_duration(RoundingMode::Nearest)
+                .unwrap(),
+            Duration::from_millis(500)
+        );
+        assert_eq!(
+            FractionEnum::from(d)
+                .to_duration(RoundingMode::Nearest)
+                .unwrap(),
+            d
+        );
+
+        assert!(
+            FractionExact::from(-1)
+                .to_duration(RoundingMode::Nearest)
+                .is_err()
+        );
+        assert!(
+            FractionF64::from(f64::INFINITY)
+                .to_duration(RoundingMode::Nearest)
+                .is_err()
+        );
+        assert!(
+            FractionF64::from(1e30)
+                .to_duration(RoundingMode::Nearest)
+                .is_err()
+        );
+    }
+}
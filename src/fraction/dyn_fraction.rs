@@ -0,0 +1,257 @@
+use anyhow::{Result, anyhow};
+use malachite::base::{num::conversion::traits::RoundingFrom, rounding_modes::RoundingMode};
+use std::{
+    any::Any,
+    cmp::Ordering,
+    fmt::{Debug, Display},
+};
+
+use crate::{
+    ebi_number::{One, Signed, Zero},
+    fraction::{fraction_exact::FractionExact, fraction_f64::FractionF64},
+    try_ops::{TryAdd, TryCmp, TryDiv, TryMul, TrySub},
+};
+
+/// The object-safe subset of the operations on fractions, such that values of different backends can be stored in one collection.
+/// Arithmetic is dispatched on the backend of `self`, and fails if the other value has a different backend.
+///
+/// The methods carry a `_dyn` suffix, to not clash with the methods of the generic traits.
+/// Usually, [DynFraction] is more convenient to work with.
+pub trait DynNumber: Any + Debug + Display + Send + Sync {
+    fn as_any(&self) -> &dyn Any;
+
+    fn clone_dyn(&self) -> Box<dyn DynNumber>;
+
+    fn is_exact_dyn(&self) -> bool;
+
+    fn is_zero_dyn(&self) -> bool;
+
+    fn is_one_dyn(&self) -> bool;
+
+    fn is_positive_dyn(&self) -> bool;
+
+    fn is_negative_dyn(&self) -> bool;
+
+    /// Returns the nearest f64.
+    fn to_f64_dyn(&self) -> f64;
+
+    fn try_add_dyn(&self, rhs: &dyn DynNumber) -> Result<Box<dyn DynNumber>>;
+
+    fn try_sub_dyn(&self, rhs: &dyn DynNumber) -> Result<Box<dyn DynNumber>>;
+
+    fn try_mul_dyn(&self, rhs: &dyn DynNumber) -> Result<Box<dyn DynNumber>>;
+
+    fn try_div_dyn(&self, rhs: &dyn DynNumber) -> Result<Box<dyn DynNumber>>;
+
+    fn try_cmp_dyn(&self, rhs: &dyn DynNumber) -> Result<Ordering>;
+}
+
+/// Returns the value as the given backend, or an error if it has a different backend.
+fn downcast<T: DynNumber>(value: &dyn DynNumber) -> Result<&T> {
+    value
+        .as_any()
+        .downcast_ref::<T>()
+        .ok_or_else(|| anyhow!("cannot combine exact and approximate arithmetic"))
+}
+
+macro_rules! dyn_number {
+    ($t:ident, $exact:expr, $to_f64:expr) => {
+        impl DynNumber for $t {
+            fn as_any(&self) -> &dyn Any {
+                self
+            }
+
+            fn clone_dyn(&self) -> Box<dyn DynNumber> {
+                Box::new(self.clone())
+            }
+
+            fn is_exact_dyn(&self) -> bool {
+                $exact
+            }
+
+            fn is_zero_dyn(&self) -> bool {
+                Zero::is_zero(self)
+            }
+
+            fn is_one_dyn(&self) -> bool {
+                One::is_one(self)
+            }
+
+            fn is_positive_dyn(&self) -> bool {
+                Signed::is_positive(self)
+            }
+
+            fn is_negative_dyn(&self) -> bool {
+                Signed::is_negative(self)
+            }
+
+            fn to_f64_dyn(&self) -> f64 {
+                $to_f64(self)
+            }
+
+            fn try_add_dyn(&self, rhs: &dyn DynNumber) -> Result<Box<dyn DynNumber>> {
+                Ok(Box::new(self.try_add(downcast::<$t>(rhs)?)?))
+            }
+
+            fn try_sub_dyn(&self, rhs: &dyn DynNumber) -> Result<Box<dyn DynNumber>> {
+                Ok(Box::new(self.try_sub(downcast::<$t>(rhs)?)?))
+            }
+
+            fn try_mul_dyn(&self, rhs: &dyn DynNumber) -> Result<Box<dyn DynNumber>> {
+                Ok(Box::new(self.try_mul(downcast::<$t>(rhs)?)?))
+            }
+
+            fn try_div_dyn(&self, rhs: &dyn DynNumber) -> Result<Box<dyn DynNumber>> {
+                Ok(Box::new(self.try_div(downcast::<$t>(rhs)?)?))
+            }
+
+            fn try_cmp_dyn(&self, rhs: &dyn DynNumber) -> Result<Ordering> {
+                self.try_cmp(downcast::<$t>(rhs)?)
+            }
+        }
+
+        impl From<$t> for DynFraction {
+            fn from(value: $t) -> Self {
+                Self(Box::new(value))
+            }
+        }
+    };
+}
+
+fn exact_to_f64(value: &FractionExact) -> f64 {
+    f64::rounding_from(&value.0, RoundingMode::Nearest).0
+}
+
+dyn_number!(FractionF64, false, |value: &FractionF64| value.0);
+dyn_number!(FractionExact, true, exact_to_f64);
+
+/// A fraction of any backend, behind a pointer.
+///
+/// Unlike [FractionEnum](crate::fraction::fraction_enum::FractionEnum), the backend is not selected by the global mode, and a collection may hold exact and approximate values side by side.
+/// Operations borrow their operands, and combining values of different backends yields an error.
+pub struct DynFraction(Box<dyn DynNumber>);
+
+impl DynFraction {
+    pub fn new(value: impl DynNumber) -> Self {
+        Self(Box::new(value))
+    }
+
+    /// Returns the value as its backend type, if it has that backend.
+    pub fn downcast_ref<T: DynNumber>(&self) -> Option<&T> {
+        self.0.as_any().downcast_ref::<T>()
+    }
+
+    pub fn as_dyn(&self) -> &dyn DynNumber {
+        self.0.as_ref()
+    }
+
+    pub fn is_exact(&self) -> bool {
+        self.0.is_exact_dyn()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0.is_zero_dyn()
+    }
+
+    pub fn is_one(&self) -> bool {
+        self.0.is_one_dyn()
+    }
+
+    pub fn is_positive(&self) -> bool {
+        self.0.is_positive_dyn()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.0.is_negative_dyn()
+    }
+
+    /// Returns the nearest f64.
+    pub fn to_f64(&self) -> f64 {
+        self.0.to_f64_dyn()
+    }
+}
+
+impl Clone for DynFraction {
+    fn clone(&self) -> Self {
+        Self(self.0.clone_dyn())
+    }
+}
+
+impl Debug for DynFraction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(&self.0, f)
+    }
+}
+
+impl Display for DynFraction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl TryAdd for DynFraction {
+    fn try_add(&self, rhs: &Self) -> Result<Self> {
+        Ok(Self(self.0.try_add_dyn(rhs.as_dyn())?))
+    }
+}
+
+impl TrySub for DynFraction {
+    fn try_sub(&self, rhs: &Self) -> Result<Self> {
+        Ok(Self(self.0.try_sub_dyn(rhs.as_dyn())?))
+    }
+}
+
+impl TryMul for DynFraction {
+    fn try_mul(&self, rhs: &Self) -> Result<Self> {
+        Ok(Self(self.0.try_mul_dyn(rhs.as_dyn())?))
+    }
+}
+
+impl TryDiv for DynFraction {
+    fn try_div(&self, rhs: &Self) -> Result<Self> {
+        Ok(Self(self.0.try_div_dyn(rhs.as_dyn())?))
+    }
+}
+
+impl TryCmp for DynFraction {
+    fn try_cmp(&self, rhs: &Self) -> Result<Ordering> {
+        self.0.try_cmp_dyn(rhs.as_dyn())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use crate::{
+        fraction::{
+            dyn_fraction::DynFraction, fraction_exact::FractionExact, fraction_f64::FractionF64,
+        },
+        try_ops::{TryAdd, TryCmp, TryDiv, TryMul},
+    };
+
+    #[test]
+    fn dyn_fraction() {
+        let column: Vec<DynFraction> = vec![
+            FractionExact::from((1, 3)).into(),
+            FractionF64::from(0.25).into(),
+            FractionExact::from((2, 3)).into(),
+        ];
+
+        let sum = column[0].try_add(&column[2]).unwrap();
+        assert!(sum.is_exact() && sum.is_one());
+        assert_eq!(
+            sum.downcast_ref::<FractionExact>(),
+            Some(&FractionExact::from(1))
+        );
+        assert!(column[0].try_add(&column[1]).is_err());
+        assert!(column[1].try_cmp(&column[2]).is_err());
+
+        let square = column[1].try_mul(&column[1]).unwrap();
+        assert_eq!(square.to_f64(), 0.0625);
+        assert!(square.downcast_ref::<FractionExact>().is_none());
+        assert_eq!(column[0].try_cmp(&column[2]).unwrap(), Ordering::Less);
+        assert!(column[0].try_div(&FractionExact::from(0).into()).is_err());
+        assert_eq!(column[2].clone().to_string(), "2/3");
+    }
+}
@@ -1,14 +1,31 @@
 use anyhow::{Result, anyhow};
-use malachite::{Integer, Natural, rational::Rational};
+use malachite::{
+    Integer, Natural,
+    base::{num::conversion::traits::RoundingFrom, rounding_modes::RoundingMode},
+    rational::Rational,
+};
+
+use std::borrow::Cow;
 
 use crate::{
     exact::MaybeExact,
     fraction::{
-        fraction_enum::FractionEnum, fraction_exact::FractionExact, fraction_f64::FractionF64,
+        approximate::Approximate, fraction_enum::FractionEnum, fraction_exact::FractionExact,
+        fraction_f64::FractionF64,
     },
     is_exact_globally,
 };
 
+/// Returns the exact value of the float; fails for infinite and NaN values.
+pub(crate) fn float_to_rational(value: f64) -> Result<Rational> {
+    Rational::try_from(value).map_err(|_| anyhow!("{} cannot be represented as a fraction", value))
+}
+
+/// Returns the float nearest to the value; beyond the finite range, an infinity.
+pub(crate) fn rational_to_f64(value: &Rational) -> f64 {
+    f64::rounding_from(value, RoundingMode::Nearest).0
+}
+
 impl MaybeExact for FractionF64 {
     type Approximate = f64;
     type Exact = Rational;
@@ -40,6 +57,10 @@ impl MaybeExact for FractionF64 {
     fn try_to_approx(approx: Self::Approximate) -> Result<Self> {
         Ok(FractionF64(approx))
     }
+
+    fn approx_to_exact(&self) -> Result<Rational> {
+        float_to_rational(self.0)
+    }
 }
 
 impl MaybeExact for FractionExact {
@@ -73,6 +94,14 @@ impl MaybeExact for FractionExact {
     fn try_to_approx(_: Self::Approximate) -> Result<Self> {
         Err(anyhow!("cannot put float in a fraction"))
     }
+
+    fn into_approx(self) -> Result<Self::Approximate> {
+        self.0.approximate()
+    }
+
+    fn approx_cow(&self) -> Result<Cow<'_, Self::Approximate>> {
+        Ok(Cow::Owned(self.0.clone().approximate()?))
+    }
 }
 
 impl MaybeExact for FractionEnum {
@@ -142,6 +171,30 @@ impl MaybeExact for FractionEnum {
             Err(anyhow!("cannot put fraction in a float"))
         }
     }
+
+    fn into_approx(self) -> Result<f64> {
+        self.approximate()
+    }
+
+    fn approx_to_exact(&self) -> Result<Rational> {
+        match self {
+            FractionEnum::Exact(f) => Ok(f.clone()),
+            FractionEnum::Approx(f) => float_to_rational(*f),
+            FractionEnum::CannotCombineExactAndApprox => {
+                Err(anyhow!("cannot combine exact and approximate arithmetic"))
+            }
+        }
+    }
+
+    fn approx_cow(&self) -> Result<Cow<'_, f64>> {
+        match self {
+            FractionEnum::Exact(f) => Ok(Cow::Owned(f.clone().approximate()?)),
+            FractionEnum::Approx(f) => Ok(Cow::Borrowed(f)),
+            FractionEnum::CannotCombineExactAndApprox => {
+                Err(anyhow!("cannot combine exact and approximate arithmetic"))
+            }
+        }
+    }
 }
 
 impl MaybeExact for Rational {
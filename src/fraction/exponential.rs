@@ -0,0 +1,267 @@
+use anyhow::{Result, anyhow};
+use malachite::{
+    Integer, Natural,
+    base::{
+        num::{
+            arithmetic::traits::{Pow, Reciprocal},
+            basic::traits::{One, Zero},
+            conversion::traits::RoundingFrom,
+            logic::traits::SignificantBits,
+        },
+        rounding_modes::RoundingMode,
+    },
+    rational::Rational,
+};
+
+use crate::{
+    Exponential,
+    fraction::{
+        fraction_enum::FractionEnum, fraction_exact::FractionExact, fraction_f64::FractionF64,
+    },
+};
+
+impl Exponential for FractionF64 {
+    fn ln(&self, _precision_decimals: u32) -> Result<Self> {
+        if self.0 <= 0.0 {
+            return Err(anyhow!(
+                "cannot calculate the logarithm of non-positive values"
+            ));
+        }
+        Ok(Self(self.0.ln()))
+    }
+
+    fn exp(&self, _precision_decimals: u32) -> Result<Self> {
+        Ok(Self(self.0.exp()))
+    }
+}
+
+impl Exponential for FractionExact {
+    fn ln(&self, precision_decimals: u32) -> Result<Self> {
+        Ok(Self(self.0.ln(precision_decimals)?))
+    }
+
+    fn exp(&self, precision_decimals: u32) -> Result<Self> {
+        Ok(Self(self.0.exp(precision_decimals)?))
+    }
+}
+
+impl Exponential for FractionEnum {
+    fn ln(&self, precision_decimals: u32) -> Result<Self> {
+        match self {
+            FractionEnum::Exact(f) => Ok(FractionEnum::Exact(f.ln(precision_decimals)?)),
+            FractionEnum::Approx(f) => Ok(FractionEnum::Approx(
+                FractionF64(*f).ln(precision_decimals)?.0,
+            )),
+            FractionEnum::CannotCombineExactAndApprox => {
+                Err(anyhow!("cannot combine exact and approximate arithmetic"))
+            }
+        }
+    }
+
+    fn exp(&self, precision_decimals: u32) -> Result<Self> {
+        match self {
+            FractionEnum::Exact(f) => Ok(FractionEnum::Exact(f.exp(precision_decimals)?)),
+            FractionEnum::Approx(f) => Ok(FractionEnum::Approx(
+                FractionF64(*f).exp(precision_decimals)?.0,
+            )),
+            FractionEnum::CannotCombineExactAndApprox => {
+                Err(anyhow!("cannot combine exact and approximate arithmetic"))
+            }
+        }
+    }
+}
+
+impl Exponential for Rational {
+    /// Writes the value as 2^k * y with y in [1, 2), and uses ln(y) = 2 atanh((y - 1) / (y + 1)) and ln(2) = 2 atanh(1/3).
+    fn ln(&self, precision_decimals: u32) -> Result<Self> {
+        if *self <= Rational::ZERO {
+            return Err(anyhow!(
+                "cannot calculate the logarithm of non-positive values"
+            ));
+        }
+        if *self == Rational::ONE {
+            return Ok(Rational::ZERO);
+        }
+
+        let epsilon = epsilon(precision_decimals);
+
+        //find k such that y = self / 2^k is in [1, 2)
+        let mut k = self.numerator_ref().significant_bits() as i64
+            - self.denominator_ref().significant_bits() as i64;
+        let mut y = self / power_of_two(k);
+        if y < Rational::ONE {
+            k -= 1;
+            y *= Rational::from(2u32);
+        }
+
+        //each part contributes at most a quarter of the error
+        let z = (&y - Rational::ONE) / (&y + Rational::ONE);
+        let mut result = atanh_series(&z, &epsilon / Rational::from(4u32));
+        if k != 0 {
+            let bound = &epsilon / Rational::from(4 * (k.unsigned_abs() + 1));
+            result +=
+                atanh_series(&Rational::from_unsigneds(1u32, 3u32), bound) * Rational::from(k);
+        }
+
+        Ok(round_to_decimals(result, precision_decimals))
+    }
+
+    /// Writes the value as n + f with f in [0, 1), and uses exp(n + f) = e^n exp(f), with both factors computed by their Taylor series.
+    fn exp(&self, precision_decimals: u32) -> Result<Self> {
+        if *self == Rational::ZERO {
+            return Ok(Rational::ONE);
+        }
+
+        //exp(-x) = 1 / exp(x), where exp(x) >= 1 thus the reciprocal does not increase the error
+        if *self < Rational::ZERO {
+            let positive = exp_non_negative(&-self, precision_decimals + 1)?;
+            return Ok(round_to_decimals(positive.reciprocal(), precision_decimals));
+        }
+
+        Ok(round_to_decimals(
+            exp_non_negative(self, precision_decimals + 1)?,
+            precision_decimals,
+        ))
+    }
+}
+
+/// Returns exp(x) for x >= 0, within 1/10^precision_decimals / 2 of the actual value.
+fn exp_non_negative(x: &Rational, precision_decimals: u32) -> Result<Rational> {
+    let n = Natural::rounding_from(x, RoundingMode::Floor).0;
+    let n = u64::try_from(&n).map_err(|_| anyhow!("{} is too large to exponentiate", x))?;
+    let f = x - Rational::from(n);
+
+    //relative error per factor: e^x <= 3^(n+1), and e^n amplifies the relative error of e by about 2n
+    let delta = epsilon(precision_decimals)
+        / (Rational::from(Natural::from(3u32).pow(n + 1)) * Rational::from(4 * (n + 2)));
+
+    let e = exp_series(&Rational::ONE, &delta);
+    let fraction = exp_series(&f, &delta);
+    Ok(Pow::pow(e, n) * fraction)
+}
+
+/// Returns exp(x) for x in [0, 1], with a relative error of at most `delta`.
+fn exp_series(x: &Rational, delta: &Rational) -> Rational {
+    //terms shrink by at least half after the first, so the tail is at most twice the first omitted term, and the sum is at least one
+    let bound = delta / Rational::from(4u32);
+    let mut sum = Rational::ONE;
+    let mut term = Rational::ONE;
+    let mut k = 1u64;
+    loop {
+        term *= x;
+        term /= Rational::from(k);
+        if term < bound {
+            break;
+        }
+        sum += &term;
+        k += 1;
+    }
+
+    //keep the representation small: rounding to a multiple of 2^-p with 2^-p <= delta / 4 adds at most a quarter of delta
+    let bits = Natural::rounding_from(delta.reciprocal(), RoundingMode::Floor)
+        .0
+        .significant_bits()
+        + 2;
+    round_to_power_of_two(sum, bits)
+}
+
+/// Returns 2 atanh(z) = 2 Σ z^(2i+1) / (2i+1), for 0 <= z <= 1/3, within `bound` of the actual value.
+fn atanh_series(z: &Rational, bound: Rational) -> Rational {
+    let square = z * z;
+    let mut sum = Rational::ZERO;
+    let mut power = z.clone();
+    let mut i = 0u64;
+    //the tail after a term is at most 9/8 times the next power of z, and is counted twice
+    let limit = bound * Rational::from_unsigneds(4u32, 9u32);
+    while power > Rational::ZERO {
+        sum += &power / Rational::from(2 * i + 1);
+        power *= &square;
+        i += 1;
+        if power <= limit {
+            break;
+        }
+    }
+    sum * Rational::from(2u32)
+}
+
+fn epsilon(precision_decimals: u32) -> Rational {
+    Rational::from(Natural::from(10u32).pow(u64::from(precision_decimals))).reciprocal()
+}
+
+fn power_of_two(k: i64) -> Rational {
+    let power = Rational::from(Natural::ONE << k.unsigned_abs());
+    if k < 0 { power.reciprocal() } else { power }
+}
+
+/// Rounds to the nearest multiple of 1/10^decimals.
+fn round_to_decimals(value: Rational, decimals: u32) -> Rational {
+    let scale = Rational::from(Natural::from(10u32).pow(u64::from(decimals)));
+    Rational::from(Integer::rounding_from(value * &scale, RoundingMode::Nearest).0) / scale
+}
+
+/// Rounds to the nearest multiple of 2^-bits.
+fn round_to_power_of_two(value: Rational, bits: u64) -> Rational {
+    let scale = Rational::from(Natural::ONE << bits);
+    Rational::from(Integer::rounding_from(value * &scale, RoundingMode::Nearest).0) / scale
+}
+
+#[cfg(test)]
+mod tests {
+    use malachite::rational::Rational;
+
+    use crate::{
+        Exponential,
+        fraction::{approximate::Approximate, fraction_exact::FractionExact},
+    };
+
+    fn assert_close(value: Rational, expected: f64, precision_decimals: i32) {
+        let value = value.approximate().unwrap();
+        assert!(
+            (value - expected).abs() <= 10f64.powi(-precision_decimals),
+            "{} is not close to {}",
+            value,
+            expected
+        );
+    }
+
+    #[test]
+    fn ln() {
+        for (x, decimals) in [(2u64, 12), (10, 12), (1, 5), (1000, 9)] {
+            let value = FractionExact::from(x).ln(decimals).unwrap();
+            assert_close(value.0, (x as f64).ln(), decimals as i32);
+        }
+        let value = FractionExact::from((1, 3)).ln(12).unwrap();
+        assert_close(value.0, (1.0f64 / 3.0).ln(), 12);
+        assert!(FractionExact::from(0).ln(5).is_err());
+        assert!(FractionExact::from(-1).ln(5).is_err());
+        assert_eq!(
+            FractionExact::from(1).ln(5).unwrap(),
+            FractionExact::from(0)
+        );
+
+        //the result is a decimal with at most the requested number of decimals
+        let value = FractionExact::from(2).ln(4).unwrap();
+        assert_eq!(value, FractionExact::from((6931, 10000)));
+    }
+
+    #[test]
+    fn exp() {
+        for (x, decimals) in [((1, 1), 12), ((1, 2), 12), ((-3, 1), 12), ((7, 2), 10)] {
+            let value = FractionExact::from(x).exp(decimals).unwrap();
+            assert_close(value.0, (x.0 as f64 / x.1 as f64).exp(), decimals as i32);
+        }
+        assert_eq!(
+            FractionExact::from(0).exp(5).unwrap(),
+            FractionExact::from(1)
+        );
+        assert_eq!(
+            FractionExact::from(1).exp(4).unwrap(),
+            FractionExact::from((27183, 10000))
+        );
+
+        //ln and exp are inverses, up to the precision
+        let x = FractionExact::from((5, 7));
+        let y = x.exp(20).unwrap().ln(15).unwrap();
+        assert_close(y.0 - x.0, 0.0, 14);
+    }
+}
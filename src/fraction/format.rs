@@ -0,0 +1,237 @@
+use malachite::{
+    Natural,
+    base::{
+        num::{
+            arithmetic::traits::{Abs, DivRem, Pow},
+            conversion::traits::RoundingFrom,
+        },
+        rounding_modes::RoundingMode,
+    },
+    rational::Rational,
+};
+
+use crate::fraction::{
+    fraction_enum::FractionEnum, fraction_exact::FractionExact, fraction_f64::FractionF64,
+};
+
+/// How [FormatWith::format_with] writes a value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Notation {
+    /// A numerator and a denominator, such as `1/3`. Approximate values are written as the simplest fraction that rounds to them.
+    Fraction,
+    /// A fixed number of digits after the decimal separator, such as `0.333`. Values are rounded half away from zero.
+    Decimal { decimals: usize },
+}
+
+/// The options of [FormatWith::format_with]. The decimal and thousands separators allow following the conventions of a locale.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FormatOptions {
+    pub notation: Notation,
+    /// Inserted between each group of three digits of the integer part, or of the numerator and denominator.
+    pub thousands_separator: Option<char>,
+    pub decimal_separator: char,
+}
+
+impl FormatOptions {
+    pub fn fraction() -> Self {
+        Self {
+            notation: Notation::Fraction,
+            ..Self::default()
+        }
+    }
+
+    pub fn decimal(decimals: usize) -> Self {
+        Self {
+            notation: Notation::Decimal { decimals },
+            ..Self::default()
+        }
+    }
+
+    pub fn with_thousands_separator(mut self, separator: char) -> Self {
+        self.thousands_separator = Some(separator);
+        self
+    }
+
+    pub fn with_decimal_separator(mut self, separator: char) -> Self {
+        self.decimal_separator = separator;
+        self
+    }
+}
+
+/// Six decimals, without thousands separator.
+impl Default for FormatOptions {
+    fn default() -> Self {
+        Self {
+            notation: Notation::Decimal { decimals: 6 },
+            thousands_separator: None,
+            decimal_separator: '.',
+        }
+    }
+}
+
+pub trait FormatWith {
+    /// Writes the value according to the options.
+    /// Exact values are rounded exactly; they are never converted to a float first.
+    fn format_with(&self, options: &FormatOptions) -> String;
+}
+
+fn group_digits(digits: String, separator: Option<char>) -> String {
+    match separator {
+        Some(separator) => {
+            let mut result = String::with_capacity(digits.len() * 4 / 3);
+            for (i, digit) in digits.chars().enumerate() {
+                if i > 0 && (digits.len() - i).is_multiple_of(3) {
+                    result.push(separator);
+                }
+                result.push(digit);
+            }
+            result
+        }
+        None => digits,
+    }
+}
+
+impl FormatWith for Rational {
+    fn format_with(&self, options: &FormatOptions) -> String {
+        match options.notation {
+            Notation::Fraction => {
+                let sign = if *self < 0u32 { "-" } else { "" };
+                let numerator = group_digits(
+                    self.numerator_ref().to_string(),
+                    options.thousands_separator,
+                );
+                if *self.denominator_ref() == 1u32 {
+                    format!("{}{}", sign, numerator)
+                } else {
+                    let denominator = group_digits(
+                        self.denominator_ref().to_string(),
+                        options.thousands_separator,
+                    );
+                    format!("{}{}/{}", sign, numerator, denominator)
+                }
+            }
+            Notation::Decimal { decimals } => {
+                let power = Natural::from(10u32).pow(decimals as u64);
+                //round half away from zero: the absolute value plus a half, rounded down
+                let scaled = self.abs() * Rational::from(&power) + Rational::from_signeds(1, 2);
+                let rounded = Natural::rounding_from(scaled, RoundingMode::Floor).0;
+                let sign = if *self < 0u32 && rounded != 0u32 {
+                    "-"
+                } else {
+                    ""
+                };
+                let (integer, fraction) = rounded.div_rem(power);
+                let integer = group_digits(integer.to_string(), options.thousands_separator);
+                if decimals == 0 {
+                    format!("{}{}", sign, integer)
+                } else {
+                    format!(
+                        "{}{}{}{:0>width$}",
+                        sign,
+                        integer,
+                        options.decimal_separator,
+                        fraction,
+                        width = decimals
+                    )
+                }
+            }
+        }
+    }
+}
+
+/// Non-finite values are written as by [Display](std::fmt::Display).
+impl FormatWith for f64 {
+    fn format_with(&self, options: &FormatOptions) -> String {
+        let rational = match options.notation {
+            Notation::Fraction => Rational::try_from_float_simplest(*self),
+            Notation::Decimal { .. } => Rational::try_from(*self),
+        };
+        match rational {
+            Ok(rational) => rational.format_with(options),
+            Err(_) => self.to_string(),
+        }
+    }
+}
+
+impl FormatWith for FractionExact {
+    fn format_with(&self, options: &FormatOptions) -> String {
+        self.0.format_with(options)
+    }
+}
+
+impl FormatWith for FractionF64 {
+    fn format_with(&self, options: &FormatOptions) -> String {
+        self.0.format_with(options)
+    }
+}
+
+impl FormatWith for FractionEnum {
+    fn format_with(&self, options: &FormatOptions) -> String {
+        match self {
+            FractionEnum::Exact(f) => f.format_with(options),
+            FractionEnum::Approx(f) => f.format_with(options),
+            FractionEnum::CannotCombineExactAndApprox => self.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fraction::{
+        format::{FormatOptions, FormatWith},
+        fraction_exact::FractionExact,
+        fraction_f64::FractionF64,
+    };
+
+    #[test]
+    fn format_with() {
+        let third = FractionExact::from((1, 3));
+        assert_eq!(third.format_with(&FormatOptions::decimal(3)), "0.333");
+        assert_eq!(third.format_with(&FormatOptions::fraction()), "1/3");
+        assert_eq!(third.format_with(&FormatOptions::default()), "0.333333");
+
+        //far beyond the precision of a float
+        let x = FractionExact::from((2, 3)) + FractionExact::from(10i64.pow(17));
+        assert_eq!(
+            x.format_with(&FormatOptions::decimal(2).with_thousands_separator(',')),
+            "100,000,000,000,000,000.67"
+        );
+
+        let x = FractionExact::from((-12345, 1000));
+        let options = FormatOptions::decimal(2)
+            .with_thousands_separator('.')
+            .with_decimal_separator(',');
+        assert_eq!(x.format_with(&options), "-12,35");
+        assert_eq!(
+            FractionExact::from((-1234567, 1000)).format_with(&options),
+            "-1.234,57"
+        );
+        assert_eq!(
+            FractionExact::from((-1, 1000)).format_with(&FormatOptions::decimal(2)),
+            "0.00"
+        );
+        assert_eq!(
+            FractionExact::from((5, 2)).format_with(&FormatOptions::decimal(0)),
+            "3"
+        );
+        assert_eq!(
+            FractionExact::from((1234567, 1000))
+                .format_with(&FormatOptions::fraction().with_thousands_separator('_')),
+            "1_234_567/1_000"
+        );
+
+        assert_eq!(
+            FractionF64::from(0.1).format_with(&FormatOptions::fraction()),
+            "1/10"
+        );
+        assert_eq!(
+            FractionF64::from(1234.5)
+                .format_with(&FormatOptions::decimal(1).with_thousands_separator(' ')),
+            "1 234.5"
+        );
+        assert_eq!(
+            FractionF64::from(f64::INFINITY).format_with(&FormatOptions::default()),
+            "inf"
+        );
+    }
+}
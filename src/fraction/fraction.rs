@@ -20,6 +20,12 @@ pub type Fraction = super::fraction_exact::FractionExact;
 pub const APPROX_DIGITS: u64 = 5;
 pub const EPSILON: f64 = 1e-13;
 
+/// The tolerant-zero policy of approximate arithmetic: a float is considered zero if its absolute value is below `epsilon`.
+/// Approximate fractions use [EPSILON]; approximate matrices use their own tolerance, which defaults to [EPSILON].
+pub fn is_approximately_zero(value: f64, epsilon: f64) -> bool {
+    value.abs() < epsilon
+}
+
 #[macro_export]
 /// Convenience short-hand macro to create fractions.
 macro_rules! f {
@@ -1,9 +1,12 @@
 use crate::{
-    ebi_number::Zero,
+    ebi_number::{One, Zero},
     exact::is_exact_globally,
-    fraction::{fraction::EPSILON, fraction_exact::FractionExact, fraction_f64::FractionF64},
+    fraction::{
+        fraction::EPSILON, fraction_exact::FractionExact, fraction_f64::FractionF64,
+        ordered_fraction::f64_total_hash,
+    },
 };
-use anyhow::Error;
+use anyhow::{Error, Result, anyhow};
 use malachite::{
     Natural,
     base::{
@@ -25,6 +28,7 @@ use std::{
 
 #[derive(Clone)]
 pub enum FractionEnum {
+    /// An exact fraction. Malachite stores numerators and denominators that fit in a machine word inline, so small exact values do not allocate.
     Exact(Rational),
     Approx(f64),
     CannotCombineExactAndApprox,
@@ -52,6 +56,55 @@ impl FractionEnum {
             FractionEnum::Approx(f64::rounding_from(&result, RoundingMode::Nearest).0)
         }
     }
+
+    /// Sums the given fractions. Rather than silently returning [FractionEnum::CannotCombineExactAndApprox], as [Sum] does,
+    /// fails with the index of the first fraction that does not match the global arithmetic mode.
+    pub fn try_sum<T: Borrow<FractionEnum>>(
+        fractions: impl IntoIterator<Item = T>,
+    ) -> Result<Self> {
+        let mut sum = Self::zero();
+        for (index, fraction) in fractions.into_iter().enumerate() {
+            let fraction = fraction.borrow();
+            check_combinable(&sum, fraction, index)?;
+            sum += fraction;
+        }
+        Ok(sum)
+    }
+
+    /// Multiplies the given fractions. Rather than silently returning [FractionEnum::CannotCombineExactAndApprox],
+    /// fails with the index of the first fraction that does not match the global arithmetic mode.
+    pub fn try_product<T: Borrow<FractionEnum>>(
+        fractions: impl IntoIterator<Item = T>,
+    ) -> Result<Self> {
+        let mut product = Self::one();
+        for (index, fraction) in fractions.into_iter().enumerate() {
+            let fraction = fraction.borrow();
+            check_combinable(&product, fraction, index)?;
+            product *= fraction;
+        }
+        Ok(product)
+    }
+}
+
+fn check_combinable(
+    accumulator: &FractionEnum,
+    fraction: &FractionEnum,
+    index: usize,
+) -> Result<()> {
+    if accumulator.matches(fraction) {
+        Ok(())
+    } else {
+        Err(anyhow!(
+            "cannot combine exact and approximate arithmetic: fraction at index {} is {}",
+            index,
+            match fraction {
+                FractionEnum::Exact(_) => "exact",
+                FractionEnum::Approx(_) => "approximate",
+                FractionEnum::CannotCombineExactAndApprox =>
+                    "the result of combining exact and approximate arithmetic",
+            }
+        ))
+    }
 }
 
 impl Default for FractionEnum {
@@ -453,11 +506,13 @@ impl Hash for FractionEnum {
     /**
      * For good reasons, Rust does not support hashing of doubles. However, we need it to store distributions in a hashmap.
      * Approximate arithmetic is discouraged
+     *
+     * Zeroes and NaNs hash equally, but values that are equal within EPSILON may not: use StrictEq as the key of a hashmap instead.
      */
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         match self {
             FractionEnum::Exact(f) => f.hash(state),
-            FractionEnum::Approx(f) => f64::to_bits(*f).hash(state),
+            FractionEnum::Approx(f) => f64_total_hash(*f, state),
             Self::CannotCombineExactAndApprox => "cceaa".hash(state),
         }
     }
@@ -738,10 +793,35 @@ ttype_signed!(i8);
 mod tests {
     use crate::{
         ebi_number::{One, Signed},
-        fraction::fraction_enum::FractionEnum,
+        exact::MaybeExact,
+        fraction::{fraction_enum::FractionEnum, fraction_exact::FractionExact},
     };
     use std::ops::Neg;
 
+    #[test]
+    fn fraction_try_sum() {
+        let other_mode = if FractionEnum::one().is_exact() {
+            FractionEnum::Approx(0.5)
+        } else {
+            FractionEnum::Exact(FractionExact::from((1, 2)).0)
+        };
+
+        let mut fractions = vec![FractionEnum::from((1, 4)); 5];
+        assert_eq!(
+            FractionEnum::try_sum(&fractions).unwrap(),
+            FractionEnum::from((5, 4))
+        );
+        assert_eq!(
+            FractionEnum::try_product(fractions.iter()).unwrap(),
+            FractionEnum::from((1, 1024))
+        );
+
+        fractions[3] = other_mode;
+        let err = FractionEnum::try_sum(&fractions).unwrap_err();
+        assert!(err.to_string().contains("index 3"));
+        assert!(FractionEnum::try_product(fractions).is_err());
+    }
+
     #[test]
     fn fraction_neg() {
         let one = FractionEnum::one();
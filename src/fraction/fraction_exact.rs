@@ -1,18 +1,19 @@
 use anyhow::{Error, anyhow};
+use fnv::FnvHasher;
 use malachite::{
     Integer, Natural, base::num::arithmetic::traits::BinomialCoefficient, rational::Rational,
 };
 use std::{
     borrow::Borrow,
     cmp::Ordering,
-    hash::Hash,
+    hash::{Hash, Hasher},
     iter::Sum,
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
     str::FromStr,
     sync::Arc,
 };
 
-use crate::ebi_number::Zero;
+use crate::{ebi_number::Zero, parsing::parse_rational};
 
 #[derive(Clone)]
 pub struct FractionExact(pub(crate) Rational);
@@ -24,6 +25,31 @@ impl FractionExact {
         let result = Natural::binomial_coefficient(Natural::from(n), Natural::from(k));
         FractionExact(result.into())
     }
+
+    /// Returns a hash of the value that is stable across runs, platforms and versions of this crate.
+    /// Suitable as a key for persistent or shared caches; for in-memory hash maps, use [Hash].
+    pub fn canonical_hash(&self) -> u64 {
+        let mut hasher = FnvHasher::default();
+        hash_rational_canonically(&self.0, &mut hasher);
+        hasher.finish()
+    }
+}
+
+/// Feeds the sign, numerator and denominator of the value into the hasher.
+/// Rationals are always in lowest terms, thus equal values produce equal input regardless of how they were computed.
+/// Limbs are written as little-endian bytes, thus the input does not depend on the platform.
+pub(crate) fn hash_rational_canonically(value: &Rational, hasher: &mut FnvHasher) {
+    hasher.write_u8(if *value < 0u32 { 1 } else { 0 });
+    let numerator = value.numerator_ref().to_limbs_asc();
+    hasher.write(&(numerator.len() as u64).to_le_bytes());
+    for limb in numerator {
+        hasher.write(&limb.to_le_bytes());
+    }
+    let denominator = value.denominator_ref().to_limbs_asc();
+    hasher.write(&(denominator.len() as u64).to_le_bytes());
+    for limb in denominator {
+        hasher.write(&limb.to_le_bytes());
+    }
 }
 
 impl Default for FractionExact {
@@ -38,8 +64,8 @@ impl FromStr for FractionExact {
     type Err = Error;
 
     fn from_str(s: &str) -> std::prelude::v1::Result<Self, Self::Err> {
-        //rational
-        if let Ok(rational) = s.parse::<Rational>() {
+        //rational, decimal, scientific notation or percentage
+        if let Some(rational) = parse_rational(s)? {
             return Ok(Self(rational));
         }
 
@@ -10,7 +10,7 @@ use std::{
     sync::Arc,
 };
 
-use anyhow::{Error, anyhow};
+use anyhow::{Error, Result, anyhow};
 use malachite::{
     Natural,
     base::{
@@ -20,7 +20,11 @@ use malachite::{
     rational::Rational,
 };
 
-use crate::{ebi_number::Zero, fraction::fraction::EPSILON};
+use crate::{
+    ebi_number::Zero,
+    fraction::{fraction::EPSILON, ordered_fraction::f64_total_hash},
+    parsing::parse_rational,
+};
 
 #[derive(Debug, Clone, Copy)]
 pub struct FractionF64(pub(crate) f64);
@@ -32,6 +36,57 @@ impl FractionF64 {
         let result = Natural::binomial_coefficient(Natural::from(n), Natural::from(k));
         FractionF64(f64::rounding_from(&result, RoundingMode::Nearest).0)
     }
+
+    /// Divides `a` by `b`, rounding the quotient according to `rounding`.
+    /// For instance, [RoundingMode::Floor] yields a guaranteed lower bound and [RoundingMode::Ceiling] a guaranteed upper bound of the true quotient,
+    /// such that running a computation twice gives certified bounds.
+    /// Fails for [RoundingMode::Exact] if the quotient is not representable as an f64.
+    /// Non-finite inputs and division by zero follow IEEE 754.
+    pub fn div_with_rounding(a: Self, b: Self, rounding: RoundingMode) -> Result<Self> {
+        let (a, b) = (a.0, b.0);
+        let quotient = a / b;
+        if !a.is_finite() || !b.is_finite() || b == 0.0 || a == 0.0 {
+            return Ok(Self(quotient));
+        }
+
+        //below this dividend, the residual of the division may not be representable
+        const SAFE_DIVIDEND: f64 = f64::MIN_POSITIVE * 9007199254740992.0;
+
+        //compare the true quotient to the rounded-to-nearest quotient
+        let ordering = if quotient.is_normal() && a.abs() >= SAFE_DIVIDEND {
+            //a - quotient * b is exact, and has the sign of (true quotient - quotient) * b
+            let residual = (-quotient).mul_add(b, a) * b.signum();
+            residual.partial_cmp(&0.0).unwrap()
+        } else {
+            let exact = Rational::try_from(a).unwrap() / Rational::try_from(b).unwrap();
+            if quotient.is_infinite() {
+                //overflow: let malachite decide between the largest finite value and infinity
+                if rounding == RoundingMode::Exact {
+                    return Err(anyhow!("{} / {} is not representable as an f64", a, b));
+                }
+                return Ok(Self(f64::rounding_from(exact, rounding).0));
+            }
+            exact
+                .partial_cmp(&Rational::try_from(quotient).unwrap())
+                .unwrap()
+        };
+
+        let positive = (a > 0.0) == (b > 0.0);
+        let result = match (ordering, rounding) {
+            (Ordering::Equal, _) | (_, RoundingMode::Nearest) => quotient,
+            (_, RoundingMode::Exact) => {
+                return Err(anyhow!("{} / {} is not representable as an f64", a, b));
+            }
+            (Ordering::Greater, RoundingMode::Ceiling) => quotient.next_up(),
+            (Ordering::Greater, RoundingMode::Up) if positive => quotient.next_up(),
+            (Ordering::Greater, RoundingMode::Down) if !positive => quotient.next_up(),
+            (Ordering::Less, RoundingMode::Floor) => quotient.next_down(),
+            (Ordering::Less, RoundingMode::Up) if !positive => quotient.next_down(),
+            (Ordering::Less, RoundingMode::Down) if positive => quotient.next_down(),
+            _ => quotient,
+        };
+        Ok(Self(result))
+    }
 }
 
 impl Default for FractionF64 {
@@ -86,7 +141,11 @@ impl FromStr for FractionF64 {
             Ok(f) => Ok(Self(f64::rounding_from(f, Nearest).0)),
             Err(_) => match f64::from_str(s) {
                 Ok(f) => Ok(Self(f)),
-                Err(_) => Err(anyhow!("{} was not recognised as a fraction", s)),
+                //repeating decimals, percentages and underscores
+                Err(_) => match parse_rational(s)? {
+                    Some(f) => Ok(Self(f64::rounding_from(f, Nearest).0)),
+                    None => Err(anyhow!("{} was not recognised as a fraction", s)),
+                },
             },
         }
     }
@@ -135,9 +194,11 @@ impl Hash for FractionF64 {
     /**
      * For good reasons, Rust does not support hashing of doubles. However, we need it to store distributions in a hashmap.
      * Approximate arithmetic is discouraged
+     *
+     * Zeroes and NaNs hash equally, but values that are equal within EPSILON may not: use StrictEq as the key of a hashmap instead.
      */
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
-        f64::to_bits(self.0).hash(state)
+        f64_total_hash(self.0, state)
     }
 }
 
@@ -517,6 +578,7 @@ macro_rules! ttype_tuple_signed {
         from_tuple_i_u!($t, u32);
         from_tuple_i_u!($t, u16);
         from_tuple_i_u!($t, u8);
+        from_tuple_i_i!($t, i128);
         from_tuple_i_i!($t, i64);
         from_tuple_i_i!($t, i32);
         from_tuple_i_i!($t, i16);
@@ -570,11 +632,66 @@ ttype_signed!(i8);
 mod tests {
     use std::ops::Neg;
 
+    use malachite::{base::rounding_modes::RoundingMode, rational::Rational};
+
     use crate::{
         ebi_number::{One, Signed},
         fraction::fraction_f64::FractionF64,
     };
 
+    #[test]
+    fn div_with_rounding() {
+        let one = FractionF64::from(1.0);
+        let three = FractionF64::from(3.0);
+        let floor = FractionF64::div_with_rounding(one, three, RoundingMode::Floor).unwrap();
+        let ceiling = FractionF64::div_with_rounding(one, three, RoundingMode::Ceiling).unwrap();
+        let third = Rational::from_signeds(1, 3);
+        assert!(Rational::try_from(floor.0).unwrap() < third);
+        assert!(Rational::try_from(ceiling.0).unwrap() > third);
+        assert_eq!(floor.0.next_up(), ceiling.0);
+        assert!(FractionF64::div_with_rounding(one, three, RoundingMode::Exact).is_err());
+
+        let down = FractionF64::div_with_rounding(-one, three, RoundingMode::Down).unwrap();
+        assert_eq!(down.0, -floor.0);
+
+        let quarter = FractionF64::div_with_rounding(one, 4.0.into(), RoundingMode::Exact).unwrap();
+        assert_eq!(quarter.0, 0.25);
+
+        //underflow
+        let tiny = FractionF64::from(1e-300);
+        let huge = FractionF64::from(1e300);
+        assert_eq!(
+            FractionF64::div_with_rounding(tiny, huge, RoundingMode::Floor)
+                .unwrap()
+                .0,
+            0.0
+        );
+        assert_eq!(
+            FractionF64::div_with_rounding(tiny, huge, RoundingMode::Ceiling)
+                .unwrap()
+                .0,
+            f64::from_bits(1)
+        );
+
+        //overflow
+        assert_eq!(
+            FractionF64::div_with_rounding(huge, tiny, RoundingMode::Floor)
+                .unwrap()
+                .0,
+            f64::MAX
+        );
+    }
+
+    #[test]
+    fn from_signed_pairs() {
+        assert_eq!(FractionF64::from((1i128, 4i128)).0, 0.25);
+        assert_eq!(FractionF64::from((-1i8, 4i128)).0, -0.25);
+        assert_eq!(
+            FractionF64::from((1i128, 3i128)),
+            FractionF64::from((1u8, 3u8))
+        );
+    }
+
     #[test]
     fn fraction_neg() {
         let one = FractionF64::one();
@@ -0,0 +1,116 @@
+use anyhow::Result;
+use malachite::{
+    Integer, Natural,
+    base::num::{
+        arithmetic::traits::{DivExact, Gcd as MGcd, Lcm as MLcm},
+        basic::traits::One,
+    },
+    rational::Rational,
+};
+
+use crate::{exact::MaybeExact, fraction::fraction_exact::FractionExact};
+
+/// Returns the greatest common divisor of the integers, which is never negative.
+/// The greatest common divisor of zero and zero is zero.
+pub fn gcd(a: &Integer, b: &Integer) -> Integer {
+    Integer::from(a.unsigned_abs_ref().gcd(b.unsigned_abs_ref()))
+}
+
+/// Returns the least common multiple of the integers, which is never negative.
+/// The least common multiple of zero and any integer is zero.
+pub fn lcm(a: &Integer, b: &Integer) -> Integer {
+    Integer::from(a.unsigned_abs_ref().lcm(b.unsigned_abs_ref()))
+}
+
+/// Scales the values to integer numerators over their common denominator: the lcm of their denominators.
+pub(crate) fn over_common_denominator<'a>(
+    values: impl Iterator<Item = &'a Rational> + Clone,
+) -> (Vec<Integer>, Natural) {
+    let denominator = values
+        .clone()
+        .fold(Natural::ONE, |lcm, value| lcm.lcm(value.denominator_ref()));
+    let numerators = values
+        .map(|value| {
+            let factor = (&denominator).div_exact(value.denominator_ref());
+            Integer::from_sign_and_abs(*value >= 0u32, value.numerator_ref() * factor)
+        })
+        .collect();
+    (numerators, denominator)
+}
+
+/// Rewrites the fractions over their smallest common denominator, such that fraction i equals `numerators[i] / denominator`.
+/// Returns the numerators and the denominator, which is positive; for instance, to feed solvers that only accept integers.
+/// Returns an error if any of the fractions is approximate.
+pub fn common_denominator<F: MaybeExact<Exact = Rational>>(
+    fractions: &[F],
+) -> Result<(Vec<Integer>, Integer)> {
+    let values = fractions
+        .iter()
+        .map(|fraction| fraction.exact_ref())
+        .collect::<Result<Vec<_>>>()?;
+    let (numerators, denominator) = over_common_denominator(values.into_iter());
+    Ok((numerators, Integer::from(denominator)))
+}
+
+impl FractionExact {
+    /// Returns the greatest fraction of which both fractions are integer multiples, which is never negative:
+    /// the gcd of the numerators over the lcm of the denominators.
+    pub fn gcd(&self, other: &Self) -> Self {
+        Self(Rational::from_naturals(
+            self.0.numerator_ref().gcd(other.0.numerator_ref()),
+            self.0.denominator_ref().lcm(other.0.denominator_ref()),
+        ))
+    }
+
+    /// Returns the smallest non-negative fraction that is an integer multiple of both fractions:
+    /// the lcm of the numerators over the gcd of the denominators.
+    pub fn lcm(&self, other: &Self) -> Self {
+        Self(Rational::from_naturals(
+            self.0.numerator_ref().lcm(other.0.numerator_ref()),
+            self.0.denominator_ref().gcd(other.0.denominator_ref()),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use malachite::Integer;
+
+    use crate::fraction::{
+        fraction_enum::FractionEnum,
+        fraction_exact::FractionExact,
+        fraction_f64::FractionF64,
+        gcd::{common_denominator, gcd, lcm},
+    };
+
+    #[test]
+    fn gcd_lcm() {
+        assert_eq!(gcd(&Integer::from(-12), &Integer::from(18)), 6);
+        assert_eq!(lcm(&Integer::from(-4), &Integer::from(6)), 12);
+        assert_eq!(gcd(&Integer::from(0), &Integer::from(0)), 0);
+
+        let a = FractionExact::from((1, 2));
+        let b = FractionExact::from((-3, 4));
+        assert_eq!(a.gcd(&b), FractionExact::from((1, 4)));
+        assert_eq!(a.lcm(&b), FractionExact::from((3, 2)));
+
+        let (numerators, denominator) = common_denominator(&[
+            FractionExact::from((1, 2)),
+            FractionExact::from((-1, 3)),
+            FractionExact::from(2),
+        ])
+        .unwrap();
+        assert_eq!(
+            numerators,
+            vec![Integer::from(3), Integer::from(-2), Integer::from(12)]
+        );
+        assert_eq!(denominator, 6);
+        assert_eq!(
+            common_denominator::<FractionExact>(&[]).unwrap(),
+            (vec![], Integer::from(1))
+        );
+
+        assert!(common_denominator(&[FractionF64::from(0.5)]).is_err());
+        assert!(common_denominator(&[FractionEnum::CannotCombineExactAndApprox]).is_err());
+    }
+}
@@ -0,0 +1,324 @@
+use anyhow::{Result, anyhow};
+use std::{
+    fmt::Display,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+use crate::{
+    ebi_number::Zero,
+    fraction::{
+        fraction::Fraction, fraction_enum::FractionEnum, fraction_exact::FractionExact,
+        fraction_f64::FractionF64,
+    },
+};
+
+/// Rounding of a bound away from the interval, such that the rounding error of approximate arithmetic stays inside the bounds.
+/// Floats are rounded to the nearest float, thus the neighbouring float bounds the actual result; exact values need no rounding.
+pub trait OutwardRounding {
+    fn round_down(self) -> Self;
+
+    fn round_up(self) -> Self;
+}
+
+impl OutwardRounding for FractionF64 {
+    fn round_down(self) -> Self {
+        Self(self.0.next_down())
+    }
+
+    fn round_up(self) -> Self {
+        Self(self.0.next_up())
+    }
+}
+
+impl OutwardRounding for FractionExact {
+    fn round_down(self) -> Self {
+        self
+    }
+
+    fn round_up(self) -> Self {
+        self
+    }
+}
+
+impl OutwardRounding for FractionEnum {
+    fn round_down(self) -> Self {
+        match self {
+            FractionEnum::Approx(f) => FractionEnum::Approx(f.next_down()),
+            _ => self,
+        }
+    }
+
+    fn round_up(self) -> Self {
+        match self {
+            FractionEnum::Approx(f) => FractionEnum::Approx(f.next_up()),
+            _ => self,
+        }
+    }
+}
+
+/// A closed interval [lower, upper] of fractions, which contains the actual value of a computation.
+///
+/// With exact fractions, the bounds are exact and an interval of a single value stays a single value.
+/// With approximate fractions, each operation widens the bounds by the rounding error, such that the result is certified to contain the value that exact arithmetic would have computed.
+/// Approximate bounds are therefore always at least as wide as the exact ones.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FractionInterval<T = Fraction> {
+    pub(crate) lower: T,
+    pub(crate) upper: T,
+}
+
+impl<T> FractionInterval<T> {
+    pub fn lower(&self) -> &T {
+        &self.lower
+    }
+
+    pub fn upper(&self) -> &T {
+        &self.upper
+    }
+}
+
+impl<T: PartialOrd + Display> FractionInterval<T> {
+    /// Creates the interval [lower, upper].
+    /// Returns an error if the lower bound is larger than the upper bound.
+    pub fn new(lower: T, upper: T) -> Result<Self> {
+        if lower > upper {
+            return Err(anyhow!(
+                "the lower bound {} is larger than the upper bound {}",
+                lower,
+                upper
+            ));
+        }
+        Ok(Self { lower, upper })
+    }
+
+    /// Returns whether the value lies within the bounds.
+    pub fn contains(&self, value: &T) -> bool {
+        &self.lower <= value && value <= &self.upper
+    }
+}
+
+impl<T: Ord + Clone + OutwardRounding> FractionInterval<T> {
+    /// Returns the smallest interval that contains all the values, rounded outwards.
+    fn hull(values: [T; 4]) -> Self {
+        let lower = values.iter().min().unwrap().clone();
+        let upper = values.into_iter().max().unwrap();
+        Self {
+            lower: lower.round_down(),
+            upper: upper.round_up(),
+        }
+    }
+}
+
+impl<T: OutwardRounding> FractionInterval<T>
+where
+    for<'a> &'a T: Sub<&'a T, Output = T>,
+{
+    /// Returns the distance between the bounds, rounded up.
+    pub fn width(&self) -> T {
+        (&self.upper - &self.lower).round_up()
+    }
+}
+
+impl<T: Zero + Ord + Clone + Display + OutwardRounding> FractionInterval<T>
+where
+    for<'a> &'a T: Div<&'a T, Output = T>,
+{
+    /// Returns the quotient of the intervals.
+    /// Returns an error if the divisor contains zero.
+    pub fn checked_div(&self, rhs: &Self) -> Result<Self> {
+        let zero = T::zero();
+        if rhs.lower <= zero && zero <= rhs.upper {
+            return Err(anyhow!(
+                "cannot divide by the interval {}, which contains zero",
+                rhs
+            ));
+        }
+        Ok(Self::hull([
+            &self.lower / &rhs.lower,
+            &self.lower / &rhs.upper,
+            &self.upper / &rhs.lower,
+            &self.upper / &rhs.upper,
+        ]))
+    }
+}
+
+impl<T: Display> Display for FractionInterval<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}, {}]", self.lower, self.upper)
+    }
+}
+
+/// The interval that contains only the value.
+impl<T: Clone> From<T> for FractionInterval<T> {
+    fn from(value: T) -> Self {
+        Self {
+            lower: value.clone(),
+            upper: value,
+        }
+    }
+}
+
+macro_rules! interval {
+    ($t:ident) => {
+        impl Add<&FractionInterval<$t>> for &FractionInterval<$t> {
+            type Output = FractionInterval<$t>;
+
+            fn add(self, rhs: &FractionInterval<$t>) -> Self::Output {
+                FractionInterval {
+                    lower: (&self.lower + &rhs.lower).round_down(),
+                    upper: (&self.upper + &rhs.upper).round_up(),
+                }
+            }
+        }
+
+        impl Add<FractionInterval<$t>> for FractionInterval<$t> {
+            type Output = FractionInterval<$t>;
+
+            fn add(self, rhs: FractionInterval<$t>) -> Self::Output {
+                &self + &rhs
+            }
+        }
+
+        impl Sub<&FractionInterval<$t>> for &FractionInterval<$t> {
+            type Output = FractionInterval<$t>;
+
+            fn sub(self, rhs: &FractionInterval<$t>) -> Self::Output {
+                FractionInterval {
+                    lower: (&self.lower - &rhs.upper).round_down(),
+                    upper: (&self.upper - &rhs.lower).round_up(),
+                }
+            }
+        }
+
+        impl Sub<FractionInterval<$t>> for FractionInterval<$t> {
+            type Output = FractionInterval<$t>;
+
+            fn sub(self, rhs: FractionInterval<$t>) -> Self::Output {
+                &self - &rhs
+            }
+        }
+
+        impl Mul<&FractionInterval<$t>> for &FractionInterval<$t> {
+            type Output = FractionInterval<$t>;
+
+            fn mul(self, rhs: &FractionInterval<$t>) -> Self::Output {
+                FractionInterval::hull([
+                    &self.lower * &rhs.lower,
+                    &self.lower * &rhs.upper,
+                    &self.upper * &rhs.lower,
+                    &self.upper * &rhs.upper,
+                ])
+            }
+        }
+
+        impl Mul<FractionInterval<$t>> for FractionInterval<$t> {
+            type Output = FractionInterval<$t>;
+
+            fn mul(self, rhs: FractionInterval<$t>) -> Self::Output {
+                &self * &rhs
+            }
+        }
+
+        /// Panics if the divisor contains zero; see [FractionInterval::checked_div] for a non-panicking variant.
+        impl Div<&FractionInterval<$t>> for &FractionInterval<$t> {
+            type Output = FractionInterval<$t>;
+
+            fn div(self, rhs: &FractionInterval<$t>) -> Self::Output {
+                self.checked_div(rhs).unwrap()
+            }
+        }
+
+        impl Div<FractionInterval<$t>> for FractionInterval<$t> {
+            type Output = FractionInterval<$t>;
+
+            fn div(self, rhs: FractionInterval<$t>) -> Self::Output {
+                &self / &rhs
+            }
+        }
+
+        impl Neg for &FractionInterval<$t> {
+            type Output = FractionInterval<$t>;
+
+            fn neg(self) -> Self::Output {
+                FractionInterval {
+                    lower: -&self.upper,
+                    upper: -&self.lower,
+                }
+            }
+        }
+
+        impl Neg for FractionInterval<$t> {
+            type Output = FractionInterval<$t>;
+
+            fn neg(self) -> Self::Output {
+                -&self
+            }
+        }
+    };
+}
+
+interval!(FractionF64);
+interval!(FractionExact);
+interval!(FractionEnum);
+
+#[cfg(test)]
+mod tests {
+    use malachite::rational::Rational;
+
+    use crate::fraction::{
+        fraction_exact::FractionExact, fraction_f64::FractionF64, interval::FractionInterval,
+    };
+
+    #[test]
+    fn interval_exact() {
+        let a = FractionInterval::new(FractionExact::from(1), FractionExact::from(2)).unwrap();
+        let b =
+            FractionInterval::new(FractionExact::from(-3), FractionExact::from((1, 2))).unwrap();
+
+        assert_eq!(
+            &a + &b,
+            FractionInterval::new((-2).into(), (5, 2).into()).unwrap()
+        );
+        assert_eq!(
+            &a - &b,
+            FractionInterval::new((1, 2).into(), 5.into()).unwrap()
+        );
+        assert_eq!(
+            &a * &b,
+            FractionInterval::new((-6).into(), 1.into()).unwrap()
+        );
+        assert_eq!(
+            -&b,
+            FractionInterval::new((-1, 2).into(), 3.into()).unwrap()
+        );
+        assert_eq!(
+            &b / &a,
+            FractionInterval::new((-3).into(), (1, 2).into()).unwrap()
+        );
+        assert!(a.checked_div(&b).is_err());
+
+        //a single exact value stays a single value
+        let third = FractionInterval::from(FractionExact::from((1, 3)));
+        assert_eq!(
+            &third + &third,
+            FractionInterval::from(FractionExact::from((2, 3)))
+        );
+
+        assert!(FractionInterval::new(FractionExact::from(1), FractionExact::from(0)).is_err());
+        assert_eq!(a.to_string(), "[1, 2]");
+    }
+
+    #[test]
+    fn interval_f64_certified() {
+        //0.1 is not representable, and repeated addition accumulates rounding errors
+        let tenth = FractionInterval::from(FractionF64::from(0.1));
+        let mut sum = FractionInterval::from(FractionF64::from(0.0));
+        for _ in 0..10 {
+            sum = &sum + &tenth;
+        }
+        let exact_sum = Rational::try_from(0.1f64).unwrap() * Rational::from(10);
+        assert!(Rational::try_from(sum.lower().0).unwrap() <= exact_sum);
+        assert!(Rational::try_from(sum.upper().0).unwrap() >= exact_sum);
+        assert!(sum.width().0 < 1e-14);
+    }
+}
@@ -0,0 +1,88 @@
+use anyhow::{Result, anyhow};
+use malachite::{Natural, rational::Rational};
+
+use crate::fraction::{
+    approximate::Approximate, exact::float_to_rational, fraction_enum::FractionEnum,
+    fraction_exact::FractionExact,
+};
+
+/// Returns the closest fraction with a denominator of at most `max_denominator`, found with continued fractions.
+fn limit_denominator(value: &Rational, max_denominator: u64) -> Result<Rational> {
+    if max_denominator == 0 {
+        return Err(anyhow!("the maximum denominator must be positive"));
+    }
+    Ok(
+        malachite::rational::arithmetic::traits::Approximate::approximate(
+            value,
+            &Natural::from(max_denominator),
+        ),
+    )
+}
+
+impl FractionExact {
+    /// Returns the fraction closest to this value whose denominator does not exceed `max_denominator`.
+    /// Of two equally close fractions, the one with the smaller denominator is returned.
+    /// Intended to keep denominators small mid-computation, at the cost of precision.
+    /// Returns an error if `max_denominator` is zero.
+    pub fn limit_denominator(&self, max_denominator: u64) -> Result<Self> {
+        Ok(Self(limit_denominator(&self.0, max_denominator)?))
+    }
+}
+
+impl FractionEnum {
+    /// Returns the fraction closest to this value whose denominator does not exceed `max_denominator`.
+    /// Approximate values are limited as the exact value of their float, and rounded back to the nearest float.
+    /// Returns an error if `max_denominator` is zero, if the value is not finite, or if it combines exact and approximate arithmetic.
+    pub fn limit_denominator(&self, max_denominator: u64) -> Result<Self> {
+        match self {
+            FractionEnum::Exact(f) => {
+                Ok(FractionEnum::Exact(limit_denominator(f, max_denominator)?))
+            }
+            FractionEnum::Approx(f) => Ok(FractionEnum::Approx(Approximate::approximate(
+                limit_denominator(&float_to_rational(*f)?, max_denominator)?,
+            )?)),
+            FractionEnum::CannotCombineExactAndApprox => {
+                Err(anyhow!("cannot combine exact and approximate arithmetic"))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fraction::{fraction_enum::FractionEnum, fraction_exact::FractionExact};
+
+    #[test]
+    fn limit_denominator() {
+        let pi = FractionExact::from((3_141_592_653_589_793u64, 1_000_000_000_000_000u64));
+        assert_eq!(
+            pi.limit_denominator(10).unwrap(),
+            FractionExact::from((22, 7))
+        );
+        assert_eq!(
+            pi.limit_denominator(1000).unwrap(),
+            FractionExact::from((355, 113))
+        );
+        assert_eq!(pi.limit_denominator(1).unwrap(), FractionExact::from(3));
+        assert!(pi.limit_denominator(0).is_err());
+
+        //values that already satisfy the bound are unchanged
+        let x = FractionExact::from((-5, 12));
+        assert_eq!(x.limit_denominator(12).unwrap(), x);
+        assert_eq!(
+            x.limit_denominator(5).unwrap(),
+            FractionExact::from((-2, 5))
+        );
+
+        let x = FractionEnum::Approx(1.0 / 3.0);
+        assert_eq!(
+            x.limit_denominator(100).unwrap(),
+            FractionEnum::Approx(1.0 / 3.0)
+        );
+        assert!(
+            FractionEnum::CannotCombineExactAndApprox
+                .limit_denominator(5)
+                .is_err()
+        );
+    }
+}
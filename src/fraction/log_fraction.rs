@@ -0,0 +1,313 @@
+use anyhow::{Error, Result, anyhow};
+use malachite::{base::num::basic::traits::Zero as MZero, rational::Rational};
+use std::{
+    f64::consts::LN_2,
+    fmt::Display,
+    iter::{Product, Sum},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign},
+};
+
+use crate::{
+    One, Round, ToNative, Zero,
+    fraction::{
+        approximate::Approximate, fraction_enum::FractionEnum, fraction_exact::FractionExact,
+        fraction_f64::FractionF64,
+    },
+};
+
+/// A non-negative number that is stored as its natural logarithm ln(p).
+///
+/// Products of thousands of probabilities underflow an f64 and blow up the denominators of exact fractions.
+/// In the log domain, a product is a sum of logarithms, which does neither.
+/// Addition is performed with log-sum-exp, which is accurate as long as the sum is dominated by its largest terms.
+///
+/// Negative numbers cannot be represented: there is no subtraction, and converting a negative value fails.
+/// Zero is stored as negative infinity.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct LogFraction(pub(crate) f64);
+
+impl LogFraction {
+    /// Returns the number e^`ln`.
+    pub fn from_ln(ln: f64) -> Self {
+        Self(ln)
+    }
+
+    /// Returns the natural logarithm of the number; negative infinity for zero.
+    pub fn ln(&self) -> f64 {
+        self.0
+    }
+}
+
+/// Returns ln(e^a + e^b) without leaving the log domain.
+fn log_sum_exp(a: f64, b: f64) -> f64 {
+    let (max, min) = if a >= b { (a, b) } else { (b, a) };
+    if min == f64::NEG_INFINITY || max == f64::INFINITY {
+        max
+    } else {
+        max + (min - max).exp().ln_1p()
+    }
+}
+
+impl TryFrom<f64> for LogFraction {
+    type Error = Error;
+
+    fn try_from(value: f64) -> Result<Self> {
+        if value.is_nan() || value < 0.0 {
+            return Err(anyhow!("cannot take the logarithm of {}", value));
+        }
+        Ok(Self(value.ln()))
+    }
+}
+
+impl TryFrom<&FractionF64> for LogFraction {
+    type Error = Error;
+
+    fn try_from(value: &FractionF64) -> Result<Self> {
+        Self::try_from(value.0)
+    }
+}
+
+/// Values that are too small or too large for an f64 are converted without underflow or overflow.
+impl TryFrom<&Rational> for LogFraction {
+    type Error = Error;
+
+    fn try_from(value: &Rational) -> Result<Self> {
+        if *value < Rational::ZERO {
+            Err(anyhow!("cannot take the logarithm of {}", value))
+        } else if *value == Rational::ZERO {
+            Ok(Self::zero())
+        } else {
+            Ok(Self(value.approx_log()))
+        }
+    }
+}
+
+impl TryFrom<&FractionExact> for LogFraction {
+    type Error = Error;
+
+    fn try_from(value: &FractionExact) -> Result<Self> {
+        Self::try_from(&value.0)
+    }
+}
+
+impl TryFrom<&FractionEnum> for LogFraction {
+    type Error = Error;
+
+    fn try_from(value: &FractionEnum) -> Result<Self> {
+        match value {
+            FractionEnum::Exact(f) => Self::try_from(f),
+            FractionEnum::Approx(f) => Self::try_from(*f),
+            FractionEnum::CannotCombineExactAndApprox => {
+                Err(anyhow!("cannot combine exact and approximate arithmetic"))
+            }
+        }
+    }
+}
+
+/// Returns e^ln(p), which underflows to zero for very small numbers and overflows to infinity for very large numbers.
+impl Approximate for LogFraction {
+    fn approximate(self) -> Result<f64> {
+        Ok(self.0.exp())
+    }
+}
+
+impl Zero for LogFraction {
+    fn zero() -> Self {
+        Self(f64::NEG_INFINITY)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == f64::NEG_INFINITY
+    }
+}
+
+impl One for LogFraction {
+    fn one() -> Self {
+        Self(0.0)
+    }
+
+    fn is_one(&self) -> bool {
+        self.0.is_zero()
+    }
+}
+
+impl Round for LogFraction {
+    fn floor(self) -> Self {
+        self.round_with(f64::floor)
+    }
+
+    fn ceil(self) -> Self {
+        self.round_with(f64::ceil)
+    }
+
+    fn round_half_away_from_zero(self) -> Self {
+        self.round_with(f64::round)
+    }
+
+    fn round_half_to_even(self) -> Self {
+        self.round_with(f64::round_ties_even)
+    }
+
+    /// The number is not negative, so truncating it is taking its floor.
+    fn trunc(self) -> Self {
+        self.floor()
+    }
+
+    /// Rounds e^ln(p) as an f64, such that numbers below the smallest positive f64 round to zero.
+    fn round_to_multiple_of(self, multiple: &Self) -> Result<Self> {
+        if multiple.is_zero() {
+            return Err(anyhow!("cannot round to a multiple of zero"));
+        }
+        Ok(Self(
+            self.0.exp().round_to_multiple_of(&multiple.0.exp())?.ln(),
+        ))
+    }
+
+    fn round_to_decimal_places(self, decimal_places: u32) -> Self {
+        self.round_with(|value| value.round_to_decimal_places(decimal_places))
+    }
+}
+
+impl LogFraction {
+    /// Numbers above 2^53 have no fractional part in an f64, and are thus returned as is.
+    fn round_with(self, round: impl Fn(f64) -> f64) -> Self {
+        if self.0 >= f64::MANTISSA_DIGITS as f64 * LN_2 {
+            self
+        } else {
+            Self(round(self.0.exp()).ln())
+        }
+    }
+}
+
+impl ToNative for LogFraction {
+    fn to_usize(&self) -> usize {
+        self.0.exp().to_usize()
+    }
+}
+
+macro_rules! log_op {
+    ($op:ident, $fn:ident, $assign_op:ident, $assign_fn:ident, $combine:expr) => {
+        impl $op<&LogFraction> for &LogFraction {
+            type Output = LogFraction;
+
+            fn $fn(self, rhs: &LogFraction) -> Self::Output {
+                LogFraction($combine(self.0, rhs.0))
+            }
+        }
+
+        impl $op<LogFraction> for LogFraction {
+            type Output = LogFraction;
+
+            fn $fn(self, rhs: LogFraction) -> Self::Output {
+                LogFraction($combine(self.0, rhs.0))
+            }
+        }
+
+        impl $assign_op<&LogFraction> for LogFraction {
+            fn $assign_fn(&mut self, rhs: &LogFraction) {
+                self.0 = $combine(self.0, rhs.0);
+            }
+        }
+
+        impl $assign_op<LogFraction> for LogFraction {
+            fn $assign_fn(&mut self, rhs: LogFraction) {
+                self.0 = $combine(self.0, rhs.0);
+            }
+        }
+    };
+}
+
+log_op!(Add, add, AddAssign, add_assign, log_sum_exp);
+log_op!(Mul, mul, MulAssign, mul_assign, |a: f64, b: f64| a + b);
+log_op!(Div, div, DivAssign, div_assign, |a: f64, b: f64| a - b);
+
+/// Sums all terms relative to the largest term, which is more accurate than adding the terms one by one.
+impl<'a> Sum<&'a LogFraction> for LogFraction {
+    fn sum<I: Iterator<Item = &'a LogFraction>>(iter: I) -> Self {
+        let terms = iter.map(|term| term.0).collect::<Vec<_>>();
+        let max = terms.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        if max.is_infinite() {
+            return Self(max);
+        }
+        Self(
+            max + terms
+                .iter()
+                .map(|term| (term - max).exp())
+                .sum::<f64>()
+                .ln(),
+        )
+    }
+}
+
+impl Sum for LogFraction {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.collect::<Vec<_>>().iter().sum()
+    }
+}
+
+impl<'a> Product<&'a LogFraction> for LogFraction {
+    fn product<I: Iterator<Item = &'a LogFraction>>(iter: I) -> Self {
+        Self(iter.map(|factor| factor.0).sum())
+    }
+}
+
+impl Product for LogFraction {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Self(iter.map(|factor| factor.0).sum())
+    }
+}
+
+/// Numbers that an f64 can represent are shown as such; others as a power of e.
+impl Display for LogFraction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let value = self.0.exp();
+        if self.is_zero() || value.is_normal() {
+            write!(f, "{}", value)
+        } else {
+            write!(f, "e^{}", self.0)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use malachite::{base::num::arithmetic::traits::PowerOf2, rational::Rational};
+
+    use crate::{
+        One, Round, ToNative, Zero,
+        fraction::{
+            approximate::Approximate, fraction_exact::FractionExact, log_fraction::LogFraction,
+        },
+    };
+
+    #[test]
+    fn log_fraction() {
+        let quarter = LogFraction::try_from(0.25).unwrap();
+        let half = quarter + quarter;
+        assert!((half.approximate().unwrap() - 0.5).abs() < 1e-15);
+        assert!(((quarter * half).approximate().unwrap() - 0.125).abs() < 1e-15);
+        assert!(((quarter / half).approximate().unwrap() - 0.5).abs() < 1e-15);
+
+        assert_eq!(quarter + LogFraction::zero(), quarter);
+        assert_eq!(quarter * LogFraction::one(), quarter);
+        assert!(LogFraction::try_from(-0.5).is_err());
+
+        //a product that underflows an f64
+        let product = std::iter::repeat_n(half, 2000).product::<LogFraction>();
+        assert_eq!(product.approximate().unwrap(), 0.0);
+        assert!(!product.is_zero());
+        assert!((product.ln() - 2000.0 * 0.5f64.ln()).abs() < 1e-9);
+
+        //exact values that underflow an f64 are converted via their logarithm
+        let tiny = LogFraction::try_from(&Rational::power_of_2(-5000i64)).unwrap();
+        assert!((tiny.ln() + 5000.0 * std::f64::consts::LN_2).abs() < 1e-9);
+        assert!(tiny.to_string().starts_with("e^-3465.7"));
+        let sum: LogFraction = [tiny, tiny, tiny, tiny].iter().sum();
+        assert!((sum.ln() + 4998.0 * std::f64::consts::LN_2).abs() < 1e-9);
+
+        let x = LogFraction::try_from(&FractionExact::from((7, 2))).unwrap();
+        assert!((x.floor().approximate().unwrap() - 3.0).abs() < 1e-12);
+        assert_eq!(x.to_usize(), 4);
+        assert_eq!(LogFraction::zero().to_string(), "0");
+    }
+}
@@ -0,0 +1,115 @@
+use anyhow::{Result, anyhow};
+use malachite::{
+    Natural,
+    base::{
+        num::{
+            arithmetic::traits::{Abs, CheckedRoot, FloorRoot, Pow},
+            conversion::traits::RoundingFrom,
+        },
+        rounding_modes::RoundingMode,
+    },
+    rational::Rational,
+};
+
+use crate::{
+    NthRoot,
+    fraction::{
+        fraction_enum::FractionEnum, fraction_exact::FractionExact, fraction_f64::FractionF64,
+    },
+};
+
+fn check_degree(n: u32) -> Result<()> {
+    if n == 0 {
+        return Err(anyhow!("cannot calculate the zeroth root of a value"));
+    }
+    Ok(())
+}
+
+impl NthRoot for f64 {
+    fn nth_root_abs(&self, n: u32, _decimal_places: u32) -> Result<Self> {
+        check_degree(n)?;
+        Ok(match n {
+            1 => self.abs(),
+            2 => self.abs().sqrt(),
+            3 => self.abs().cbrt(),
+            _ => self.abs().powf(1.0 / n as f64),
+        })
+    }
+}
+
+impl NthRoot for Rational {
+    /// Scales the value by 10^(n * `decimal_places`), such that the integer n-th root of its floor is the root of the value, truncated to `decimal_places` decimals.
+    fn nth_root_abs(&self, n: u32, decimal_places: u32) -> Result<Self> {
+        check_degree(n)?;
+        let value = self.abs();
+        if let Some(root) = (&value).checked_root(n as u64) {
+            return Ok(root);
+        }
+
+        let scale = Natural::from(10u32).pow(decimal_places as u64);
+        let scaled = value * Rational::from((&scale).pow(n as u64));
+        let floor = Natural::rounding_from(scaled, RoundingMode::Floor).0;
+        Ok(Rational::from_naturals(floor.floor_root(n as u64), scale))
+    }
+}
+
+impl NthRoot for FractionF64 {
+    fn nth_root_abs(&self, n: u32, decimal_places: u32) -> Result<Self> {
+        Ok(Self(self.0.nth_root_abs(n, decimal_places)?))
+    }
+}
+
+impl NthRoot for FractionExact {
+    fn nth_root_abs(&self, n: u32, decimal_places: u32) -> Result<Self> {
+        Ok(Self(self.0.nth_root_abs(n, decimal_places)?))
+    }
+}
+
+impl NthRoot for FractionEnum {
+    fn nth_root_abs(&self, n: u32, decimal_places: u32) -> Result<Self> {
+        match self {
+            FractionEnum::Exact(f) => Ok(FractionEnum::Exact(f.nth_root_abs(n, decimal_places)?)),
+            FractionEnum::Approx(f) => Ok(FractionEnum::Approx(f.nth_root_abs(n, decimal_places)?)),
+            FractionEnum::CannotCombineExactAndApprox => {
+                Err(anyhow!("cannot combine exact and approximate arithmetic"))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use malachite::{base::num::arithmetic::traits::Pow, rational::Rational};
+
+    use crate::{
+        NthRoot,
+        fraction::{fraction_exact::FractionExact, fraction_f64::FractionF64},
+    };
+
+    #[test]
+    fn nth_root_abs() {
+        //perfect powers are exact
+        assert_eq!(
+            FractionExact::from((-8, 27)).nth_root_abs(3, 2).unwrap(),
+            FractionExact::from((2, 3))
+        );
+        assert_eq!(
+            FractionExact::from((1, 16)).nth_root_abs(4, 0).unwrap(),
+            FractionExact::from((1, 2))
+        );
+
+        //geometric mean of the probabilities of a trace of length 5
+        let product =
+            Rational::from_signeds(1, 2).pow(3u64) * Rational::from_signeds(1, 3).pow(2u64);
+        let root = product.nth_root_abs(5, 10).unwrap();
+        let lower = &root;
+        let upper = &root + Rational::from_signeds(1, 10i64.pow(10));
+        assert!(lower.pow(5u64) <= product && upper.pow(5u64) > product);
+
+        assert_eq!(
+            FractionF64::from(-32.0).nth_root_abs(5, 0).unwrap(),
+            FractionF64::from(2.0)
+        );
+        assert!(FractionExact::from(2).nth_root_abs(0, 3).is_err());
+    }
+}
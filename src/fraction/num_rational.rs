@@ -0,0 +1,152 @@
+use anyhow::{Error, Result, anyhow};
+use malachite::{
+    Integer, Natural,
+    base::{num::conversion::traits::RoundingFrom, rounding_modes::RoundingMode},
+    rational::Rational,
+};
+use num_bigint::{BigInt, BigUint, Sign};
+use num_rational::BigRational;
+
+use crate::{
+    exact::is_exact_globally,
+    fraction::{
+        exact::float_to_rational, fraction_enum::FractionEnum, fraction_exact::FractionExact,
+    },
+};
+
+fn big_int_to_integer(value: &BigInt) -> Integer {
+    let (sign, limbs) = value.to_u64_digits();
+    let magnitude = Integer::from(Natural::from_owned_limbs_asc(limbs));
+    if sign == Sign::Minus {
+        -magnitude
+    } else {
+        magnitude
+    }
+}
+
+fn natural_to_big_int(value: &Natural, sign: Sign) -> BigInt {
+    let digits = value
+        .to_limbs_asc()
+        .into_iter()
+        .flat_map(|limb| [limb as u32, (limb >> 32) as u32])
+        .collect();
+    BigInt::from_biguint(sign, BigUint::new(digits))
+}
+
+fn big_rational_to_rational(value: &BigRational) -> Rational {
+    Rational::from_integers(
+        big_int_to_integer(value.numer()),
+        big_int_to_integer(value.denom()),
+    )
+}
+
+fn rational_to_big_rational(value: &Rational) -> BigRational {
+    let sign = if *value < 0u32 {
+        Sign::Minus
+    } else {
+        Sign::Plus
+    };
+    //malachite keeps the fraction reduced, with a positive denominator
+    BigRational::new_raw(
+        natural_to_big_int(value.numerator_ref(), sign),
+        natural_to_big_int(value.denominator_ref(), Sign::Plus),
+    )
+}
+
+impl From<&BigRational> for FractionExact {
+    fn from(value: &BigRational) -> Self {
+        Self(big_rational_to_rational(value))
+    }
+}
+
+impl From<BigRational> for FractionExact {
+    fn from(value: BigRational) -> Self {
+        Self::from(&value)
+    }
+}
+
+/// The value exactly in exact mode, or rounded to the nearest float in approximate mode.
+impl From<&BigRational> for FractionEnum {
+    fn from(value: &BigRational) -> Self {
+        let value = big_rational_to_rational(value);
+        if is_exact_globally() {
+            FractionEnum::Exact(value)
+        } else {
+            FractionEnum::Approx(f64::rounding_from(value, RoundingMode::Nearest).0)
+        }
+    }
+}
+
+impl From<BigRational> for FractionEnum {
+    fn from(value: BigRational) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl From<&FractionExact> for BigRational {
+    fn from(value: &FractionExact) -> Self {
+        rational_to_big_rational(&value.0)
+    }
+}
+
+impl From<FractionExact> for BigRational {
+    fn from(value: FractionExact) -> Self {
+        Self::from(&value)
+    }
+}
+
+/// Approximate values are converted to the exact value of their float.
+/// Fails for values that are not finite, and for values that combine exact and approximate arithmetic.
+impl TryFrom<&FractionEnum> for BigRational {
+    type Error = Error;
+
+    fn try_from(value: &FractionEnum) -> Result<Self> {
+        match value {
+            FractionEnum::Exact(f) => Ok(rational_to_big_rational(f)),
+            FractionEnum::Approx(f) => Ok(rational_to_big_rational(&float_to_rational(*f)?)),
+            FractionEnum::CannotCombineExactAndApprox => {
+                Err(anyhow!("cannot combine exact and approximate arithmetic"))
+            }
+        }
+    }
+}
+
+impl TryFrom<FractionEnum> for BigRational {
+    type Error = Error;
+
+    fn try_from(value: FractionEnum) -> Result<Self> {
+        Self::try_from(&value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigInt;
+    use num_rational::BigRational;
+    use std::str::FromStr;
+
+    use crate::fraction::{fraction_enum::FractionEnum, fraction_exact::FractionExact};
+
+    #[test]
+    fn big_rational_round_trip() {
+        let r = BigRational::new(
+            BigInt::from_str("-123456789012345678901234567890").unwrap(),
+            BigInt::from(7),
+        );
+        let f = FractionExact::from(&r);
+        assert_eq!(
+            f,
+            FractionExact::from_str("-123456789012345678901234567890/7").unwrap()
+        );
+        assert_eq!(BigRational::from(&f), r);
+
+        //unreduced values with a negative denominator
+        let r = BigRational::new_raw(BigInt::from(6), BigInt::from(-4));
+        assert_eq!(FractionExact::from(r), FractionExact::from((-3, 2)));
+
+        let r: BigRational = FractionEnum::Approx(0.375).try_into().unwrap();
+        assert_eq!(r, BigRational::new(BigInt::from(3), BigInt::from(8)));
+        assert!(BigRational::try_from(FractionEnum::Approx(f64::NAN)).is_err());
+        assert!(BigRational::try_from(FractionEnum::CannotCombineExactAndApprox).is_err());
+    }
+}
@@ -0,0 +1,126 @@
+use anyhow::{Result, anyhow};
+
+use crate::{
+    Exponential, One, OneMinus, Signed,
+    fraction::{
+        fraction_enum::FractionEnum, fraction_exact::FractionExact, fraction_f64::FractionF64,
+    },
+};
+
+/// Conversions between probabilities, odds and log-odds.
+pub trait Odds: Sized {
+    /// Returns the odds p / (1 - p) of the probability p.
+    /// Returns an error if the value is not in [0, 1).
+    fn to_odds(&self) -> Result<Self>;
+
+    /// Returns the probability o / (1 + o) of the odds o.
+    /// Returns an error if the odds are negative.
+    fn from_odds(odds: &Self) -> Result<Self>;
+
+    /// Returns the log-odds ln(p / (1 - p)) of the probability p, within 1/10^`precision_decimals` of the actual value.
+    /// Returns an error if the value is not in (0, 1).
+    fn logit(&self, precision_decimals: u32) -> Result<Self>;
+
+    /// Returns the probability 1 / (1 + e^-x) of the log-odds x, within 1/10^`precision_decimals` of the actual value.
+    /// This is the inverse of [Odds::logit].
+    fn sigmoid(&self, precision_decimals: u32) -> Result<Self>;
+}
+
+macro_rules! odds {
+    ($t:ident) => {
+        impl Odds for $t {
+            fn to_odds(&self) -> Result<Self> {
+                let complement = self.clone().one_minus();
+                if self.is_negative() || !complement.is_positive() {
+                    return Err(anyhow!(
+                        "cannot take the odds of {}, which is not a probability below one",
+                        self
+                    ));
+                }
+                Ok(self / &complement)
+            }
+
+            fn from_odds(odds: &Self) -> Result<Self> {
+                if odds.is_negative() {
+                    return Err(anyhow!("odds cannot be negative, but got {}", odds));
+                }
+                Ok(odds / &(odds + &$t::one()))
+            }
+
+            fn logit(&self, precision_decimals: u32) -> Result<Self> {
+                if !self.is_positive() {
+                    return Err(anyhow!(
+                        "cannot take the log-odds of {}, which is not a positive probability",
+                        self
+                    ));
+                }
+                self.to_odds()?.ln(precision_decimals)
+            }
+
+            fn sigmoid(&self, precision_decimals: u32) -> Result<Self> {
+                //take the exponent of a non-positive value, such that e <= 1 and an error in e is not amplified
+                if self.is_negative() {
+                    let e = self.exp(precision_decimals)?;
+                    Ok(&e / &(&e + &$t::one()))
+                } else {
+                    let e = (-self).exp(precision_decimals)?;
+                    Ok(&$t::one() / &(&e + &$t::one()))
+                }
+            }
+        }
+    };
+}
+
+odds!(FractionF64);
+odds!(FractionExact);
+odds!(FractionEnum);
+
+#[cfg(test)]
+mod tests {
+    use crate::fraction::{
+        approximate::Approximate, fraction_exact::FractionExact, fraction_f64::FractionF64,
+        odds::Odds,
+    };
+
+    #[test]
+    fn odds() {
+        let p = FractionExact::from((1, 4));
+        let odds = p.to_odds().unwrap();
+        assert_eq!(odds, FractionExact::from((1, 3)));
+        assert_eq!(FractionExact::from_odds(&odds).unwrap(), p);
+        assert_eq!(
+            FractionExact::from(0).to_odds().unwrap(),
+            FractionExact::from(0)
+        );
+        assert!(FractionExact::from(1).to_odds().is_err());
+        assert!(FractionExact::from((-1, 2)).to_odds().is_err());
+        assert!(FractionExact::from_odds(&FractionExact::from(-1)).is_err());
+
+        let p = FractionF64::from(0.2);
+        assert_eq!(p.to_odds().unwrap(), FractionF64::from(0.25));
+        assert_eq!(FractionF64::from_odds(&FractionF64::from(0.25)).unwrap(), p);
+    }
+
+    #[test]
+    fn logit() {
+        let p = FractionExact::from((3, 4));
+        let x = p.logit(12).unwrap();
+        assert!((x.clone().approximate().unwrap() - 3f64.ln()).abs() < 1e-12);
+        let q = x.sigmoid(12).unwrap();
+        assert!((q - p).approximate().unwrap().abs() < 1e-11);
+
+        assert_eq!(
+            FractionExact::from((1, 2)).logit(5).unwrap(),
+            FractionExact::from(0)
+        );
+        assert_eq!(
+            FractionExact::from(0).sigmoid(5).unwrap(),
+            FractionExact::from((1, 2))
+        );
+        assert!(FractionExact::from(0).logit(5).is_err());
+        assert!(FractionExact::from(1).logit(5).is_err());
+
+        let x = FractionF64::from(-2.0).sigmoid(0).unwrap();
+        assert_eq!(x, FractionF64::from(1.0 / (1.0 + 2f64.exp())));
+    }
+}
@@ -0,0 +1,276 @@
+use std::{
+    cmp::Ordering,
+    fmt::Display,
+    hash::{Hash, Hasher},
+};
+
+use crate::fraction::{
+    fraction_enum::FractionEnum, fraction_exact::FractionExact, fraction_f64::FractionF64,
+};
+
+/// A total order and a hash that agree with each other, for every value of a backend.
+///
+/// The policy is the same for all backends:
+/// - values are compared without tolerance, and -0 equals 0;
+/// - negative infinity is smaller than every other value, and positive infinity is larger than every finite value;
+/// - all NaNs are equal to each other and larger than every other value;
+/// - for [FractionEnum], all exact values are smaller than all approximate values, which are smaller than [FractionEnum::CannotCombineExactAndApprox].
+///
+/// Usually, [OrderedFraction] is more convenient to work with.
+pub trait TotalOrd {
+    fn total_cmp(&self, other: &Self) -> Ordering;
+
+    /// Feeds the value to the hasher, such that values that are equal according to [TotalOrd::total_cmp] have equal hashes.
+    fn total_hash<H: Hasher>(&self, state: &mut H);
+}
+
+fn f64_total_cmp(x: f64, y: f64) -> Ordering {
+    match (x.is_nan(), y.is_nan()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => x.partial_cmp(&y).unwrap(),
+    }
+}
+
+pub(crate) fn f64_total_hash<H: Hasher>(x: f64, state: &mut H) {
+    if x.is_nan() {
+        f64::NAN.to_bits().hash(state)
+    } else if x == 0.0 {
+        0u64.hash(state)
+    } else {
+        x.to_bits().hash(state)
+    }
+}
+
+impl TotalOrd for FractionF64 {
+    fn total_cmp(&self, other: &Self) -> Ordering {
+        f64_total_cmp(self.0, other.0)
+    }
+
+    fn total_hash<H: Hasher>(&self, state: &mut H) {
+        f64_total_hash(self.0, state)
+    }
+}
+
+impl TotalOrd for FractionExact {
+    fn total_cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+
+    fn total_hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state)
+    }
+}
+
+impl TotalOrd for FractionEnum {
+    fn total_cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (FractionEnum::Exact(x), FractionEnum::Exact(y)) => x.cmp(y),
+            (FractionEnum::Approx(x), FractionEnum::Approx(y)) => f64_total_cmp(*x, *y),
+            _ => rank(self).cmp(&rank(other)),
+        }
+    }
+
+    fn total_hash<H: Hasher>(&self, state: &mut H) {
+        rank(self).hash(state);
+        match self {
+            FractionEnum::Exact(f) => f.hash(state),
+            FractionEnum::Approx(f) => f64_total_hash(*f, state),
+            FractionEnum::CannotCombineExactAndApprox => {}
+        }
+    }
+}
+
+/// The position of the variant in the total order.
+fn rank(value: &FractionEnum) -> u8 {
+    match value {
+        FractionEnum::Exact(_) => 0,
+        FractionEnum::Approx(_) => 1,
+        FractionEnum::CannotCombineExactAndApprox => 2,
+    }
+}
+
+/// A fraction with the total order and hash of [TotalOrd], such that it can be used as the key of a `BTreeMap` or a `HashMap`.
+///
+/// Unlike the comparisons of the fractions themselves, approximate values are compared without tolerance, NaN has a fixed place and mixing exact and approximate values does not panic.
+#[derive(Clone, Debug, Default)]
+pub struct OrderedFraction<F>(pub F);
+
+impl<F> OrderedFraction<F> {
+    pub fn into_inner(self) -> F {
+        self.0
+    }
+}
+
+impl<F> From<F> for OrderedFraction<F> {
+    fn from(value: F) -> Self {
+        Self(value)
+    }
+}
+
+impl<F: Display> Display for OrderedFraction<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<F: TotalOrd> PartialEq for OrderedFraction<F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.total_cmp(&other.0) == Ordering::Equal
+    }
+}
+
+impl<F: TotalOrd> Eq for OrderedFraction<F> {}
+
+impl<F: TotalOrd> PartialOrd for OrderedFraction<F> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<F: TotalOrd> Ord for OrderedFraction<F> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl<F: TotalOrd> Hash for OrderedFraction<F> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.total_hash(state)
+    }
+}
+
+/// A fraction with the equality and hash of [TotalOrd], such that it can be used as the key of a `HashMap`.
+///
+/// The equality of [FractionF64] itself has a tolerance, which is not transitive, so no hash can agree with it for all values.
+/// This wrapper compares without tolerance instead, such that equal values hash equally for all backends.
+#[derive(Clone, Debug, Default)]
+pub struct StrictEq<F>(pub F);
+
+impl<F> StrictEq<F> {
+    pub fn into_inner(self) -> F {
+        self.0
+    }
+}
+
+impl<F> From<F> for StrictEq<F> {
+    fn from(value: F) -> Self {
+        Self(value)
+    }
+}
+
+impl<F: Display> Display for StrictEq<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<F: TotalOrd> PartialEq for StrictEq<F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.total_cmp(&other.0) == Ordering::Equal
+    }
+}
+
+impl<F: TotalOrd> Eq for StrictEq<F> {}
+
+impl<F: TotalOrd> Hash for StrictEq<F> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.total_hash(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use malachite::rational::Rational;
+    use std::{
+        collections::{BTreeSet, HashMap, HashSet},
+        hash::{BuildHasher, RandomState},
+    };
+
+    use crate::fraction::{
+        fraction_enum::FractionEnum,
+        fraction_exact::FractionExact,
+        fraction_f64::FractionF64,
+        ordered_fraction::{OrderedFraction, StrictEq},
+    };
+
+    #[test]
+    fn ordered_fraction() {
+        let values = [
+            f64::NAN,
+            1.0,
+            f64::NEG_INFINITY,
+            -0.0,
+            f64::INFINITY,
+            0.0,
+            -f64::NAN,
+        ];
+        let set: BTreeSet<OrderedFraction<FractionF64>> = values
+            .iter()
+            .map(|f| OrderedFraction(FractionF64(*f)))
+            .collect();
+        let sorted: Vec<f64> = set.iter().map(|f| f.0.0).collect();
+        assert_eq!(sorted[..4], [f64::NEG_INFINITY, 0.0, 1.0, f64::INFINITY]);
+        assert!(sorted[4].is_nan() && sorted.len() == 5);
+
+        //no tolerance, and equal values hash equally
+        assert_ne!(
+            OrderedFraction(FractionF64(1.0)),
+            OrderedFraction(FractionF64(1.0 + 1e-15))
+        );
+        let state = RandomState::new();
+        assert_eq!(
+            state.hash_one(OrderedFraction(FractionF64(-0.0))),
+            state.hash_one(OrderedFraction(FractionF64(0.0)))
+        );
+
+        //mixed values do not panic
+        let set: HashSet<OrderedFraction<FractionEnum>> = [
+            FractionEnum::Approx(0.5),
+            FractionEnum::Exact(Rational::from_signeds(1, 2)),
+            FractionEnum::CannotCombineExactAndApprox,
+            FractionEnum::Exact(Rational::from_signeds(2, 4)),
+        ]
+        .into_iter()
+        .map(OrderedFraction)
+        .collect();
+        assert_eq!(set.len(), 3);
+        let mut sorted = set.into_iter().collect::<Vec<_>>();
+        sorted.sort();
+        assert_eq!(
+            sorted[0].0,
+            FractionEnum::Exact(Rational::from_signeds(1, 2))
+        );
+        assert!(matches!(
+            sorted[2].0,
+            FractionEnum::CannotCombineExactAndApprox
+        ));
+    }
+
+    #[test]
+    fn strict_eq() {
+        let state = RandomState::new();
+        let mut map = HashMap::new();
+        map.insert(StrictEq(FractionF64(0.1 + 0.2)), "sum");
+        map.insert(StrictEq(FractionF64(0.3)), "literal");
+        map.insert(StrictEq(FractionF64(-0.0)), "zero");
+        assert_eq!(map.len(), 3);
+        assert_eq!(map.get(&StrictEq(FractionF64(0.0))), Some(&"zero"));
+
+        assert_eq!(
+            StrictEq(FractionExact::from((1, 3))),
+            StrictEq(FractionExact::from((2, 6)))
+        );
+        assert_eq!(
+            state.hash_one(StrictEq(FractionEnum::Approx(f64::NAN))),
+            state.hash_one(StrictEq(FractionEnum::Approx(-f64::NAN)))
+        );
+
+        //the tolerant equality of the backend itself now agrees with its hash on zeroes
+        assert_eq!(
+            state.hash_one(FractionF64(-0.0)),
+            state.hash_one(FractionF64(0.0))
+        );
+    }
+}
@@ -0,0 +1,166 @@
+use std::fmt::Display;
+
+use malachite::{
+    Natural,
+    base::{
+        num::{
+            arithmetic::traits::{DivRem, Pow},
+            basic::traits::{One, Zero},
+            conversion::traits::RoundingFrom,
+        },
+        rounding_modes::RoundingMode,
+    },
+    rational::Rational,
+};
+
+use crate::{
+    ebi_number::ToPercentage,
+    fraction::{
+        fraction::{EPSILON, is_approximately_zero},
+        fraction_enum::FractionEnum,
+        fraction_exact::FractionExact,
+        fraction_f64::FractionF64,
+    },
+};
+
+/// A percentage between 0% and 100%, split for display.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Percentage {
+    /// The integer part, between 0 and 100.
+    pub integer: u8,
+    /// The digits after the decimal point, without trailing zeroes.
+    pub decimals: String,
+    /// Whether the percentage represents the value exactly, that is, without rounding or saturation.
+    pub exact: bool,
+}
+
+impl Display for Percentage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.decimals.is_empty() {
+            write!(f, "{}%", self.integer)
+        } else {
+            write!(f, "{}.{}%", self.integer, self.decimals)
+        }
+    }
+}
+
+/// Rounds a value in [0, 1] to a percentage with at most `max_decimals` decimals.
+/// Returns the integer part, the decimals, and the value that the percentage represents.
+fn round_to_percentage(value: &Rational, max_decimals: u32) -> (u8, String, Rational) {
+    let power = Natural::from(10u32).pow(u64::from(max_decimals));
+    let scale = Natural::from(100u32) * &power;
+    let scaled = value * Rational::from(&scale);
+    let rounded = Natural::rounding_from(scaled, RoundingMode::Nearest).0;
+    let shown = Rational::from_naturals_ref(&rounded, &scale);
+    let (integer, decimals) = rounded.div_rem(power);
+
+    let mut decimals = format!("{:0>width$}", decimals, width = max_decimals as usize);
+    decimals.truncate(decimals.trim_end_matches('0').len());
+    (u8::try_from(&integer).unwrap_or(100), decimals, shown)
+}
+
+impl ToPercentage for Rational {
+    fn to_percentage_points(&self, max_decimals: u32) -> Percentage {
+        let (value, saturated) = if *self < 0u32 {
+            (&Rational::ZERO, true)
+        } else if *self > 1u32 {
+            (&Rational::ONE, true)
+        } else {
+            (self, false)
+        };
+        let (integer, decimals, shown) = round_to_percentage(value, max_decimals);
+        Percentage {
+            integer,
+            decimals,
+            exact: !saturated && shown == *value,
+        }
+    }
+}
+
+impl ToPercentage for FractionExact {
+    fn to_percentage_points(&self, max_decimals: u32) -> Percentage {
+        self.0.to_percentage_points(max_decimals)
+    }
+}
+
+impl ToPercentage for f64 {
+    fn to_percentage_points(&self, max_decimals: u32) -> Percentage {
+        if self.is_nan() {
+            return Percentage {
+                integer: 0,
+                decimals: String::new(),
+                exact: false,
+            };
+        }
+
+        //values just outside of [0, 1] are rounding errors rather than saturation
+        let clamped = self.clamp(0.0, 1.0);
+        let saturated = !is_approximately_zero(self - clamped, EPSILON);
+
+        let value = Rational::try_from(clamped).unwrap();
+        let (integer, decimals, shown) = round_to_percentage(&value, max_decimals);
+
+        //an approximate value is represented exactly if the percentage is within the tolerance
+        let difference = f64::rounding_from(&(shown - value), RoundingMode::Nearest).0;
+
+        Percentage {
+            integer,
+            decimals,
+            exact: !saturated && is_approximately_zero(difference, EPSILON),
+        }
+    }
+}
+
+impl ToPercentage for FractionF64 {
+    fn to_percentage_points(&self, max_decimals: u32) -> Percentage {
+        self.0.to_percentage_points(max_decimals)
+    }
+}
+
+impl ToPercentage for FractionEnum {
+    fn to_percentage_points(&self, max_decimals: u32) -> Percentage {
+        match self {
+            FractionEnum::Exact(f) => f.to_percentage_points(max_decimals),
+            FractionEnum::Approx(f) => f.to_percentage_points(max_decimals),
+            FractionEnum::CannotCombineExactAndApprox => Percentage {
+                integer: 0,
+                decimals: String::new(),
+                exact: false,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ebi_number::ToPercentage,
+        fraction::{fraction_exact::FractionExact, fraction_f64::FractionF64},
+    };
+
+    #[test]
+    fn percentage() {
+        let p = FractionExact::from((1, 3)).to_percentage_points(2);
+        assert_eq!(p.to_string(), "33.33%");
+        assert!(!p.exact);
+
+        let p = FractionExact::from((1, 8)).to_percentage_points(3);
+        assert_eq!(p.to_string(), "12.5%");
+        assert!(p.exact);
+
+        let p = FractionExact::from((9, 8)).to_percentage_points(3);
+        assert_eq!(p.to_string(), "100%");
+        assert!(!p.exact);
+
+        let p = FractionExact::from((2, 3)).to_percentage_points(0);
+        assert_eq!(p.to_string(), "67%");
+
+        let p = FractionF64::from(0.1 + 0.2 + 0.7).to_percentage_points(2);
+        assert_eq!(p.to_string(), "100%");
+        assert!(p.exact);
+
+        let p = FractionF64::from(-0.5).to_percentage_points(2);
+        assert_eq!(p.to_string(), "0%");
+        assert!(!p.exact);
+    }
+}
@@ -0,0 +1,100 @@
+use anyhow::{Result, anyhow};
+use malachite::rational::Rational;
+
+use crate::{
+    ebi_number::Pow,
+    fraction::{
+        fraction_enum::FractionEnum, fraction_exact::FractionExact, fraction_f64::FractionF64,
+    },
+};
+
+impl Pow for f64 {
+    fn pow(&self, exponent: i64) -> Result<Self> {
+        if *self == 0.0 && exponent < 0 {
+            return Err(anyhow!("cannot raise zero to a negative power"));
+        }
+        match i32::try_from(exponent) {
+            Ok(exponent) => Ok(self.powi(exponent)),
+            Err(_) => Ok(self.powf(exponent as f64)),
+        }
+    }
+}
+
+impl Pow for Rational {
+    fn pow(&self, exponent: i64) -> Result<Self> {
+        if *self == 0u32 && exponent < 0 {
+            return Err(anyhow!("cannot raise zero to a negative power"));
+        }
+        Ok(malachite::base::num::arithmetic::traits::Pow::pow(
+            self, exponent,
+        ))
+    }
+}
+
+impl Pow for FractionF64 {
+    fn pow(&self, exponent: i64) -> Result<Self> {
+        Ok(Self(Pow::pow(&self.0, exponent)?))
+    }
+}
+
+impl Pow for FractionExact {
+    fn pow(&self, exponent: i64) -> Result<Self> {
+        Ok(Self(Pow::pow(&self.0, exponent)?))
+    }
+}
+
+impl Pow for FractionEnum {
+    fn pow(&self, exponent: i64) -> Result<Self> {
+        match self {
+            FractionEnum::Exact(f) => Ok(FractionEnum::Exact(Pow::pow(f, exponent)?)),
+            FractionEnum::Approx(f) => Ok(FractionEnum::Approx(Pow::pow(f, exponent)?)),
+            FractionEnum::CannotCombineExactAndApprox => {
+                Err(anyhow!("cannot combine exact and approximate arithmetic"))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ebi_number::Pow,
+        fraction::{
+            fraction_enum::FractionEnum, fraction_exact::FractionExact, fraction_f64::FractionF64,
+        },
+    };
+
+    #[test]
+    fn pow() {
+        let x = FractionExact::from((2, 3));
+        assert_eq!(x.pow(3).unwrap(), FractionExact::from((8, 27)));
+        assert_eq!(x.pow(-2).unwrap(), FractionExact::from((9, 4)));
+        assert_eq!(x.pow(0).unwrap(), FractionExact::from(1));
+        assert_eq!(
+            FractionExact::from(-2).pow(-3).unwrap(),
+            FractionExact::from((-1, 8))
+        );
+
+        let x = FractionF64::from(0.5);
+        assert_eq!(x.pow(-3).unwrap(), FractionF64::from(8));
+        assert_eq!(x.pow(i64::MAX).unwrap(), FractionF64::from(0));
+
+        let x = FractionEnum::from((1, 2));
+        assert_eq!(x.pow(-2).unwrap(), FractionEnum::from(4));
+
+        //zero bases behave the same in all backends
+        assert_eq!(
+            FractionExact::from(0).pow(0).unwrap(),
+            FractionExact::from(1)
+        );
+        assert_eq!(FractionF64::from(0).pow(0).unwrap(), FractionF64::from(1));
+        assert_eq!(
+            FractionExact::from(0).pow(2).unwrap(),
+            FractionExact::from(0)
+        );
+        assert!(FractionExact::from(0).pow(-1).is_err());
+        assert!(FractionF64::from(0).pow(-1).is_err());
+        assert!(FractionEnum::from(0).pow(-1).is_err());
+        assert!(FractionEnum::CannotCombineExactAndApprox.pow(1).is_err());
+    }
+}
@@ -0,0 +1,103 @@
+//! Comparisons of fractions with primitive numbers, such as `f > 1u64`, without converting the primitive into a fraction first.
+//!
+//! Exact fractions are compared exactly, also with floats. Approximate fractions are compared as floats, with the same tolerance as comparisons between fractions.
+//!
+//! Comparisons are only implemented for `u64`, `i64` and `f64`; other primitives can be widened with `as` or `.into()`.
+//! This is a breaking change: as a fraction can be compared with several types, the other side of `==` can no longer be inferred, thus `assert_eq!(f, 3.into())` needs to be written as `assert_eq!(f, Fraction::from(3))`.
+
+use std::cmp::Ordering;
+
+use crate::fraction::{
+    fraction::EPSILON, fraction_enum::FractionEnum, fraction_exact::FractionExact,
+    fraction_f64::FractionF64,
+};
+
+fn approx_eq(value: f64, other: f64) -> bool {
+    value - EPSILON <= other && other <= value + EPSILON
+}
+
+macro_rules! primitive_cmp {
+    ($($p:ident),*) => {
+        $(
+            impl PartialEq<$p> for FractionExact {
+                fn eq(&self, other: &$p) -> bool {
+                    self.0 == *other
+                }
+            }
+
+            impl PartialOrd<$p> for FractionExact {
+                fn partial_cmp(&self, other: &$p) -> Option<Ordering> {
+                    self.0.partial_cmp(other)
+                }
+            }
+
+            impl PartialEq<$p> for FractionF64 {
+                fn eq(&self, other: &$p) -> bool {
+                    approx_eq(self.0, *other as f64)
+                }
+            }
+
+            impl PartialOrd<$p> for FractionF64 {
+                fn partial_cmp(&self, other: &$p) -> Option<Ordering> {
+                    self.0.partial_cmp(&(*other as f64))
+                }
+            }
+
+            /// A value that combines exact and approximate arithmetic equals nothing, and is ordered with nothing.
+            impl PartialEq<$p> for FractionEnum {
+                fn eq(&self, other: &$p) -> bool {
+                    match self {
+                        FractionEnum::Exact(f) => f == other,
+                        FractionEnum::Approx(f) => approx_eq(*f, *other as f64),
+                        FractionEnum::CannotCombineExactAndApprox => false,
+                    }
+                }
+            }
+
+            impl PartialOrd<$p> for FractionEnum {
+                fn partial_cmp(&self, other: &$p) -> Option<Ordering> {
+                    match self {
+                        FractionEnum::Exact(f) => f.partial_cmp(other),
+                        FractionEnum::Approx(f) => f.partial_cmp(&(*other as f64)),
+                        FractionEnum::CannotCombineExactAndApprox => None,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+primitive_cmp!(u64, i64, f64);
+
+#[cfg(test)]
+mod tests {
+    use malachite::rational::Rational;
+
+    use crate::fraction::{
+        fraction_enum::FractionEnum, fraction_exact::FractionExact, fraction_f64::FractionF64,
+    };
+
+    #[test]
+    fn primitive_cmp() {
+        let half = FractionExact::from((1, 2));
+        assert!(half > 0u64 && half < 1u64);
+        assert!(half == 0.5f64 && half > -1i64);
+        assert!(FractionExact::from(3) == 3u64);
+
+        //exact comparison with floats
+        assert!(FractionExact::from((1, 10)) != 0.1f64);
+        assert!(FractionExact::from((1, 10)) < 0.1f64);
+
+        let approx = FractionF64::from(1.0 - 1e-15);
+        assert!(approx == 1u64);
+        assert!(approx < 1u64);
+
+        assert!(FractionEnum::Exact(Rational::from_signeds(1, 3)) < 1u64);
+        assert!(FractionEnum::Approx(2.0) >= 2i64);
+        assert!(FractionEnum::CannotCombineExactAndApprox != 0u64);
+        assert_eq!(
+            FractionEnum::CannotCombineExactAndApprox.partial_cmp(&0u64),
+            None
+        );
+    }
+}
@@ -1,7 +1,13 @@
+use anyhow::{Result, anyhow};
 use malachite::{
-    base::num::{
-        arithmetic::traits::{Ceiling, Floor},
-        basic::traits::OneHalf,
+    Integer,
+    base::{
+        num::{
+            arithmetic::traits::{Ceiling, Floor, Pow},
+            basic::traits::{OneHalf, Zero as MZero},
+            conversion::traits::RoundingFrom,
+        },
+        rounding_modes::RoundingMode,
     },
     rational::Rational,
 };
@@ -14,6 +20,11 @@ use crate::{
     },
 };
 
+/// Rounds the value to an integer with the given mode; `Nearest` rounds half-way values to the even integer.
+fn round_rational(value: &Rational, mode: RoundingMode) -> Rational {
+    Integer::rounding_from(value, mode).0.into()
+}
+
 impl Round for FractionF64 {
     fn floor(self) -> Self {
         FractionF64(self.0.floor())
@@ -26,6 +37,22 @@ impl Round for FractionF64 {
     fn round_half_away_from_zero(self) -> Self {
         FractionF64(self.0.round())
     }
+
+    fn round_half_to_even(self) -> Self {
+        FractionF64(self.0.round_ties_even())
+    }
+
+    fn trunc(self) -> Self {
+        FractionF64(self.0.trunc())
+    }
+
+    fn round_to_multiple_of(self, multiple: &Self) -> Result<Self> {
+        Ok(FractionF64(self.0.round_to_multiple_of(&multiple.0)?))
+    }
+
+    fn round_to_decimal_places(self, decimal_places: u32) -> Self {
+        FractionF64(self.0.round_to_decimal_places(decimal_places))
+    }
 }
 
 impl Round for FractionExact {
@@ -40,6 +67,22 @@ impl Round for FractionExact {
     fn round_half_away_from_zero(self) -> Self {
         Self(Round::round_half_away_from_zero(self.0))
     }
+
+    fn round_half_to_even(self) -> Self {
+        Self(self.0.round_half_to_even())
+    }
+
+    fn trunc(self) -> Self {
+        Self(self.0.trunc())
+    }
+
+    fn round_to_multiple_of(self, multiple: &Self) -> Result<Self> {
+        Ok(Self(self.0.round_to_multiple_of(&multiple.0)?))
+    }
+
+    fn round_to_decimal_places(self, decimal_places: u32) -> Self {
+        Self(self.0.round_to_decimal_places(decimal_places))
+    }
 }
 
 impl Round for FractionEnum {
@@ -66,6 +109,38 @@ impl Round for FractionEnum {
             Self::CannotCombineExactAndApprox => Self::CannotCombineExactAndApprox,
         }
     }
+
+    fn round_half_to_even(self) -> Self {
+        match self {
+            Self::Exact(f) => Self::Exact(f.round_half_to_even()),
+            Self::Approx(f) => Self::Approx(f.round_ties_even()),
+            Self::CannotCombineExactAndApprox => Self::CannotCombineExactAndApprox,
+        }
+    }
+
+    fn trunc(self) -> Self {
+        match self {
+            Self::Exact(f) => Self::Exact(f.trunc()),
+            Self::Approx(f) => Self::Approx(f.trunc()),
+            Self::CannotCombineExactAndApprox => Self::CannotCombineExactAndApprox,
+        }
+    }
+
+    fn round_to_multiple_of(self, multiple: &Self) -> Result<Self> {
+        match (self, multiple) {
+            (Self::Exact(f), Self::Exact(m)) => Ok(Self::Exact(f.round_to_multiple_of(m)?)),
+            (Self::Approx(f), Self::Approx(m)) => Ok(Self::Approx(f.round_to_multiple_of(m)?)),
+            _ => Err(anyhow!("cannot combine exact and approximate arithmetic")),
+        }
+    }
+
+    fn round_to_decimal_places(self, decimal_places: u32) -> Self {
+        match self {
+            Self::Exact(f) => Self::Exact(f.round_to_decimal_places(decimal_places)),
+            Self::Approx(f) => Self::Approx(f.round_to_decimal_places(decimal_places)),
+            Self::CannotCombineExactAndApprox => Self::CannotCombineExactAndApprox,
+        }
+    }
 }
 
 impl Round for Rational {
@@ -84,6 +159,26 @@ impl Round for Rational {
             Ceiling::ceiling(self - Rational::ONE_HALF).into()
         }
     }
+
+    fn round_half_to_even(self) -> Self {
+        round_rational(&self, RoundingMode::Nearest)
+    }
+
+    fn trunc(self) -> Self {
+        round_rational(&self, RoundingMode::Down)
+    }
+
+    fn round_to_multiple_of(self, multiple: &Self) -> Result<Self> {
+        if *multiple == Rational::ZERO {
+            return Err(anyhow!("cannot round to a multiple of zero"));
+        }
+        Ok(round_rational(&(self / multiple), RoundingMode::Nearest) * multiple)
+    }
+
+    fn round_to_decimal_places(self, decimal_places: u32) -> Self {
+        let scale = Rational::from(10u32).pow(decimal_places as u64);
+        round_rational(&(self * &scale), RoundingMode::Nearest) / scale
+    }
 }
 
 macro_rules! float {
@@ -100,6 +195,43 @@ macro_rules! float {
             fn round_half_away_from_zero(self) -> $t {
                 $t::round(self)
             }
+
+            fn round_half_to_even(self) -> $t {
+                $t::round_ties_even(self)
+            }
+
+            fn trunc(self) -> $t {
+                $t::trunc(self)
+            }
+
+            /// Rounds the exact values of the floats, and the result back to the nearest float.
+            /// Infinite and NaN values are returned as is.
+            fn round_to_multiple_of(self, multiple: &$t) -> Result<$t> {
+                let Ok(value) = Rational::try_from(self) else {
+                    return Ok(self);
+                };
+                let multiple = Rational::try_from(*multiple)
+                    .map_err(|_| anyhow!("cannot round to a multiple of {}", multiple))?;
+                Ok($t::rounding_from(
+                    value.round_to_multiple_of(&multiple)?,
+                    RoundingMode::Nearest,
+                )
+                .0)
+            }
+
+            /// Infinite and NaN values are returned as is.
+            fn round_to_decimal_places(self, decimal_places: u32) -> $t {
+                match Rational::try_from(self) {
+                    Ok(value) => {
+                        $t::rounding_from(
+                            value.round_to_decimal_places(decimal_places),
+                            RoundingMode::Nearest,
+                        )
+                        .0
+                    }
+                    Err(_) => self,
+                }
+            }
         }
     };
 }
@@ -121,6 +253,31 @@ macro_rules! ttype {
             fn round_half_away_from_zero(self) -> Self {
                 self
             }
+
+            fn round_half_to_even(self) -> Self {
+                self
+            }
+
+            fn trunc(self) -> Self {
+                self
+            }
+
+            fn round_to_multiple_of(self, multiple: &Self) -> Result<Self> {
+                let result =
+                    Rational::from(self).round_to_multiple_of(&Rational::from(*multiple))?;
+                $t::try_from(&result).map_err(|_| {
+                    anyhow!(
+                        "rounding {} to a multiple of {} does not fit in a {}",
+                        self,
+                        multiple,
+                        stringify!($t)
+                    )
+                })
+            }
+
+            fn round_to_decimal_places(self, _decimal_places: u32) -> Self {
+                self
+            }
         }
     };
 }
@@ -141,7 +298,10 @@ ttype!(i8);
 mod tests {
     use std::str::FromStr;
 
-    use crate::{One, Round, Zero, fraction::fraction_exact::FractionExact};
+    use crate::{
+        One, Round, Zero,
+        fraction::{fraction_exact::FractionExact, fraction_f64::FractionF64},
+    };
 
     #[test]
     fn rounding() {
@@ -169,4 +329,93 @@ mod tests {
             -FractionExact::one()
         );
     }
+
+    #[test]
+    fn rounding_half_to_even() {
+        let half = |n: i64| FractionExact::from((n, 2));
+        assert_eq!(half(5).round_half_to_even(), FractionExact::from(2));
+        assert_eq!(half(7).round_half_to_even(), FractionExact::from(4));
+        assert_eq!(half(-5).round_half_to_even(), FractionExact::from(-2));
+        assert_eq!(half(-5).trunc(), FractionExact::from(-2));
+        assert_eq!(
+            FractionF64::from(2.5).round_half_to_even(),
+            FractionF64::from(2.0)
+        );
+        assert_eq!(FractionF64::from(-2.7).trunc(), FractionF64::from(-2.0));
+
+        assert_eq!(
+            FractionExact::from((7, 3))
+                .round_to_multiple_of(&FractionExact::from((1, 2)))
+                .unwrap(),
+            FractionExact::from((5, 2))
+        );
+        assert!(
+            FractionExact::from(1)
+                .round_to_multiple_of(&FractionExact::zero())
+                .is_err()
+        );
+        assert_eq!(17u8.round_to_multiple_of(&5).unwrap(), 15);
+        assert!(255u8.round_to_multiple_of(&10).is_err());
+
+        //floats round as the exact value that they represent, and the float nearest to 2.675 is below it
+        assert_eq!(
+            FractionF64::from(2.675).round_to_decimal_places(2),
+            FractionF64::from(2.67)
+        );
+        assert_eq!(
+            FractionExact::from((2675, 1000)).round_to_decimal_places(2),
+            FractionExact::from((268, 100))
+        );
+        assert_eq!(
+            FractionExact::from((2665, 1000)).round_to_decimal_places(2),
+            FractionExact::from((266, 100))
+        );
+    }
+
+    #[test]
+    fn rounding_default_trunc() {
+        //a type that only rounds as the original trait required
+        #[derive(Debug, PartialEq, PartialOrd)]
+        struct Whole(f64);
+
+        impl Zero for Whole {
+            fn zero() -> Self {
+                Whole(0.0)
+            }
+
+            fn is_zero(&self) -> bool {
+                self.0 == 0.0
+            }
+        }
+
+        impl Round for Whole {
+            fn floor(self) -> Self {
+                Whole(self.0.floor())
+            }
+
+            fn ceil(self) -> Self {
+                Whole(self.0.ceil())
+            }
+
+            fn round_half_away_from_zero(self) -> Self {
+                Whole(self.0.round())
+            }
+
+            fn round_half_to_even(self) -> Self {
+                Whole(self.0.round_ties_even())
+            }
+
+            fn round_to_multiple_of(self, multiple: &Self) -> anyhow::Result<Self> {
+                Ok(Whole((self.0 / multiple.0).round_ties_even() * multiple.0))
+            }
+
+            fn round_to_decimal_places(self, _: u32) -> Self {
+                self
+            }
+        }
+
+        assert_eq!(Whole(2.7).trunc(), Whole(2.0));
+        assert_eq!(Whole(-2.7).trunc(), Whole(-2.0));
+        assert_eq!(Whole(0.0).trunc(), Whole(0.0));
+    }
 }
@@ -0,0 +1,196 @@
+use malachite::rational::Rational;
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de, ser};
+use std::{fmt, str::FromStr};
+
+use crate::fraction::{
+    fraction_enum::FractionEnum, fraction_exact::FractionExact, fraction_f64::FractionF64,
+};
+
+/// Serialises a borrowed exact value as a string such as "-3/4", without copying it.
+pub(crate) struct RationalRef<'a>(pub(crate) &'a Rational);
+
+impl Serialize for RationalRef<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self.0)
+    }
+}
+
+/// An approximate value as it is serialised.
+/// Human-readable formats such as JSON cannot represent NaN and infinities as numbers, so there, these are serialised as the strings "NaN", "inf" and "-inf".
+#[derive(Clone, Copy)]
+pub(crate) struct F64Repr(pub(crate) f64);
+
+impl Serialize for F64Repr {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() && !self.0.is_finite() {
+            serializer.collect_str(&self.0)
+        } else {
+            serializer.serialize_f64(self.0)
+        }
+    }
+}
+
+struct F64Visitor;
+
+impl de::Visitor<'_> for F64Visitor {
+    type Value = F64Repr;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a number, or one of the strings \"NaN\", \"inf\" and \"-inf\"")
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<F64Repr, E> {
+        Ok(F64Repr(value))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<F64Repr, E> {
+        Ok(F64Repr(value as f64))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<F64Repr, E> {
+        Ok(F64Repr(value as f64))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<F64Repr, E> {
+        match value {
+            "NaN" => Ok(F64Repr(f64::NAN)),
+            "inf" => Ok(F64Repr(f64::INFINITY)),
+            "-inf" => Ok(F64Repr(f64::NEG_INFINITY)),
+            _ => Err(de::Error::invalid_value(de::Unexpected::Str(value), &self)),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for F64Repr {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(F64Visitor)
+        } else {
+            Ok(Self(f64::deserialize(deserializer)?))
+        }
+    }
+}
+
+/// Exact fractions are serialised as strings such as "-3/4", as their numerators and denominators may exceed any native integer type.
+impl Serialize for FractionExact {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        RationalRef(&self.0).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for FractionExact {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Rational::from_str(&s)
+            .map(Self)
+            .map_err(|_| de::Error::custom(format!("{} was not recognised as a fraction", s)))
+    }
+}
+
+/// Approximate fractions are serialised as numbers; in human-readable formats, NaN and infinities are serialised as the strings "NaN", "inf" and "-inf".
+impl Serialize for FractionF64 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        F64Repr(self.0).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for FractionF64 {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Self(F64Repr::deserialize(deserializer)?.0))
+    }
+}
+
+/// The serialised form of a [FractionEnum]; the variant records the mode, thus a value keeps its exactness regardless of the global mode.
+#[derive(Deserialize)]
+enum FractionEnumRepr {
+    Exact(FractionExact),
+    Approx(F64Repr),
+}
+
+impl Serialize for FractionEnum {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            FractionEnum::Exact(f) => serializer.serialize_newtype_variant(
+                "FractionEnumRepr",
+                0,
+                "Exact",
+                &RationalRef(f),
+            ),
+            FractionEnum::Approx(f) => {
+                serializer.serialize_newtype_variant("FractionEnumRepr", 1, "Approx", &F64Repr(*f))
+            }
+            FractionEnum::CannotCombineExactAndApprox => Err(ser::Error::custom(
+                "cannot combine exact and approximate arithmetic",
+            )),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for FractionEnum {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(match FractionEnumRepr::deserialize(deserializer)? {
+            FractionEnumRepr::Exact(f) => FractionEnum::Exact(f.0),
+            FractionEnumRepr::Approx(f) => FractionEnum::Approx(f.0),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fraction::{
+        fraction_enum::FractionEnum, fraction_exact::FractionExact, fraction_f64::FractionF64,
+    };
+
+    #[test]
+    fn serde_fractions() {
+        let f = FractionExact::from((-3, 4));
+        let json = serde_json::to_string(&f).unwrap();
+        assert_eq!(json, "\"-3/4\"");
+        assert_eq!(serde_json::from_str::<FractionExact>(&json).unwrap(), f);
+        assert!(serde_json::from_str::<FractionExact>("\"x\"").is_err());
+
+        let f = FractionF64::from(0.25);
+        let json = serde_json::to_string(&f).unwrap();
+        assert_eq!(json, "0.25");
+        assert_eq!(serde_json::from_str::<FractionF64>(&json).unwrap(), f);
+        assert_eq!(
+            serde_json::from_str::<FractionF64>("3").unwrap(),
+            FractionF64::from(3.0)
+        );
+
+        let f = FractionEnum::Exact(FractionExact::from((1, 3)).0);
+        let json = serde_json::to_string(&f).unwrap();
+        assert_eq!(json, "{\"Exact\":\"1/3\"}");
+        assert_eq!(serde_json::from_str::<FractionEnum>(&json).unwrap(), f);
+
+        let f = FractionEnum::Approx(0.5);
+        let json = serde_json::to_string(&f).unwrap();
+        assert_eq!(json, "{\"Approx\":0.5}");
+        assert_eq!(serde_json::from_str::<FractionEnum>(&json).unwrap(), f);
+
+        assert!(serde_json::to_string(&FractionEnum::CannotCombineExactAndApprox).is_err());
+    }
+
+    #[test]
+    fn serde_non_finite() {
+        for (value, text) in [
+            (f64::INFINITY, "\"inf\""),
+            (f64::NEG_INFINITY, "\"-inf\""),
+            (f64::NAN, "\"NaN\""),
+        ] {
+            let json = serde_json::to_string(&FractionF64::from(value)).unwrap();
+            assert_eq!(json, text);
+            let back = serde_json::from_str::<FractionF64>(&json).unwrap();
+            assert_eq!(back.0.to_bits(), value.to_bits());
+
+            let json = serde_json::to_string(&FractionEnum::Approx(value)).unwrap();
+            assert_eq!(json, format!("{{\"Approx\":{}}}", text));
+            match serde_json::from_str::<FractionEnum>(&json).unwrap() {
+                FractionEnum::Approx(back) => assert_eq!(back.to_bits(), value.to_bits()),
+                _ => panic!("the exactness was not kept"),
+            }
+        }
+        assert!(serde_json::from_str::<FractionF64>("\"infinity\"").is_err());
+        assert!(serde_json::from_str::<FractionF64>("null").is_err());
+    }
+}
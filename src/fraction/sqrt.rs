@@ -8,6 +8,7 @@ use anyhow::{Result, anyhow};
 use malachite::{
     Integer, Natural,
     base::num::{
+        arithmetic::traits::CheckedSqrt,
         basic::traits::{One as MOne, Two, Zero as MZero},
         conversion::traits::IsInteger,
         logic::traits::SignificantBits,
@@ -45,6 +46,34 @@ impl Sqrt for FractionEnum {
     }
 }
 
+/// The square root of a rational, if it is rational. Malachite panics on negative values, so those are excluded first.
+fn exact_sqrt(value: &Rational) -> Option<Rational> {
+    if *value < 0u32 {
+        None
+    } else {
+        value.checked_sqrt()
+    }
+}
+
+impl FractionExact {
+    /// Returns the square root if it is a fraction, that is, if both the numerator and the denominator are perfect squares.
+    /// Returns None otherwise, including for negative values; [Sqrt::approx_sqrt] approximates those roots instead.
+    pub fn exact_sqrt(&self) -> Option<Self> {
+        exact_sqrt(&self.0).map(Self)
+    }
+}
+
+impl FractionEnum {
+    /// Returns the square root of an exact value if both its numerator and denominator are perfect squares.
+    /// Returns None otherwise, and for approximate values, as those have no exactness to keep.
+    pub fn exact_sqrt(&self) -> Option<Self> {
+        match self {
+            FractionEnum::Exact(f) => exact_sqrt(f).map(FractionEnum::Exact),
+            FractionEnum::Approx(_) | FractionEnum::CannotCombineExactAndApprox => None,
+        }
+    }
+}
+
 impl Sqrt for f64 {
     fn approx_sqrt(&self, _precision_decimals: u32) -> Result<Self>
     where
@@ -172,7 +201,10 @@ fn sqrt_search(low: &Natural, high: &Natural, n: &Natural) -> Natural {
 mod test {
     use malachite::rational::Rational;
 
-    use crate::{One, Sqrt, Zero};
+    use crate::{
+        One, Sqrt, Zero,
+        fraction::{fraction_enum::FractionEnum, fraction_exact::FractionExact},
+    };
 
     #[test]
     fn sqrt_exact() {
@@ -191,4 +223,25 @@ mod test {
         assert_eq!(Rational::one().approx_abs_sqrt(1), Rational::one());
         assert_eq!(Rational::zero().approx_abs_sqrt(1), Rational::zero());
     }
+
+    #[test]
+    fn exact_sqrt() {
+        assert_eq!(
+            FractionExact::from((9, 16)).exact_sqrt(),
+            Some(FractionExact::from((3, 4)))
+        );
+        assert_eq!(
+            FractionExact::from((18, 32)).exact_sqrt(),
+            Some((3, 4).into())
+        );
+        assert_eq!(FractionExact::from((2, 9)).exact_sqrt(), None);
+        assert_eq!(FractionExact::from((-9, 16)).exact_sqrt(), None);
+        assert_eq!(FractionExact::from(0).exact_sqrt(), Some(0.into()));
+
+        assert_eq!(
+            FractionEnum::Exact(Rational::from_signeds(1, 4)).exact_sqrt(),
+            Some(FractionEnum::Exact(Rational::from_signeds(1, 2)))
+        );
+        assert_eq!(FractionEnum::Approx(0.25).exact_sqrt(), None);
+    }
 }
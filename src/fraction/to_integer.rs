@@ -0,0 +1,184 @@
+use anyhow::{Result, anyhow};
+use malachite::{
+    Integer,
+    base::{
+        num::{
+            arithmetic::traits::{Ceiling, Floor},
+            basic::traits::OneHalf,
+            conversion::traits::{RoundingFrom, SaturatingFrom},
+        },
+        rounding_modes::RoundingMode,
+    },
+    rational::Rational,
+};
+
+use crate::{
+    ebi_number::ToInteger,
+    fraction::{
+        fraction::EPSILON, fraction_enum::FractionEnum, fraction_exact::FractionExact,
+        fraction_f64::FractionF64,
+    },
+};
+
+/// Returns the integer nearest to `value`; if it is half-way between two integers, rounds away from zero.
+pub(crate) fn round_half_away_from_zero(value: &Rational) -> Integer {
+    if *value >= 0u32 {
+        Floor::floor(value + Rational::ONE_HALF)
+    } else {
+        Ceiling::ceiling(value - Rational::ONE_HALF)
+    }
+}
+
+impl ToInteger for Rational {
+    fn to_usize_floor(&self) -> usize {
+        usize::saturating_from(&Floor::floor(self))
+    }
+
+    fn to_i64_round(&self) -> i64 {
+        i64::saturating_from(&round_half_away_from_zero(self))
+    }
+
+    fn try_to_integer(&self) -> Result<Integer> {
+        Integer::try_from(self).map_err(|_| anyhow!("{} is not an integer", self))
+    }
+}
+
+impl ToInteger for f64 {
+    fn to_usize_floor(&self) -> usize {
+        if self.is_nan() {
+            return usize::MAX;
+        }
+        //within the tolerance of an integer, as in try_to_integer
+        let int = self.round();
+        //casts saturate
+        if (self - int).abs() <= EPSILON {
+            int as usize
+        } else {
+            self.floor() as usize
+        }
+    }
+
+    fn to_i64_round(&self) -> i64 {
+        if self.is_nan() {
+            return i64::MAX;
+        }
+        //casts saturate
+        self.round() as i64
+    }
+
+    fn try_to_integer(&self) -> Result<Integer> {
+        let int = self.round();
+        if !int.is_finite() || (self - int).abs() > EPSILON {
+            return Err(anyhow!("{} is not an integer", self));
+        }
+        Ok(Integer::rounding_from(int, RoundingMode::Exact).0)
+    }
+}
+
+impl ToInteger for FractionExact {
+    fn to_usize_floor(&self) -> usize {
+        self.0.to_usize_floor()
+    }
+
+    fn to_i64_round(&self) -> i64 {
+        self.0.to_i64_round()
+    }
+
+    fn try_to_integer(&self) -> Result<Integer> {
+        self.0.try_to_integer()
+    }
+}
+
+impl ToInteger for FractionF64 {
+    fn to_usize_floor(&self) -> usize {
+        self.0.to_usize_floor()
+    }
+
+    fn to_i64_round(&self) -> i64 {
+        self.0.to_i64_round()
+    }
+
+    fn try_to_integer(&self) -> Result<Integer> {
+        self.0.try_to_integer()
+    }
+}
+
+impl ToInteger for FractionEnum {
+    fn to_usize_floor(&self) -> usize {
+        match self {
+            FractionEnum::Exact(rational) => rational.to_usize_floor(),
+            FractionEnum::Approx(f) => f.to_usize_floor(),
+            FractionEnum::CannotCombineExactAndApprox => usize::MAX,
+        }
+    }
+
+    fn to_i64_round(&self) -> i64 {
+        match self {
+            FractionEnum::Exact(rational) => rational.to_i64_round(),
+            FractionEnum::Approx(f) => f.to_i64_round(),
+            FractionEnum::CannotCombineExactAndApprox => i64::MAX,
+        }
+    }
+
+    fn try_to_integer(&self) -> Result<Integer> {
+        match self {
+            FractionEnum::Exact(rational) => rational.try_to_integer(),
+            FractionEnum::Approx(f) => f.try_to_integer(),
+            FractionEnum::CannotCombineExactAndApprox => {
+                Err(anyhow!("cannot combine exact and approximate arithmetic"))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use malachite::{Integer, base::num::arithmetic::traits::Pow};
+
+    use crate::{
+        ebi_number::ToInteger,
+        fraction::{
+            fraction_enum::FractionEnum, fraction_exact::FractionExact, fraction_f64::FractionF64,
+        },
+    };
+
+    #[test]
+    fn to_integer() {
+        //beyond the precision of f64
+        let large = Integer::from(2u32).pow(60) + Integer::from(1);
+        let x = FractionExact::from(large.clone());
+        assert_eq!(x.to_usize_floor(), (1usize << 60) + 1);
+        assert_eq!(x.to_i64_round(), (1i64 << 60) + 1);
+        assert_eq!(x.try_to_integer().unwrap(), large);
+
+        let x = FractionExact::from((-7, 2));
+        assert_eq!(x.to_usize_floor(), 0);
+        assert_eq!(x.to_i64_round(), -4);
+        assert!(x.try_to_integer().is_err());
+
+        let x = FractionExact::from(Integer::from(2u32).pow(70));
+        assert_eq!(x.to_usize_floor(), usize::MAX);
+        assert_eq!(x.to_i64_round(), i64::MAX);
+        assert_eq!((-x).to_i64_round(), i64::MIN);
+
+        let x = FractionF64::from(2.0 - 1e-15);
+        assert_eq!(x.to_usize_floor(), 2);
+        assert_eq!(x.to_i64_round(), 2);
+        assert_eq!(x.try_to_integer().unwrap(), 2);
+        assert_eq!(FractionF64::from(2.0 - 1e-10).to_usize_floor(), 1);
+        assert!(FractionF64::from(2.5).try_to_integer().is_err());
+        assert!(FractionF64::from(f64::INFINITY).try_to_integer().is_err());
+        assert_eq!(
+            FractionF64::from(f64::NEG_INFINITY).to_i64_round(),
+            i64::MIN
+        );
+        assert_eq!(FractionF64::from(f64::NAN).to_usize_floor(), usize::MAX);
+
+        assert_eq!(FractionEnum::Approx(-2.5).to_i64_round(), -3);
+        assert!(
+            FractionEnum::CannotCombineExactAndApprox
+                .try_to_integer()
+                .is_err()
+        );
+    }
+}
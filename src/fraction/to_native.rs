@@ -2,25 +2,15 @@ use crate::{
     Signed, ToNative,
     fraction::{
         fraction_enum::FractionEnum, fraction_exact::FractionExact, fraction_f64::FractionF64,
+        to_integer::round_half_away_from_zero,
     },
 };
-use malachite::{
-    Rational,
-    base::num::{
-        arithmetic::traits::{Ceiling, Floor},
-        basic::traits::OneHalf,
-    },
-};
+use malachite::Rational;
 use std::usize;
 
 impl ToNative for Rational {
     fn to_usize(&self) -> usize {
-        let int = if self.is_positive() {
-            Floor::floor(self + Rational::ONE_HALF)
-        } else {
-            Ceiling::ceiling(self - Rational::ONE_HALF)
-        };
-
+        let int = round_half_away_from_zero(self);
         match usize::try_from(&int) {
             Ok(x) => x,
             Err(_) => {
@@ -4,7 +4,9 @@ use crate::{
     ebi_number::{Signed, Zero},
     exact::is_exact_globally,
     fraction::{
-        fraction::EPSILON, fraction_enum::FractionEnum, fraction_exact::FractionExact,
+        fraction::{EPSILON, is_approximately_zero},
+        fraction_enum::FractionEnum,
+        fraction_exact::FractionExact,
         fraction_f64::FractionF64,
     },
 };
@@ -100,7 +102,16 @@ macro_rules! float {
 }
 
 float!(f32, f32::EPSILON);
-float!(f64, EPSILON);
+
+impl Zero for f64 {
+    fn zero() -> Self {
+        0.0
+    }
+
+    fn is_zero(&self) -> bool {
+        is_approximately_zero(*self, EPSILON)
+    }
+}
 
 macro_rules! ttype {
     ($t:ident) => {
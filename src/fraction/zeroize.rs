@@ -0,0 +1,68 @@
+//! Erasure of fractions, for deployments that must scrub intermediate values from memory.
+//!
+//! Approximate values are overwritten in place.
+//! Exact values are taken apart into the limbs of their numerators and denominators, which are overwritten before they are released.
+//! Buffers that malachite released during earlier computations are out of reach, and are not overwritten.
+//!
+//! To clear values on drop, wrap them in [zeroize::Zeroizing]. The fraction types do not implement [Drop] themselves, as that would prevent moving values out of them.
+
+use malachite::rational::Rational;
+use zeroize::Zeroize;
+
+use crate::fraction::{
+    fraction_enum::FractionEnum, fraction_exact::FractionExact, fraction_f64::FractionF64,
+};
+
+/// Overwrites the limbs of the numerator and denominator of the value, and leaves zero in its place.
+pub(crate) fn zeroize_rational(value: &mut Rational) {
+    let (numerator, denominator) = std::mem::take(value).into_numerator_and_denominator();
+    numerator.into_limbs_asc().zeroize();
+    denominator.into_limbs_asc().zeroize();
+}
+
+impl Zeroize for FractionF64 {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl Zeroize for FractionExact {
+    fn zeroize(&mut self) {
+        zeroize_rational(&mut self.0);
+    }
+}
+
+impl Zeroize for FractionEnum {
+    fn zeroize(&mut self) {
+        match self {
+            FractionEnum::Exact(f) => zeroize_rational(f),
+            FractionEnum::Approx(f) => f.zeroize(),
+            FractionEnum::CannotCombineExactAndApprox => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use malachite::{Integer, base::num::arithmetic::traits::Pow};
+    use zeroize::{Zeroize, Zeroizing};
+
+    use crate::{
+        Zero,
+        fraction::{fraction_enum::FractionEnum, fraction_exact::FractionExact},
+    };
+
+    #[test]
+    fn zeroize_fraction() {
+        let mut f = FractionExact::from(Integer::from(3u32).pow(200)) / FractionExact::from(7);
+        f.zeroize();
+        assert!(f.is_zero());
+
+        let mut f = FractionEnum::Approx(0.5);
+        f.zeroize();
+        assert!(f.is_zero());
+
+        let f = Zeroizing::new(FractionExact::from((1, 3)));
+        assert_eq!(*f, FractionExact::from((1, 3)));
+    }
+}
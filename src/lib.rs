@@ -1,20 +1,51 @@
 pub mod fraction {
+    pub mod alias_method;
+    pub mod approx_eq;
     pub mod approximate;
+    pub mod bits;
+    pub mod checked_from;
     pub mod choose_randomly;
+    pub mod clamp;
+    pub mod common_denom_vector;
+    pub mod continued_fraction;
+    pub mod convert;
+    #[cfg(any(feature = "rust_decimal", feature = "bigdecimal"))]
+    pub mod decimal;
+    pub mod duration;
+    pub mod dyn_fraction;
     pub mod exact;
+    pub mod exponential;
+    pub mod format;
     pub mod fraction;
     pub mod fraction_enum;
     pub mod fraction_exact;
     pub mod fraction_f64;
+    pub mod gcd;
+    pub mod interval;
+    pub mod limit_denominator;
+    pub mod log_fraction;
+    pub mod nth_root;
+    #[cfg(feature = "num_rational")]
+    pub mod num_rational;
+    pub mod odds;
     pub mod one;
     pub mod one_minus;
+    pub mod ordered_fraction;
+    pub mod percentage;
+    pub mod pow;
+    pub mod primitive_cmp;
     pub mod random;
     pub mod recip;
     pub mod round;
+    #[cfg(feature = "serde")]
+    pub mod serde;
     pub mod signed;
     pub mod sqrt;
+    pub mod to_integer;
     pub mod to_native;
     pub mod zero;
+    #[cfg(feature = "zeroize")]
+    pub mod zeroize;
 }
 pub mod log_polynomial {
     pub mod add;
@@ -34,24 +65,71 @@ pub mod log_polynomial {
     pub mod zero;
 }
 pub mod matrix {
+    pub mod add;
+    pub mod approx_eq;
+    pub mod banded;
+    pub mod bits;
+    pub mod block_diagonal;
+    pub mod blocks;
+    pub mod bytes;
+    pub mod convert;
+    pub mod convex_combination;
+    pub mod determinant;
+    pub mod diff;
+    pub mod equilibrate;
+    pub mod export;
     pub mod exact;
+    pub mod fixed_fraction_matrix;
     pub mod fraction_matrix;
     pub mod fraction_matrix_enum;
     pub mod fraction_matrix_exact;
     pub mod fraction_matrix_f64;
     pub mod gauss_jordan;
     pub mod identity_minus;
+    pub mod index;
+    pub mod interned_fraction_matrix;
     pub mod inversion;
+    pub mod markov;
     pub mod mul;
+    #[cfg(feature = "nalgebra")]
+    pub mod nalgebra;
+    pub mod neg;
+    pub mod norm;
+    pub mod rows;
+    pub mod scalar_mul;
+    #[cfg(feature = "serde")]
+    pub mod serde;
+    pub mod solution_space;
+    pub mod sparse_fraction_matrix;
+    pub mod spectral_radius;
+    pub mod symmetric;
+    pub mod tridiagonal;
+    pub mod view;
+    #[cfg(feature = "zeroize")]
+    pub mod zeroize;
 }
+#[cfg(feature = "bench")]
+pub mod bench_support;
 pub mod constant_fraction;
+pub mod dual_run;
 pub mod ebi_log_polynomial;
 pub mod ebi_matrix;
 pub mod ebi_number;
+pub mod events;
 pub mod exact;
 pub mod exporter;
 pub mod log;
 pub mod parsing;
+#[cfg(feature = "python")]
+pub mod python;
+pub mod size_guard;
+#[cfg(feature = "testing")]
+pub mod testing;
+pub mod traits;
+pub mod try_ops;
+pub mod vector;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
 pub use crate::constant_fraction::*;
 pub use crate::ebi_matrix::*;
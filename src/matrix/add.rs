@@ -0,0 +1,208 @@
+use anyhow::{Result, anyhow};
+use malachite::rational::Rational;
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+
+use crate::matrix::{
+    fraction_matrix_enum::FractionMatrixEnum, fraction_matrix_exact::FractionMatrixExact,
+    fraction_matrix_f64::FractionMatrixF64,
+};
+
+macro_rules! add_sub_op {
+    ($t:ident, $v:ident, $trait:ident, $fn:ident, $assign_trait:ident, $assign_fn:ident, $verb:literal) => {
+        /// Combines the matrices value by value. Returns an error if the matrices have different sizes.
+        impl $trait for &$t {
+            type Output = Result<$t>;
+
+            fn $fn(self, rhs: Self) -> Self::Output {
+                self.check_same_size(rhs, $verb)?;
+                let values = self
+                    .values
+                    .iter()
+                    .zip(rhs.values.iter())
+                    .map(|(a, b)| a.$fn(b))
+                    .collect::<Vec<$v>>();
+                Ok(self.with_values(values, self.number_of_rows, self.number_of_columns))
+            }
+        }
+
+        impl $trait for $t {
+            type Output = Result<$t>;
+
+            fn $fn(mut self, rhs: Self) -> Self::Output {
+                self.check_same_size(&rhs, $verb)?;
+                self.$assign_fn(&rhs);
+                Ok(self)
+            }
+        }
+
+        /// Combines the matrices value by value, in place. Panics if the matrices have different sizes.
+        impl $assign_trait<&$t> for $t {
+            fn $assign_fn(&mut self, rhs: &$t) {
+                if let Err(err) = self.check_same_size(rhs, $verb) {
+                    panic!("{}", err);
+                }
+                self.values
+                    .iter_mut()
+                    .zip(rhs.values.iter())
+                    .for_each(|(a, b)| a.$assign_fn(b));
+            }
+        }
+
+        impl $assign_trait for $t {
+            fn $assign_fn(&mut self, rhs: $t) {
+                self.$assign_fn(&rhs)
+            }
+        }
+    };
+}
+
+macro_rules! same_size {
+    ($t:ident) => {
+        impl $t {
+            fn check_same_size(&self, rhs: &Self, verb: &str) -> Result<()> {
+                if self.number_of_rows != rhs.number_of_rows
+                    || self.number_of_columns != rhs.number_of_columns
+                {
+                    return Err(anyhow!(
+                        "cannot {} matrices of sizes {}x{} and {}x{}",
+                        verb,
+                        self.number_of_rows,
+                        self.number_of_columns,
+                        rhs.number_of_rows,
+                        rhs.number_of_columns
+                    ));
+                }
+                Ok(())
+            }
+        }
+    };
+}
+
+same_size!(FractionMatrixF64);
+same_size!(FractionMatrixExact);
+
+macro_rules! add_sub {
+    ($t:ident, $v:ident) => {
+        add_sub_op!($t, $v, Add, add, AddAssign, add_assign, "add");
+        add_sub_op!($t, $v, Sub, sub, SubAssign, sub_assign, "subtract");
+    };
+}
+
+add_sub!(FractionMatrixF64, f64);
+add_sub!(FractionMatrixExact, Rational);
+
+macro_rules! add_sub_enum {
+    ($trait:ident, $fn:ident, $assign_trait:ident, $assign_fn:ident) => {
+        /// Returns an error if the matrices have different sizes, or if one is exact and the other approximate.
+        impl $trait for &FractionMatrixEnum {
+            type Output = Result<FractionMatrixEnum>;
+
+            fn $fn(self, rhs: Self) -> Self::Output {
+                match (self, rhs) {
+                    (FractionMatrixEnum::Approx(a), FractionMatrixEnum::Approx(b)) => {
+                        Ok(FractionMatrixEnum::Approx(a.$fn(b)?))
+                    }
+                    (FractionMatrixEnum::Exact(a), FractionMatrixEnum::Exact(b)) => {
+                        Ok(FractionMatrixEnum::Exact(a.$fn(b)?))
+                    }
+                    _ => Err(anyhow!("cannot combine exact and approximate arithmetic")),
+                }
+            }
+        }
+
+        impl $trait for FractionMatrixEnum {
+            type Output = Result<FractionMatrixEnum>;
+
+            fn $fn(self, rhs: Self) -> Self::Output {
+                match (self, rhs) {
+                    (FractionMatrixEnum::Approx(a), FractionMatrixEnum::Approx(b)) => {
+                        Ok(FractionMatrixEnum::Approx(a.$fn(b)?))
+                    }
+                    (FractionMatrixEnum::Exact(a), FractionMatrixEnum::Exact(b)) => {
+                        Ok(FractionMatrixEnum::Exact(a.$fn(b)?))
+                    }
+                    _ => Err(anyhow!("cannot combine exact and approximate arithmetic")),
+                }
+            }
+        }
+
+        /// Becomes [FractionMatrixEnum::CannotCombineExactAndApprox] if one matrix is exact and the other approximate.
+        /// Panics if the matrices have different sizes.
+        impl $assign_trait<&FractionMatrixEnum> for FractionMatrixEnum {
+            fn $assign_fn(&mut self, rhs: &FractionMatrixEnum) {
+                match (self, rhs) {
+                    (FractionMatrixEnum::Approx(a), FractionMatrixEnum::Approx(b)) => {
+                        a.$assign_fn(b)
+                    }
+                    (FractionMatrixEnum::Exact(a), FractionMatrixEnum::Exact(b)) => a.$assign_fn(b),
+                    (s, _) => *s = FractionMatrixEnum::CannotCombineExactAndApprox,
+                }
+            }
+        }
+
+        impl $assign_trait for FractionMatrixEnum {
+            fn $assign_fn(&mut self, rhs: FractionMatrixEnum) {
+                self.$assign_fn(&rhs)
+            }
+        }
+    };
+}
+
+add_sub_enum!(Add, add, AddAssign, add_assign);
+add_sub_enum!(Sub, sub, SubAssign, sub_assign);
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        EbiMatrix, IdentityMinus,
+        matrix::{
+            fraction_matrix_enum::FractionMatrixEnum, fraction_matrix_exact::FractionMatrixExact,
+            fraction_matrix_f64::FractionMatrixF64,
+        },
+    };
+
+    #[test]
+    fn add_sub() {
+        let p: FractionMatrixExact = vec![
+            vec![(1, 2).into(), (1, 2).into()],
+            vec![(1, 3).into(), (2, 3).into()],
+        ]
+        .try_into()
+        .unwrap();
+        let i = FractionMatrixExact::identity(2);
+        let mut q = p.clone();
+        q.identity_minus();
+        assert_eq!((&i - &p).unwrap(), q);
+
+        let mut m = p.clone();
+        m += &p;
+        m -= p.clone();
+        assert_eq!(m, p);
+        assert_eq!(
+            (p.clone() + p.clone()).unwrap(),
+            (&(&p + &p).unwrap() - &FractionMatrixExact::new(2, 2)).unwrap()
+        );
+        assert!((&p + &FractionMatrixExact::new(2, 3)).is_err());
+
+        let a: FractionMatrixF64 = vec![vec![1.into(), 2.into()]].try_into().unwrap();
+        let b: FractionMatrixF64 = vec![vec![3.into(), 4.into()]].try_into().unwrap();
+        assert_eq!(
+            (&a + &b).unwrap(),
+            vec![vec![4.into(), 6.into()]].try_into().unwrap()
+        );
+
+        let mut e = FractionMatrixEnum::Exact(p.clone());
+        e -= FractionMatrixEnum::Exact(p.clone());
+        assert_eq!(e, FractionMatrixEnum::Exact(FractionMatrixExact::new(2, 2)));
+        assert!((&e + &FractionMatrixEnum::Approx(a.clone())).is_err());
+        e += FractionMatrixEnum::Approx(a);
+        assert_eq!(e, FractionMatrixEnum::CannotCombineExactAndApprox);
+    }
+
+    #[test]
+    #[should_panic]
+    fn add_assign_size() {
+        let mut m = FractionMatrixF64::new(2, 2);
+        m += FractionMatrixF64::new(2, 3);
+    }
+}
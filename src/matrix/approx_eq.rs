@@ -0,0 +1,79 @@
+use crate::{
+    fraction::{
+        approx_eq::{ApproxEq, f64_approx_eq, rational_approx_eq},
+        fraction_enum::FractionEnum,
+        fraction_exact::FractionExact,
+        fraction_f64::FractionF64,
+    },
+    matrix::{
+        fraction_matrix_enum::FractionMatrixEnum, fraction_matrix_exact::FractionMatrixExact,
+        fraction_matrix_f64::FractionMatrixF64,
+    },
+};
+
+/// Matrices are approximately equal if they have the same size and each pair of values is within the tolerance.
+/// The epsilon of the matrix is not used.
+impl ApproxEq<FractionF64> for FractionMatrixF64 {
+    fn approx_eq(&self, other: &Self, tolerance: &FractionF64) -> bool {
+        self.number_of_rows == other.number_of_rows
+            && self.number_of_columns == other.number_of_columns
+            && self
+                .values
+                .iter()
+                .zip(other.values.iter())
+                .all(|(x, y)| f64_approx_eq(*x, *y, tolerance.0))
+    }
+}
+
+impl ApproxEq<FractionExact> for FractionMatrixExact {
+    fn approx_eq(&self, other: &Self, tolerance: &FractionExact) -> bool {
+        self.number_of_rows == other.number_of_rows
+            && self.number_of_columns == other.number_of_columns
+            && self
+                .values
+                .iter()
+                .zip(other.values.iter())
+                .all(|(x, y)| rational_approx_eq(x, y, &tolerance.0))
+    }
+}
+
+/// The tolerance may be exact or approximate, regardless of the matrices.
+impl ApproxEq<FractionEnum> for FractionMatrixEnum {
+    fn approx_eq(&self, other: &Self, tolerance: &FractionEnum) -> bool {
+        match (self, other) {
+            (FractionMatrixEnum::Approx(x), FractionMatrixEnum::Approx(y)) => tolerance
+                .approx_tolerance()
+                .is_some_and(|tolerance| x.approx_eq(y, &FractionF64(tolerance))),
+            (FractionMatrixEnum::Exact(x), FractionMatrixEnum::Exact(y)) => tolerance
+                .exact_tolerance()
+                .is_some_and(|tolerance| x.approx_eq(y, &FractionExact(tolerance))),
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        EbiMatrix,
+        fraction::{approx_eq::ApproxEq, fraction_enum::FractionEnum, fraction_f64::FractionF64},
+        matrix::{
+            fraction_matrix_enum::FractionMatrixEnum, fraction_matrix_f64::FractionMatrixF64,
+        },
+    };
+
+    #[test]
+    fn matrix_approx_eq() {
+        let m: FractionMatrixF64 = vec![vec![1.into(), 2.into()]].try_into().unwrap();
+        let n: FractionMatrixF64 = vec![vec![1.into(), 2.001.into()]].try_into().unwrap();
+        assert!(m.approx_eq(&n, &FractionF64::from(0.01)));
+        assert!(!m.approx_eq(&n, &FractionF64::from(0.0001)));
+        assert!(!m.approx_eq(&FractionMatrixF64::new(2, 1), &FractionF64::from(10.0)));
+
+        let m = FractionMatrixEnum::Approx(m);
+        assert!(!m.approx_eq(
+            &FractionMatrixEnum::CannotCombineExactAndApprox,
+            &FractionEnum::Approx(1.0)
+        ));
+    }
+}
@@ -0,0 +1,195 @@
+use malachite::rational::Rational;
+
+use crate::{
+    Zero,
+    matrix::{fraction_matrix_exact::FractionMatrixExact, fraction_matrix_f64::FractionMatrixF64},
+};
+
+/// Solves a banded system A x = d with Gaussian elimination restricted to the band, without pivoting.
+/// The elimination takes O(n · lower · upper) time and O(n · (lower + upper)) space, on top of copying the band out of the dense matrix.
+/// Returns None if a pivot is zero, in which case the caller should fall back to general elimination.
+macro_rules! banded_elimination {
+    ($self:ident, $d:ident, $lower:ident, $upper:ident, $v:ident) => {{
+        let n = $self.number_of_rows;
+        let width = $lower + $upper + 1;
+
+        //band[i * width + j + lower - i] holds the value at (i, j)
+        let mut band: Vec<$v> = Vec::with_capacity(n * width);
+        for i in 0..n {
+            for offset in 0..width {
+                match (i + offset).checked_sub($lower).filter(|j| *j < n) {
+                    Some(j) => band.push($self.values[$self.index(i, j)].clone()),
+                    None => band.push($v::zero()),
+                }
+            }
+        }
+        let mut x: Vec<$v> = $d.to_vec();
+
+        //forward elimination: only the rows below the pivot that lie within the band are touched
+        for k in 0..n {
+            let pivot = band[k * width + $lower].clone();
+            if $self.is_zero_value(&pivot) {
+                return None;
+            }
+            for i in k + 1..n.min(k + $lower + 1) {
+                let entry = &band[i * width + k + $lower - i];
+                if $self.is_zero_value(entry) {
+                    continue;
+                }
+                let factor = entry / &pivot;
+                for j in k..n.min(k + $upper + 1) {
+                    let value = &band[k * width + j + $lower - k] * &factor;
+                    band[i * width + j + $lower - i] -= value;
+                }
+                let value = &x[k] * &factor;
+                x[i] -= value;
+            }
+        }
+
+        //back substitution
+        for i in (0..n).rev() {
+            for j in i + 1..n.min(i + $upper + 1) {
+                let value = &band[i * width + j + $lower - i] * &x[j];
+                x[i] -= value;
+            }
+            x[i] /= &band[i * width + $lower];
+        }
+        Some(x)
+    }};
+}
+
+macro_rules! banded {
+    ($t:ident) => {
+        impl $t {
+            /// Returns the number of non-zero diagonals below and above the main diagonal.
+            /// A diagonal matrix has bandwidths (0, 0), a tridiagonal matrix at most (1, 1).
+            /// Scans all values of the matrix.
+            pub fn bandwidths(&self) -> (usize, usize) {
+                let mut lower = 0;
+                let mut upper = 0;
+                for row in 0..self.number_of_rows {
+                    for column in 0..self.number_of_columns {
+                        if !self.is_zero_value(&self.values[self.index(row, column)]) {
+                            if row > column {
+                                lower = lower.max(row - column);
+                            } else {
+                                upper = upper.max(column - row);
+                            }
+                        }
+                    }
+                }
+                (lower, upper)
+            }
+
+            /// Returns whether the matrix is square and so narrowly banded that elimination within the band pays off:
+            /// at most half of its diagonals may contain non-zero values.
+            pub fn is_banded(&self) -> bool {
+                if self.number_of_rows != self.number_of_columns {
+                    return false;
+                }
+                let (lower, upper) = self.bandwidths();
+                2 * (lower + upper + 1) <= self.number_of_rows
+            }
+        }
+    };
+}
+
+banded!(FractionMatrixF64);
+banded!(FractionMatrixExact);
+
+impl FractionMatrixF64 {
+    /// Solves the system with elimination within the band if the matrix is tridiagonal or banded, and diagonally dominant, for which it is stable without pivoting.
+    /// Returns None otherwise, or if a pivot is zero.
+    pub(crate) fn solve_banded(&self, d: &[f64]) -> Option<Vec<f64>> {
+        if !(self.is_tridiagonal() || self.is_banded()) || !self.is_diagonally_dominant() {
+            return None;
+        }
+        let (lower, upper) = self.bandwidths();
+        banded_elimination!(self, d, lower, upper, f64)
+    }
+
+    /// Returns whether, in each row, the absolute value on the diagonal is at least the sum of the other absolute values.
+    pub(crate) fn is_diagonally_dominant(&self) -> bool {
+        (0..self.number_of_rows).all(|row| {
+            let off_diagonal: f64 = (0..self.number_of_columns)
+                .filter(|column| *column != row)
+                .map(|column| self.values[self.index(row, column)].abs())
+                .sum();
+            self.values[self.index(row, row)].abs() >= off_diagonal
+        })
+    }
+}
+
+impl FractionMatrixExact {
+    /// Solves the system with elimination within the band if the matrix is tridiagonal or banded; exact pivots need no dominance.
+    /// Returns None otherwise, or if a pivot is zero.
+    pub(crate) fn solve_banded(&self, d: &[Rational]) -> Option<Vec<Rational>> {
+        if !(self.is_tridiagonal() || self.is_banded()) {
+            return None;
+        }
+        let (lower, upper) = self.bandwidths();
+        banded_elimination!(self, d, lower, upper, Rational)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Solve,
+        fraction::{fraction_exact::FractionExact, fraction_f64::FractionF64},
+        matrix::{
+            fraction_matrix_exact::FractionMatrixExact, fraction_matrix_f64::FractionMatrixF64,
+        },
+    };
+
+    #[test]
+    fn banded() {
+        //one diagonal below the main diagonal and two above it
+        let values: Vec<Vec<FractionExact>> = (0..8)
+            .map(|row: usize| {
+                (0..8)
+                    .map(|column: usize| match column as isize - row as isize {
+                        0 => 6.into(),
+                        -1 | 1 => (-1).into(),
+                        2 => (-2).into(),
+                        _ => 0.into(),
+                    })
+                    .collect()
+            })
+            .collect();
+        let m: FractionMatrixExact = values.try_into().unwrap();
+        assert_eq!(m.bandwidths(), (1, 2));
+        assert!(m.is_banded());
+        assert!(!m.is_tridiagonal());
+
+        let x: Vec<FractionExact> = (1..=8).map(|i| (i, 7).into()).collect();
+        let rhs = (&m * &x).unwrap();
+        let b = rhs.iter().map(|f| f.0.clone()).collect::<Vec<_>>();
+        assert_eq!(
+            m.solve_banded(&b).unwrap(),
+            x.iter().map(|f| f.0.clone()).collect::<Vec<_>>()
+        );
+        assert_eq!(m.solve(&rhs).unwrap(), x);
+
+        let m: FractionMatrixF64 = vec![
+            vec![4.into(), 0.into(), 1.into(), 0.into()],
+            vec![0.into(), 4.into(), 0.into(), 1.into()],
+            vec![1.into(), 0.into(), 4.into(), 0.into()],
+            vec![0.into(), 1.into(), 0.into(), 4.into()],
+        ]
+        .try_into()
+        .unwrap();
+        assert_eq!(m.bandwidths(), (2, 2));
+        assert!(!m.is_banded());
+
+        let m: FractionMatrixF64 = vec![vec![2.into(), 0.into()], vec![0.into(), 4.into()]]
+            .try_into()
+            .unwrap();
+        assert_eq!(m.bandwidths(), (0, 0));
+        assert_eq!(m.solve_banded(&[1.0, 1.0]), Some(vec![0.5, 0.25]));
+        assert_eq!(
+            m.solve(&[1.into(), 1.into()]).unwrap(),
+            vec![FractionF64::from(0.5), 0.25.into()]
+        );
+    }
+}
@@ -0,0 +1,65 @@
+use crate::{
+    fraction::bits::bits,
+    matrix::{
+        fraction_matrix_enum::FractionMatrixEnum, fraction_matrix_exact::FractionMatrixExact,
+    },
+};
+
+impl FractionMatrixExact {
+    /// Returns the largest number of bits of the numerator and the denominator of a value together; zero for an empty matrix.
+    /// Useful to monitor the growth of values during iterated exact computations, for instance to decide when to limit denominators.
+    pub fn max_entry_bits(&self) -> u64 {
+        self.values
+            .iter()
+            .map(|value| {
+                let (numerator, denominator) = bits(value);
+                numerator + denominator
+            })
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+impl FractionMatrixEnum {
+    /// Returns the largest number of bits of the numerator and the denominator of a value together, for an exact matrix.
+    /// Returns None for approximate matrices, of which the values have a fixed size.
+    pub fn max_entry_bits(&self) -> Option<u64> {
+        match self {
+            FractionMatrixEnum::Exact(m) => Some(m.max_entry_bits()),
+            FractionMatrixEnum::Approx(_) | FractionMatrixEnum::CannotCombineExactAndApprox => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        EbiMatrix,
+        matrix::{
+            fraction_matrix_enum::FractionMatrixEnum, fraction_matrix_exact::FractionMatrixExact,
+            fraction_matrix_f64::FractionMatrixF64,
+        },
+    };
+
+    #[test]
+    fn max_entry_bits() {
+        assert_eq!(FractionMatrixExact::new(0, 0).max_entry_bits(), 0);
+
+        //a zero has no numerator bits and a denominator of one bit
+        assert_eq!(FractionMatrixExact::new(2, 2).max_entry_bits(), 1);
+
+        //the sign is excluded: -255/2 has 8 + 2 bits, 3/4 has 2 + 3 bits
+        let m: FractionMatrixExact = vec![vec![(-255, 2).into(), (3, 4).into(), 0.into()]]
+            .try_into()
+            .unwrap();
+        assert_eq!(m.max_entry_bits(), 10);
+        assert_eq!(FractionMatrixEnum::Exact(m).max_entry_bits(), Some(10));
+
+        let a: FractionMatrixF64 = vec![vec![(-127.5).into(), 0.75.into()]].try_into().unwrap();
+        assert_eq!(FractionMatrixEnum::Approx(a).max_entry_bits(), None);
+        assert_eq!(
+            FractionMatrixEnum::CannotCombineExactAndApprox.max_entry_bits(),
+            None
+        );
+    }
+}
@@ -0,0 +1,119 @@
+use anyhow::{Result, anyhow};
+use malachite::rational::Rational;
+
+use crate::{
+    BlockDiagonal, EbiMatrix, Zero,
+    matrix::{
+        fraction_matrix_enum::FractionMatrixEnum, fraction_matrix_exact::FractionMatrixExact,
+        fraction_matrix_f64::FractionMatrixF64,
+    },
+};
+
+macro_rules! block_diagonal {
+    ($blocks:ident, $t:ident, $v:ident) => {{
+        let number_of_rows = $blocks.iter().map(|b| b.number_of_rows).sum();
+        let number_of_columns = $blocks.iter().map(|b| b.number_of_columns).sum();
+
+        let mut values = vec![$v::zero(); number_of_rows * number_of_columns];
+
+        //move the values of each block into place, row by row
+        let mut row_offset = 0;
+        let mut column_offset = 0;
+        for block in &mut $blocks {
+            for (i, value) in block.values.drain(..).enumerate() {
+                let row = row_offset + i / block.number_of_columns;
+                let column = column_offset + i % block.number_of_columns;
+                values[row * number_of_columns + column] = value;
+            }
+            row_offset += block.number_of_rows;
+            column_offset += block.number_of_columns;
+        }
+
+        Ok(match $blocks.first() {
+            Some(first) => first.with_values(values, number_of_rows, number_of_columns),
+            None => $t::new(0, 0),
+        })
+    }};
+}
+
+impl BlockDiagonal for FractionMatrixF64 {
+    fn block_diagonal(mut blocks: Vec<Self>) -> Result<Self> {
+        block_diagonal!(blocks, FractionMatrixF64, f64)
+    }
+}
+
+impl BlockDiagonal for FractionMatrixExact {
+    fn block_diagonal(mut blocks: Vec<Self>) -> Result<Self> {
+        block_diagonal!(blocks, FractionMatrixExact, Rational)
+    }
+}
+
+impl BlockDiagonal for FractionMatrixEnum {
+    fn block_diagonal(blocks: Vec<Self>) -> Result<Self> {
+        if blocks.is_empty() {
+            return Ok(FractionMatrixEnum::new(0, 0));
+        }
+
+        if blocks
+            .iter()
+            .all(|b| matches!(b, FractionMatrixEnum::Exact(_)))
+        {
+            let blocks = blocks
+                .into_iter()
+                .filter_map(|b| match b {
+                    FractionMatrixEnum::Exact(m) => Some(m),
+                    _ => None,
+                })
+                .collect();
+            Ok(FractionMatrixEnum::Exact(
+                FractionMatrixExact::block_diagonal(blocks)?,
+            ))
+        } else if blocks
+            .iter()
+            .all(|b| matches!(b, FractionMatrixEnum::Approx(_)))
+        {
+            let blocks = blocks
+                .into_iter()
+                .filter_map(|b| match b {
+                    FractionMatrixEnum::Approx(m) => Some(m),
+                    _ => None,
+                })
+                .collect();
+            Ok(FractionMatrixEnum::Approx(
+                FractionMatrixF64::block_diagonal(blocks)?,
+            ))
+        } else {
+            Err(anyhow!("cannot combine exact and approximate arithmetic"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        BlockDiagonal, EbiMatrix, fraction::fraction::Fraction,
+        matrix::fraction_matrix::FractionMatrix,
+    };
+
+    #[test]
+    fn block_diagonal() {
+        let a: FractionMatrix = vec![vec![1.into(), 2.into()], vec![3.into(), 4.into()]]
+            .try_into()
+            .unwrap();
+        let b: FractionMatrix = vec![vec![5.into()]].try_into().unwrap();
+        let c = FractionMatrix::new(1, 0);
+
+        let m = FractionMatrix::block_diagonal(vec![a, c, b]).unwrap();
+
+        let correct: Vec<Vec<Fraction>> = vec![
+            vec![1.into(), 2.into(), 0.into()],
+            vec![3.into(), 4.into(), 0.into()],
+            vec![0.into(), 0.into(), 0.into()],
+            vec![0.into(), 0.into(), 5.into()],
+        ];
+        assert_eq!(m.to_vec(), correct);
+
+        let empty = FractionMatrix::block_diagonal(vec![]).unwrap();
+        assert_eq!(empty.number_of_rows(), 0);
+    }
+}
@@ -0,0 +1,145 @@
+use anyhow::{Result, anyhow};
+use std::ops::Range;
+
+use crate::{
+    Blocks,
+    matrix::{
+        fraction_matrix_enum::FractionMatrixEnum, fraction_matrix_exact::FractionMatrixExact,
+        fraction_matrix_f64::FractionMatrixF64,
+    },
+};
+
+macro_rules! blocks {
+    ($t:ident) => {
+        impl Blocks for $t {
+            fn submatrix(&self, rows: Range<usize>, columns: Range<usize>) -> Result<Self> {
+                Ok(self.view(rows, columns)?.to_matrix())
+            }
+
+            fn from_blocks(blocks: &[[&Self; 2]; 2]) -> Result<Self> {
+                let [[a, b], [c, d]] = *blocks;
+                if a.number_of_rows != b.number_of_rows
+                    || c.number_of_rows != d.number_of_rows
+                    || a.number_of_columns != c.number_of_columns
+                    || b.number_of_columns != d.number_of_columns
+                {
+                    return Err(anyhow!(
+                        "cannot compose blocks of sizes [[{}x{}, {}x{}], [{}x{}, {}x{}]]",
+                        a.number_of_rows,
+                        a.number_of_columns,
+                        b.number_of_rows,
+                        b.number_of_columns,
+                        c.number_of_rows,
+                        c.number_of_columns,
+                        d.number_of_rows,
+                        d.number_of_columns
+                    ));
+                }
+
+                let number_of_rows = a.number_of_rows + c.number_of_rows;
+                let number_of_columns = a.number_of_columns + b.number_of_columns;
+                let mut values = Vec::with_capacity(number_of_rows * number_of_columns);
+                for [left, right] in [[a, b], [c, d]] {
+                    for row in 0..left.number_of_rows {
+                        values.extend_from_slice(
+                            &left.values
+                                [left.index(row, 0)..left.index(row, left.number_of_columns)],
+                        );
+                        values.extend_from_slice(
+                            &right.values
+                                [right.index(row, 0)..right.index(row, right.number_of_columns)],
+                        );
+                    }
+                }
+                Ok(a.with_values(values, number_of_rows, number_of_columns))
+            }
+        }
+    };
+}
+
+blocks!(FractionMatrixF64);
+blocks!(FractionMatrixExact);
+
+impl Blocks for FractionMatrixEnum {
+    fn submatrix(&self, rows: Range<usize>, columns: Range<usize>) -> Result<Self> {
+        match self {
+            FractionMatrixEnum::Approx(m) => {
+                Ok(FractionMatrixEnum::Approx(m.submatrix(rows, columns)?))
+            }
+            FractionMatrixEnum::Exact(m) => {
+                Ok(FractionMatrixEnum::Exact(m.submatrix(rows, columns)?))
+            }
+            FractionMatrixEnum::CannotCombineExactAndApprox => {
+                Err(anyhow!("cannot combine exact and approximate arithmetic"))
+            }
+        }
+    }
+
+    fn from_blocks(blocks: &[[&Self; 2]; 2]) -> Result<Self> {
+        match blocks {
+            [
+                [FractionMatrixEnum::Approx(a), FractionMatrixEnum::Approx(b)],
+                [FractionMatrixEnum::Approx(c), FractionMatrixEnum::Approx(d)],
+            ] => Ok(FractionMatrixEnum::Approx(FractionMatrixF64::from_blocks(
+                &[[a, b], [c, d]],
+            )?)),
+            [
+                [FractionMatrixEnum::Exact(a), FractionMatrixEnum::Exact(b)],
+                [FractionMatrixEnum::Exact(c), FractionMatrixEnum::Exact(d)],
+            ] => Ok(FractionMatrixEnum::Exact(FractionMatrixExact::from_blocks(
+                &[[a, b], [c, d]],
+            )?)),
+            _ => Err(anyhow!("cannot combine exact and approximate arithmetic")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Blocks, EbiMatrix,
+        fraction::fraction_exact::FractionExact,
+        matrix::{
+            fraction_matrix_enum::FractionMatrixEnum, fraction_matrix_exact::FractionMatrixExact,
+        },
+    };
+
+    #[test]
+    fn blocks() {
+        //an absorbing Markov chain in canonical form [[Q, R], [0, I]]
+        let p: FractionMatrixExact = vec![
+            vec![0.into(), (1, 2).into(), (1, 2).into(), 0.into()],
+            vec![(1, 3).into(), 0.into(), 0.into(), (2, 3).into()],
+            vec![0.into(), 0.into(), 1.into(), 0.into()],
+            vec![0.into(), 0.into(), 0.into(), 1.into()],
+        ]
+        .try_into()
+        .unwrap();
+        let q = p.submatrix(0..2, 0..2).unwrap();
+        let r = p.submatrix(0..2, 2..4).unwrap();
+        let zero = p.submatrix(2..4, 0..2).unwrap();
+        let i = p.submatrix(2..4, 2..4).unwrap();
+        let correct: Vec<Vec<FractionExact>> =
+            vec![vec![(1, 2).into(), 0.into()], vec![0.into(), (2, 3).into()]];
+        assert_eq!(r.clone().to_vec(), correct);
+        assert_eq!(zero, FractionMatrixExact::new(2, 2));
+        assert_eq!(i, FractionMatrixExact::identity(2));
+        assert_eq!(
+            FractionMatrixExact::from_blocks(&[[&q, &r], [&zero, &i]]).unwrap(),
+            p
+        );
+
+        assert_eq!(p.submatrix(1..1, 0..4).unwrap().number_of_rows(), 0);
+        assert!(p.submatrix(0..5, 0..1).is_err());
+        assert!(FractionMatrixExact::from_blocks(&[[&q, &r], [&p, &i]]).is_err());
+
+        let e = FractionMatrixEnum::Exact(q);
+        assert!(
+            FractionMatrixEnum::from_blocks(&[
+                [&e, &e],
+                [&e, &FractionMatrixEnum::CannotCombineExactAndApprox]
+            ])
+            .is_err()
+        );
+    }
+}
@@ -0,0 +1,345 @@
+//! A compact, versioned binary format for matrices, to cache intermediate results between runs.
+//!
+//! All integers are little-endian; sizes are unsigned LEB128 varints, that is, 7 bits per byte with the highest bit set on all but the last byte.
+//! A matrix consists of:
+//! - the 4 bytes `EBIM`;
+//! - the version of the format, 1 byte, currently [BYTES_VERSION];
+//! - the kind of the values, 1 byte: 0 for approximate and 1 for exact;
+//! - the number of rows and the number of columns, each a varint;
+//! - for approximate matrices: the tolerance, followed by the values in row-major order, each an f64;
+//! - for exact matrices: the values in row-major order, each as its sign (1 byte: 0 for non-negative, 1 for negative),
+//!   followed by its numerator and its denominator, each as a varint number of bytes followed by those bytes.
+//!   Small values thus take few bytes: 1/2 takes 5.
+
+use anyhow::{Result, anyhow};
+use malachite::{
+    Natural,
+    base::num::{basic::traits::Zero, conversion::traits::PowerOf2Digits},
+    rational::Rational,
+};
+
+use crate::matrix::{
+    fraction_matrix_enum::FractionMatrixEnum, fraction_matrix_exact::FractionMatrixExact,
+    fraction_matrix_f64::FractionMatrixF64,
+};
+
+const MAGIC: &[u8; 4] = b"EBIM";
+
+/// The version of the binary format that is written. Bytes of other versions are refused.
+pub const BYTES_VERSION: u8 = 1;
+
+const KIND_APPROXIMATE: u8 = 0;
+const KIND_EXACT: u8 = 1;
+
+fn write_header(bytes: &mut Vec<u8>, kind: u8, number_of_rows: usize, number_of_columns: usize) {
+    bytes.extend_from_slice(MAGIC);
+    bytes.push(BYTES_VERSION);
+    bytes.push(kind);
+    write_varint(bytes, number_of_rows as u64);
+    write_varint(bytes, number_of_columns as u64);
+}
+
+fn write_varint(bytes: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        bytes.push((value & 0x7f) as u8 | 0x80);
+        value >>= 7;
+    }
+    bytes.push(value as u8);
+}
+
+fn write_natural(bytes: &mut Vec<u8>, value: &Natural) {
+    let digits: Vec<u8> = value.to_power_of_2_digits_asc(8);
+    write_varint(bytes, digits.len() as u64);
+    bytes.extend_from_slice(&digits);
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, length: usize) -> Result<&'a [u8]> {
+        if length > self.bytes.len() {
+            return Err(anyhow!("the bytes of the matrix end prematurely"));
+        }
+        let (taken, rest) = self.bytes.split_at(length);
+        self.bytes = rest;
+        Ok(taken)
+    }
+
+    fn u8(&mut self) -> Result<u8> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn varint(&mut self) -> Result<u64> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = self.u8()?;
+            let bits = u64::from(byte & 0x7f);
+            if bits << shift >> shift != bits {
+                break;
+            }
+            value |= bits << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(anyhow!("a size in the bytes of the matrix is too large"))
+    }
+
+    fn usize(&mut self) -> Result<usize> {
+        Ok(usize::try_from(self.varint()?)?)
+    }
+
+    fn f64(&mut self) -> Result<f64> {
+        Ok(f64::from_le_bytes(self.take(8)?.try_into()?))
+    }
+
+    fn natural(&mut self) -> Result<Natural> {
+        let length = self.usize()?;
+        Natural::from_power_of_2_digits_asc(8, self.take(length)?.iter().copied())
+            .ok_or_else(|| anyhow!("invalid digits in the bytes of the matrix"))
+    }
+
+    /// Returns the kind, the number of rows and the number of columns.
+    fn header(&mut self) -> Result<(u8, usize, usize)> {
+        if self.take(MAGIC.len())? != MAGIC {
+            return Err(anyhow!("the bytes do not hold a matrix"));
+        }
+        let version = self.u8()?;
+        if version != BYTES_VERSION {
+            return Err(anyhow!(
+                "cannot read version {} of the binary matrix format; version {} is supported",
+                version,
+                BYTES_VERSION
+            ));
+        }
+        let kind = self.u8()?;
+        let number_of_rows = self.usize()?;
+        let number_of_columns = self.usize()?;
+        Ok((kind, number_of_rows, number_of_columns))
+    }
+
+    fn finish(&self) -> Result<()> {
+        if !self.bytes.is_empty() {
+            return Err(anyhow!(
+                "{} bytes remain after the matrix",
+                self.bytes.len()
+            ));
+        }
+        Ok(())
+    }
+}
+
+fn number_of_values(number_of_rows: usize, number_of_columns: usize) -> Result<usize> {
+    number_of_rows
+        .checked_mul(number_of_columns)
+        .ok_or_else(|| {
+            anyhow!(
+                "a matrix of size {}x{} is too large",
+                number_of_rows,
+                number_of_columns
+            )
+        })
+}
+
+fn read_approximate(
+    reader: &mut Reader,
+    number_of_rows: usize,
+    number_of_columns: usize,
+) -> Result<FractionMatrixF64> {
+    let epsilon = reader.f64()?;
+    let values = (0..number_of_values(number_of_rows, number_of_columns)?)
+        .map(|_| reader.f64())
+        .collect::<Result<Vec<_>>>()?;
+    Ok(FractionMatrixF64 {
+        values,
+        number_of_rows,
+        number_of_columns,
+        epsilon,
+    })
+}
+
+fn read_exact(
+    reader: &mut Reader,
+    number_of_rows: usize,
+    number_of_columns: usize,
+) -> Result<FractionMatrixExact> {
+    let values = (0..number_of_values(number_of_rows, number_of_columns)?)
+        .map(|_| {
+            let negative = match reader.u8()? {
+                0 => false,
+                1 => true,
+                sign => return Err(anyhow!("invalid sign {} in the bytes of the matrix", sign)),
+            };
+            let numerator = reader.natural()?;
+            let denominator = reader.natural()?;
+            if denominator == Natural::ZERO {
+                return Err(anyhow!("a denominator in the bytes of the matrix is zero"));
+            }
+            Ok(Rational::from_sign_and_naturals(
+                !negative,
+                numerator,
+                denominator,
+            ))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(FractionMatrixExact {
+        values,
+        number_of_rows,
+        number_of_columns,
+    })
+}
+
+impl FractionMatrixF64 {
+    /// Encodes the matrix, including its tolerance, in the binary format of this module.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(30 + 8 * self.values.len());
+        write_header(
+            &mut bytes,
+            KIND_APPROXIMATE,
+            self.number_of_rows,
+            self.number_of_columns,
+        );
+        bytes.extend_from_slice(&self.epsilon.to_le_bytes());
+        for value in &self.values {
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Decodes a matrix that was encoded with [FractionMatrixF64::to_bytes].
+    /// Returns an error for the bytes of an exact matrix.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        match FractionMatrixEnum::from_bytes(bytes)? {
+            FractionMatrixEnum::Approx(m) => Ok(m),
+            _ => Err(anyhow!(
+                "the bytes hold an exact matrix rather than an approximate one"
+            )),
+        }
+    }
+}
+
+impl FractionMatrixExact {
+    /// Encodes the matrix in the binary format of this module.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![];
+        write_header(
+            &mut bytes,
+            KIND_EXACT,
+            self.number_of_rows,
+            self.number_of_columns,
+        );
+        for value in &self.values {
+            bytes.push(if *value < 0u32 { 1 } else { 0 });
+            write_natural(&mut bytes, value.numerator_ref());
+            write_natural(&mut bytes, value.denominator_ref());
+        }
+        bytes
+    }
+
+    /// Decodes a matrix that was encoded with [FractionMatrixExact::to_bytes].
+    /// Returns an error for the bytes of an approximate matrix.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        match FractionMatrixEnum::from_bytes(bytes)? {
+            FractionMatrixEnum::Exact(m) => Ok(m),
+            _ => Err(anyhow!(
+                "the bytes hold an approximate matrix rather than an exact one"
+            )),
+        }
+    }
+}
+
+impl FractionMatrixEnum {
+    /// Encodes the matrix in the binary format of this module.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        match self {
+            FractionMatrixEnum::Approx(m) => Ok(m.to_bytes()),
+            FractionMatrixEnum::Exact(m) => Ok(m.to_bytes()),
+            FractionMatrixEnum::CannotCombineExactAndApprox => {
+                Err(anyhow!("cannot combine exact and approximate arithmetic"))
+            }
+        }
+    }
+
+    /// Decodes a matrix that was encoded with `to_bytes` on any of the matrix types, keeping whether it is exact.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let mut reader = Reader { bytes };
+        let (kind, number_of_rows, number_of_columns) = reader.header()?;
+        let result = match kind {
+            KIND_APPROXIMATE => FractionMatrixEnum::Approx(read_approximate(
+                &mut reader,
+                number_of_rows,
+                number_of_columns,
+            )?),
+            KIND_EXACT => FractionMatrixEnum::Exact(read_exact(
+                &mut reader,
+                number_of_rows,
+                number_of_columns,
+            )?),
+            _ => return Err(anyhow!("unknown kind {} of matrix", kind)),
+        };
+        reader.finish()?;
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        EbiMatrix,
+        matrix::{
+            bytes::BYTES_VERSION, fraction_matrix_enum::FractionMatrixEnum,
+            fraction_matrix_exact::FractionMatrixExact, fraction_matrix_f64::FractionMatrixF64,
+        },
+    };
+
+    #[test]
+    fn bytes() {
+        let m: FractionMatrixExact = vec![
+            vec![(-1, 3).into(), 0.into()],
+            vec![(123456789012345678901234567890u128, 7u128).into(), 1.into()],
+        ]
+        .try_into()
+        .unwrap();
+        let bytes = m.to_bytes();
+        assert_eq!(FractionMatrixExact::from_bytes(&bytes).unwrap(), m);
+        assert!(FractionMatrixF64::from_bytes(&bytes).is_err());
+        assert!(FractionMatrixExact::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+
+        let mut newer = bytes.clone();
+        newer[4] = BYTES_VERSION + 1;
+        assert!(FractionMatrixEnum::from_bytes(&newer).is_err());
+
+        let mut a = FractionMatrixF64::from(&m);
+        a.set_epsilon(1e-6);
+        let bytes = a.to_bytes();
+        assert_eq!(bytes.len(), 4 + 1 + 1 + 1 + 1 + 8 + 4 * 8);
+        let b = FractionMatrixF64::from_bytes(&bytes).unwrap();
+        assert_eq!(b, a);
+        assert_eq!(b.epsilon(), 1e-6);
+
+        let mut longer = bytes;
+        longer.push(0);
+        assert!(FractionMatrixEnum::from_bytes(&longer).is_err());
+    }
+
+    #[test]
+    fn bytes_size() {
+        //header of 8 bytes, then the sign, and a length byte and a digit byte for each of the numerator and denominator
+        let half: FractionMatrixExact = vec![vec![(1, 2).into()]].try_into().unwrap();
+        assert_eq!(half.to_bytes().len(), 8 + 5);
+
+        //sizes of 128 and more take more than one byte; zero has no digits
+        let m = FractionMatrixExact::new(200, 1);
+        let bytes = m.to_bytes();
+        assert_eq!(bytes.len(), 4 + 1 + 1 + 2 + 1 + 200 * 4);
+        assert_eq!(FractionMatrixExact::from_bytes(&bytes).unwrap(), m);
+
+        //a varint beyond the range of u64
+        let mut bytes = half.to_bytes()[..6].to_vec();
+        bytes.extend_from_slice(&[0xff; 10]);
+        bytes.push(0x01);
+        assert!(FractionMatrixEnum::from_bytes(&bytes).is_err());
+    }
+}
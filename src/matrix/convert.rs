@@ -0,0 +1,97 @@
+//! Conversions between the matrix backends, with the same semantics as the conversions between the fraction backends.
+
+use anyhow::{Error, Result};
+
+use crate::{
+    exact::MaybeExact,
+    matrix::{
+        exact::{to_approx, to_exact},
+        fraction_matrix_enum::FractionMatrixEnum,
+        fraction_matrix_exact::FractionMatrixExact,
+        fraction_matrix_f64::FractionMatrixF64,
+    },
+};
+
+/// Rounds every value to the nearest float; the result has the default tolerance.
+impl From<&FractionMatrixExact> for FractionMatrixF64 {
+    fn from(matrix: &FractionMatrixExact) -> Self {
+        to_approx(matrix)
+    }
+}
+
+impl From<FractionMatrixExact> for FractionMatrixF64 {
+    fn from(matrix: FractionMatrixExact) -> Self {
+        to_approx(&matrix)
+    }
+}
+
+/// Converts every float to the exact value it represents.
+/// Returns an error if the matrix contains infinite or NaN values.
+impl TryFrom<&FractionMatrixF64> for FractionMatrixExact {
+    type Error = Error;
+
+    fn try_from(matrix: &FractionMatrixF64) -> Result<Self> {
+        to_exact(matrix)
+    }
+}
+
+impl TryFrom<FractionMatrixF64> for FractionMatrixExact {
+    type Error = Error;
+
+    fn try_from(matrix: FractionMatrixF64) -> Result<Self> {
+        to_exact(&matrix)
+    }
+}
+
+/// Keeps the matrix exact, regardless of the global arithmetic mode.
+impl From<FractionMatrixExact> for FractionMatrixEnum {
+    fn from(matrix: FractionMatrixExact) -> Self {
+        FractionMatrixEnum::Exact(matrix)
+    }
+}
+
+/// Keeps the matrix approximate, regardless of the global arithmetic mode.
+impl From<FractionMatrixF64> for FractionMatrixEnum {
+    fn from(matrix: FractionMatrixF64) -> Self {
+        FractionMatrixEnum::Approx(matrix)
+    }
+}
+
+/// Approximate matrices are converted to the exact values of their floats.
+/// Returns an error for infinite and NaN values, and if the matrix combines exact and approximate arithmetic.
+impl TryFrom<FractionMatrixEnum> for FractionMatrixExact {
+    type Error = Error;
+
+    fn try_from(matrix: FractionMatrixEnum) -> Result<Self> {
+        match matrix {
+            FractionMatrixEnum::Exact(m) => Ok(m),
+            matrix => matrix.approx_to_exact(),
+        }
+    }
+}
+
+impl TryFrom<&FractionMatrixEnum> for FractionMatrixExact {
+    type Error = Error;
+
+    fn try_from(matrix: &FractionMatrixEnum) -> Result<Self> {
+        matrix.approx_to_exact()
+    }
+}
+
+/// Exact matrices are rounded to the nearest floats.
+/// Returns an error if the matrix combines exact and approximate arithmetic.
+impl TryFrom<FractionMatrixEnum> for FractionMatrixF64 {
+    type Error = Error;
+
+    fn try_from(matrix: FractionMatrixEnum) -> Result<Self> {
+        matrix.into_approx()
+    }
+}
+
+impl TryFrom<&FractionMatrixEnum> for FractionMatrixF64 {
+    type Error = Error;
+
+    fn try_from(matrix: &FractionMatrixEnum) -> Result<Self> {
+        Ok(matrix.approx_cow()?.into_owned())
+    }
+}
@@ -0,0 +1,120 @@
+use anyhow::{Result, anyhow};
+use malachite::rational::Rational;
+
+use crate::{
+    ConvexCombination,
+    fraction::{
+        fraction_enum::FractionEnum, fraction_exact::FractionExact, fraction_f64::FractionF64,
+    },
+    matrix::{
+        fraction_matrix_enum::FractionMatrixEnum, fraction_matrix_exact::FractionMatrixExact,
+        fraction_matrix_f64::FractionMatrixF64,
+    },
+};
+
+macro_rules! convex_combination {
+    ($alpha:expr, $a:ident, $b:ident, $zero:expr, $one:expr) => {{
+        let alpha = $alpha;
+        if !($zero..=$one).contains(alpha) {
+            return Err(anyhow!(
+                "the weight {} of a convex combination must be between zero and one",
+                alpha
+            ));
+        }
+        if $a.number_of_rows != $b.number_of_rows || $a.number_of_columns != $b.number_of_columns {
+            return Err(anyhow!(
+                "cannot combine a {}x{} matrix with a {}x{} matrix",
+                $a.number_of_rows,
+                $a.number_of_columns,
+                $b.number_of_rows,
+                $b.number_of_columns
+            ));
+        }
+        let complement = &$one - alpha;
+        let values = $a
+            .values
+            .iter()
+            .zip($b.values.iter())
+            .map(|(x, y)| alpha * x + &complement * y)
+            .collect();
+        Ok($a.with_values(values, $a.number_of_rows, $a.number_of_columns))
+    }};
+}
+
+impl ConvexCombination<FractionF64> for FractionMatrixF64 {
+    fn convex_combination(alpha: &FractionF64, a: &Self, b: &Self) -> Result<Self> {
+        convex_combination!(&alpha.0, a, b, 0f64, 1f64)
+    }
+}
+
+impl ConvexCombination<FractionExact> for FractionMatrixExact {
+    fn convex_combination(alpha: &FractionExact, a: &Self, b: &Self) -> Result<Self> {
+        convex_combination!(&alpha.0, a, b, Rational::from(0), Rational::from(1))
+    }
+}
+
+impl ConvexCombination<FractionEnum> for FractionMatrixEnum {
+    fn convex_combination(alpha: &FractionEnum, a: &Self, b: &Self) -> Result<Self> {
+        match (alpha, a, b) {
+            (
+                FractionEnum::Approx(alpha),
+                FractionMatrixEnum::Approx(a),
+                FractionMatrixEnum::Approx(b),
+            ) => Ok(FractionMatrixEnum::Approx(
+                FractionMatrixF64::convex_combination(&FractionF64(*alpha), a, b)?,
+            )),
+            (
+                FractionEnum::Exact(alpha),
+                FractionMatrixEnum::Exact(a),
+                FractionMatrixEnum::Exact(b),
+            ) => Ok(FractionMatrixEnum::Exact(
+                FractionMatrixExact::convex_combination(&FractionExact(alpha.clone()), a, b)?,
+            )),
+            _ => Err(anyhow!("cannot combine exact and approximate arithmetic")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ConvexCombination,
+        fraction::{fraction_exact::FractionExact, fraction_f64::FractionF64},
+        matrix::{
+            fraction_matrix_exact::FractionMatrixExact, fraction_matrix_f64::FractionMatrixF64,
+        },
+    };
+
+    #[test]
+    fn convex_combination() {
+        let a: FractionMatrixExact = vec![vec![1.into(), 0.into()], vec![0.into(), 1.into()]]
+            .try_into()
+            .unwrap();
+        let b: FractionMatrixExact = vec![vec![0.into(), 1.into()], vec![1.into(), 0.into()]]
+            .try_into()
+            .unwrap();
+        let m =
+            FractionMatrixExact::convex_combination(&FractionExact::from((1, 4)), &a, &b).unwrap();
+        let correct: FractionMatrixExact = vec![
+            vec![(1, 4).into(), (3, 4).into()],
+            vec![(3, 4).into(), (1, 4).into()],
+        ]
+        .try_into()
+        .unwrap();
+        assert_eq!(m, correct);
+        assert_eq!(
+            FractionMatrixExact::convex_combination(&FractionExact::from(1), &a, &b).unwrap(),
+            a
+        );
+
+        assert!(FractionMatrixExact::convex_combination(&FractionExact::from(2), &a, &b).is_err());
+        assert!(FractionMatrixExact::convex_combination(&FractionExact::from(-1), &a, &b).is_err());
+        let c: FractionMatrixExact = vec![vec![1.into(), 0.into()]].try_into().unwrap();
+        assert!(FractionMatrixExact::convex_combination(&FractionExact::from(0), &a, &c).is_err());
+
+        let a: FractionMatrixF64 = vec![vec![2.into(), 4.into()]].try_into().unwrap();
+        let b: FractionMatrixF64 = vec![vec![0.into(), 8.into()]].try_into().unwrap();
+        let m = FractionMatrixF64::convex_combination(&FractionF64::from(0.5), &a, &b).unwrap();
+        assert_eq!(m, vec![vec![1.into(), 6.into()]].try_into().unwrap());
+    }
+}
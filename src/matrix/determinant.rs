@@ -0,0 +1,148 @@
+use anyhow::{Result, anyhow};
+use malachite::rational::Rational;
+
+use crate::{
+    Determinant, One, Zero,
+    fraction::{
+        fraction_enum::FractionEnum, fraction_exact::FractionExact, fraction_f64::FractionF64,
+    },
+    matrix::{
+        fraction_matrix_enum::FractionMatrixEnum, fraction_matrix_exact::FractionMatrixExact,
+        fraction_matrix_f64::FractionMatrixF64,
+    },
+    size_guard::check_exact_size,
+};
+
+/// `$better` returns whether a candidate pivot value is preferable over the current one; `$check` is applied to the input, the intermediate values and the result after each column.
+macro_rules! determinant {
+    ($t:ident, $v:ident, $f:ident, $better:expr, $check:expr) => {
+        impl Determinant<$f> for $t {
+            fn determinant(&self) -> Result<$f> {
+                if self.number_of_rows != self.number_of_columns {
+                    return Err(anyhow!("can only take the determinant of a square matrix"));
+                }
+                let better = $better;
+                let check = $check;
+                let n = self.number_of_rows;
+                let mut values = self.values.clone();
+
+                let mut result = $v::one();
+                for column in 0..n {
+                    //choose a pivot
+                    let mut chosen = None;
+                    for row in column..n {
+                        let candidate = &values[row * n + column];
+                        if self.is_zero_value(candidate) {
+                            continue;
+                        }
+                        match chosen {
+                            Some(best) if !better(candidate, &values[best * n + column]) => {}
+                            _ => chosen = Some(row),
+                        }
+                    }
+                    let Some(chosen) = chosen else {
+                        return Ok($f($v::zero()));
+                    };
+
+                    //a row swap flips the sign
+                    if chosen != column {
+                        for k in column..n {
+                            values.swap(chosen * n + k, column * n + k);
+                        }
+                        result = -result;
+                    }
+
+                    //eliminate below the pivot
+                    let pivot = values[column * n + column].clone();
+                    for row in column + 1..n {
+                        if self.is_zero_value(&values[row * n + column]) {
+                            continue;
+                        }
+                        let factor = &values[row * n + column] / &pivot;
+                        for k in column + 1..n {
+                            let product = &factor * &values[column * n + k];
+                            values[row * n + k] -= product;
+                        }
+                    }
+
+                    result *= pivot;
+                    check(&[&self.values, &values, std::slice::from_ref(&result)])?;
+                }
+
+                Ok($f(result))
+            }
+        }
+    };
+}
+
+determinant!(
+    FractionMatrixF64,
+    f64,
+    FractionF64,
+    |candidate: &f64, best: &f64| candidate.abs() > best.abs(),
+    |_: &[&[f64]]| -> Result<()> { Ok(()) }
+);
+determinant!(
+    FractionMatrixExact,
+    Rational,
+    FractionExact,
+    |_: &Rational, _: &Rational| false,
+    check_exact_size
+);
+
+impl Determinant<FractionEnum> for FractionMatrixEnum {
+    fn determinant(&self) -> Result<FractionEnum> {
+        match self {
+            FractionMatrixEnum::Approx(m) => Ok(FractionEnum::Approx(m.determinant()?.0)),
+            FractionMatrixEnum::Exact(m) => Ok(FractionEnum::Exact(m.determinant()?.0)),
+            FractionMatrixEnum::CannotCombineExactAndApprox => {
+                Err(anyhow!("cannot combine exact and approximate arithmetic"))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Determinant, EbiMatrix,
+        fraction::{fraction_exact::FractionExact, fraction_f64::FractionF64},
+        matrix::{
+            fraction_matrix_exact::FractionMatrixExact, fraction_matrix_f64::FractionMatrixF64,
+        },
+    };
+
+    #[test]
+    fn determinant() {
+        //the first column needs a row swap
+        let m: FractionMatrixExact = vec![
+            vec![0.into(), 2.into(), 1.into()],
+            vec![(1, 2).into(), 1.into(), 0.into()],
+            vec![1.into(), 0.into(), 3.into()],
+        ]
+        .try_into()
+        .unwrap();
+        assert_eq!(m.determinant().unwrap(), FractionExact::from(-4));
+
+        let m: FractionMatrixF64 = vec![
+            vec![0.into(), 2.into(), 1.into()],
+            vec![0.5.into(), 1.into(), 0.into()],
+            vec![1.into(), 0.into(), 3.into()],
+        ]
+        .try_into()
+        .unwrap();
+        assert_eq!(m.determinant().unwrap(), FractionF64::from(-4));
+
+        let singular: FractionMatrixExact =
+            vec![vec![1.into(), 2.into()], vec![(1, 2).into(), 1.into()]]
+                .try_into()
+                .unwrap();
+        assert_eq!(singular.determinant().unwrap(), FractionExact::from(0));
+
+        assert_eq!(
+            FractionMatrixExact::new(0, 0).determinant().unwrap(),
+            FractionExact::from(1)
+        );
+        assert!(FractionMatrixExact::new(2, 3).determinant().is_err());
+    }
+}
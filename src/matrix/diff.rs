@@ -0,0 +1,227 @@
+use std::fmt::Display;
+
+use crate::{
+    Diff, EbiMatrix,
+    fraction::{
+        approximate::Approximate,
+        fraction::{EPSILON, is_approximately_zero},
+        fraction_enum::FractionEnum,
+    },
+    matrix::{
+        fraction_matrix_enum::FractionMatrixEnum, fraction_matrix_exact::FractionMatrixExact,
+        fraction_matrix_f64::FractionMatrixF64,
+    },
+};
+
+/// A cell in which two matrices differ.
+#[derive(Clone, Debug)]
+pub struct CellDiff {
+    pub row: usize,
+    pub column: usize,
+    pub left: FractionEnum,
+    pub right: FractionEnum,
+    pub absolute_error: f64,
+    /// The absolute error relative to the largest of the two values.
+    pub relative_error: f64,
+}
+
+/// The differences between two matrices.
+/// If the sizes differ, only the cells present in both matrices are compared.
+#[derive(Clone, Debug)]
+pub struct MatrixDiff {
+    pub left_size: (usize, usize),
+    pub right_size: (usize, usize),
+    pub cells: Vec<CellDiff>,
+}
+
+impl MatrixDiff {
+    /// Returns whether the matrices are equal.
+    pub fn is_empty(&self) -> bool {
+        self.left_size == self.right_size && self.cells.is_empty()
+    }
+}
+
+impl Display for MatrixDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_empty() {
+            return write!(f, "matrices are equal");
+        }
+        if self.left_size != self.right_size {
+            writeln!(
+                f,
+                "sizes differ: {}x{} vs {}x{}",
+                self.left_size.0, self.left_size.1, self.right_size.0, self.right_size.1
+            )?;
+        }
+        write!(f, "{} cells differ", self.cells.len())?;
+        for cell in &self.cells {
+            write!(
+                f,
+                "\n  ({}, {}): {} vs {} (absolute error {:e}, relative error {:e})",
+                cell.row,
+                cell.column,
+                cell.left,
+                cell.right,
+                cell.absolute_error,
+                cell.relative_error
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Access to the cells of a matrix, independent of its backend.
+trait DiffCells {
+    fn size(&self) -> (usize, usize);
+
+    fn cell(&self, row: usize, column: usize) -> FractionEnum;
+
+    /// The tolerance with which approximate values are compared.
+    fn epsilon(&self) -> f64;
+}
+
+impl DiffCells for FractionMatrixF64 {
+    fn size(&self) -> (usize, usize) {
+        (self.number_of_rows, self.number_of_columns)
+    }
+
+    fn cell(&self, row: usize, column: usize) -> FractionEnum {
+        FractionEnum::Approx(self.values[self.index(row, column)])
+    }
+
+    fn epsilon(&self) -> f64 {
+        self.epsilon
+    }
+}
+
+impl DiffCells for FractionMatrixExact {
+    fn size(&self) -> (usize, usize) {
+        (self.number_of_rows, self.number_of_columns)
+    }
+
+    fn cell(&self, row: usize, column: usize) -> FractionEnum {
+        FractionEnum::Exact(self.values[self.index(row, column)].clone())
+    }
+
+    fn epsilon(&self) -> f64 {
+        EPSILON
+    }
+}
+
+impl DiffCells for FractionMatrixEnum {
+    fn size(&self) -> (usize, usize) {
+        (self.number_of_rows(), self.number_of_columns())
+    }
+
+    fn cell(&self, row: usize, column: usize) -> FractionEnum {
+        match self {
+            FractionMatrixEnum::Approx(m) => m.cell(row, column),
+            FractionMatrixEnum::Exact(m) => m.cell(row, column),
+            FractionMatrixEnum::CannotCombineExactAndApprox => {
+                FractionEnum::CannotCombineExactAndApprox
+            }
+        }
+    }
+
+    fn epsilon(&self) -> f64 {
+        self.epsilon().unwrap_or(EPSILON)
+    }
+}
+
+fn diff<A: DiffCells, B: DiffCells>(left: &A, right: &B) -> MatrixDiff {
+    let left_size = left.size();
+    let right_size = right.size();
+    let epsilon = left.epsilon().max(right.epsilon());
+
+    let mut cells = vec![];
+    for row in 0..left_size.0.min(right_size.0) {
+        for column in 0..left_size.1.min(right_size.1) {
+            let l = left.cell(row, column);
+            let r = right.cell(row, column);
+            let (l_approx, r_approx) = (
+                l.clone().approximate().unwrap_or(f64::NAN),
+                r.clone().approximate().unwrap_or(f64::NAN),
+            );
+            let absolute_error = (l_approx - r_approx).abs();
+
+            let differs = match (&l, &r) {
+                (FractionEnum::Exact(a), FractionEnum::Exact(b)) => a != b,
+                _ => !is_approximately_zero(l_approx - r_approx, epsilon),
+            };
+
+            if differs {
+                let largest = l_approx.abs().max(r_approx.abs());
+                cells.push(CellDiff {
+                    row,
+                    column,
+                    left: l,
+                    right: r,
+                    absolute_error,
+                    relative_error: if largest == 0.0 {
+                        0.0
+                    } else {
+                        absolute_error / largest
+                    },
+                });
+            }
+        }
+    }
+
+    MatrixDiff {
+        left_size,
+        right_size,
+        cells,
+    }
+}
+
+macro_rules! diff {
+    ($t:ident, $u:ident) => {
+        impl Diff<$u> for $t {
+            fn diff(&self, other: &$u) -> MatrixDiff {
+                diff(self, other)
+            }
+        }
+    };
+}
+
+diff!(FractionMatrixF64, FractionMatrixF64);
+diff!(FractionMatrixF64, FractionMatrixExact);
+diff!(FractionMatrixExact, FractionMatrixExact);
+diff!(FractionMatrixExact, FractionMatrixF64);
+diff!(FractionMatrixEnum, FractionMatrixEnum);
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Diff,
+        fraction::{fraction_exact::FractionExact, fraction_f64::FractionF64},
+        matrix::{
+            fraction_matrix_exact::FractionMatrixExact, fraction_matrix_f64::FractionMatrixF64,
+        },
+    };
+
+    #[test]
+    fn diff_backends() {
+        let exact: FractionMatrixExact = vec![
+            vec![FractionExact::from((1, 3)), 1.into()],
+            vec![0.into(), 2.into()],
+        ]
+        .try_into()
+        .unwrap();
+        let approx: FractionMatrixF64 = vec![
+            vec![FractionF64::from((1, 3)), 1.into()],
+            vec![0.into(), FractionF64::from(2.5)],
+        ]
+        .try_into()
+        .unwrap();
+
+        let diff = exact.diff(&approx);
+        assert_eq!(diff.cells.len(), 1);
+        assert_eq!((diff.cells[0].row, diff.cells[0].column), (1, 1));
+        assert_eq!(diff.cells[0].absolute_error, 0.5);
+        assert_eq!(diff.cells[0].relative_error, 0.2);
+        assert!(diff.to_string().contains("(1, 1): 2 vs 2.5"));
+
+        assert!(exact.diff(&exact).is_empty());
+    }
+}
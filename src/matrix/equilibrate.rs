@@ -0,0 +1,188 @@
+use anyhow::{Result, anyhow};
+
+use crate::{fraction::fraction_f64::FractionF64, matrix::fraction_matrix_f64::FractionMatrixF64};
+
+/// The row and column scales applied by [FractionMatrixF64::equilibrate].
+/// The scaled matrix is R A C, with R and C diagonal matrices of the row and column scales.
+/// All scales are powers of two, thus scaling introduces no rounding errors.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Equilibration {
+    pub row_scales: Vec<f64>,
+    pub column_scales: Vec<f64>,
+}
+
+impl Equilibration {
+    /// Scales the right-hand side b of A x = b into R b, the right-hand side of the scaled system.
+    pub fn scale_rhs(&self, rhs: &mut [FractionF64]) -> Result<()> {
+        if rhs.len() != self.row_scales.len() {
+            return Err(anyhow!(
+                "cannot scale a vector of size {} with {} row scales",
+                rhs.len(),
+                self.row_scales.len()
+            ));
+        }
+        for (value, scale) in rhs.iter_mut().zip(self.row_scales.iter()) {
+            value.0 *= scale;
+        }
+        Ok(())
+    }
+
+    /// Transforms a solution y of the scaled system back into the solution x = C y of the original system.
+    pub fn unscale_solution(&self, solution: &mut [FractionF64]) -> Result<()> {
+        if solution.len() != self.column_scales.len() {
+            return Err(anyhow!(
+                "cannot unscale a vector of size {} with {} column scales",
+                solution.len(),
+                self.column_scales.len()
+            ));
+        }
+        for (value, scale) in solution.iter_mut().zip(self.column_scales.iter()) {
+            value.0 *= scale;
+        }
+        Ok(())
+    }
+
+    /// Transforms the inverse of the scaled matrix back into the inverse C (R A C)⁻¹ R of the original matrix.
+    pub fn unscale_inverse(&self, inverse: &mut FractionMatrixF64) -> Result<()> {
+        if inverse.number_of_rows != self.column_scales.len()
+            || inverse.number_of_columns != self.row_scales.len()
+        {
+            return Err(anyhow!(
+                "cannot unscale a matrix of size {}x{} with {} row scales and {} column scales",
+                inverse.number_of_rows,
+                inverse.number_of_columns,
+                self.row_scales.len(),
+                self.column_scales.len()
+            ));
+        }
+        for row in 0..inverse.number_of_rows {
+            for column in 0..inverse.number_of_columns {
+                let idx = inverse.index(row, column);
+                inverse.values[idx] =
+                    inverse.values[idx] * self.column_scales[row] * self.row_scales[column];
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns the power of two closest to 1 / `max`, or 1 if `max` is zero or not finite.
+/// The exponent is limited to the range of normal numbers, such that the scale itself is finite and normal, also for subnormal `max` and `max` close to [f64::MAX].
+/// As the product of two such scales may overflow, scales are applied to a value one after the other.
+fn power_of_two_reciprocal(max: f64) -> f64 {
+    if max == 0.0 || !max.is_finite() {
+        1.0
+    } else {
+        let exponent = -(max.log2().round() as i32);
+        2f64.powi(exponent.clamp(f64::MIN_EXP - 1, f64::MAX_EXP - 1))
+    }
+}
+
+impl FractionMatrixF64 {
+    /// Scales the rows and then the columns of the matrix, such that the largest absolute value in each row and column is close to one.
+    /// This improves the conditioning of badly scaled systems before elimination.
+    /// Returns the applied scales, with which the solution of the scaled system can be transformed back.
+    pub fn equilibrate(&mut self) -> Equilibration {
+        let row_scales = (0..self.number_of_rows)
+            .map(|row| {
+                let max = (0..self.number_of_columns)
+                    .map(|column| self.values[self.index(row, column)].abs())
+                    .fold(0.0, f64::max);
+                power_of_two_reciprocal(max)
+            })
+            .collect::<Vec<_>>();
+
+        let column_scales = (0..self.number_of_columns)
+            .map(|column| {
+                let max = (0..self.number_of_rows)
+                    .map(|row| (self.values[self.index(row, column)] * row_scales[row]).abs())
+                    .fold(0.0, f64::max);
+                power_of_two_reciprocal(max)
+            })
+            .collect::<Vec<_>>();
+
+        if self.number_of_columns > 0 {
+            for (row, row_scale) in self
+                .values
+                .chunks_mut(self.number_of_columns)
+                .zip(row_scales.iter())
+            {
+                for (value, column_scale) in row.iter_mut().zip(column_scales.iter()) {
+                    *value = *value * row_scale * column_scale;
+                }
+            }
+        }
+
+        Equilibration {
+            row_scales,
+            column_scales,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Inversion, fraction::fraction_f64::FractionF64,
+        matrix::fraction_matrix_f64::FractionMatrixF64,
+    };
+
+    #[test]
+    fn equilibrate() {
+        let m: FractionMatrixF64 = vec![
+            vec![FractionF64::from(1e6), FractionF64::from(2e6)],
+            vec![FractionF64::from(3e-6), FractionF64::from(1e-6)],
+        ]
+        .try_into()
+        .unwrap();
+
+        let mut scaled = m.clone();
+        let scales = scaled.equilibrate();
+        assert!(
+            scales
+                .row_scales
+                .iter()
+                .chain(scales.column_scales.iter())
+                .all(|s| s.log2().fract() == 0.0)
+        );
+        assert!(
+            scaled
+                .values
+                .iter()
+                .all(|v| v.abs() <= 2.0 && v.abs() >= 0.25)
+        );
+
+        //solve via the scaled system
+        let mut rhs = vec![FractionF64::from(3e6), FractionF64::from(4e-6)];
+        scales.scale_rhs(&mut rhs).unwrap();
+        let mut x = (&scaled.clone().invert().unwrap() * &rhs).unwrap();
+        scales.unscale_solution(&mut x).unwrap();
+        assert_eq!(x, vec![FractionF64::from(1), FractionF64::from(1)]);
+
+        //undo the scaling on the inverse
+        let mut inverse = scaled.invert().unwrap();
+        scales.unscale_inverse(&mut inverse).unwrap();
+        assert_eq!(inverse, m.invert().unwrap());
+    }
+
+    #[test]
+    fn equilibrate_extreme_rows() {
+        let mut m: FractionMatrixF64 = vec![
+            vec![FractionF64::from(5e-324), FractionF64::from(0.0)],
+            vec![FractionF64::from(0.0), FractionF64::from(f64::MAX)],
+        ]
+        .try_into()
+        .unwrap();
+
+        let scales = m.equilibrate();
+        assert!(
+            scales
+                .row_scales
+                .iter()
+                .chain(scales.column_scales.iter())
+                .all(|s| s.is_normal())
+        );
+        assert!(m.values.iter().all(|v| v.is_finite()));
+        assert!(m.values[0] > 0.0 && (m.values[3] - 1.0).abs() <= 1.0);
+    }
+}
@@ -1,5 +1,9 @@
 use crate::{
     exact::MaybeExact,
+    fraction::{
+        exact::{float_to_rational, rational_to_f64},
+        fraction::EPSILON,
+    },
     is_exact_globally,
     matrix::{
         fraction_matrix_enum::FractionMatrixEnum, fraction_matrix_exact::FractionMatrixExact,
@@ -7,6 +11,30 @@ use crate::{
     },
 };
 use anyhow::{Result, anyhow};
+use std::borrow::Cow;
+
+/// Converts every value of the matrix to its exact value; fails for infinite and NaN values.
+pub(crate) fn to_exact(matrix: &FractionMatrixF64) -> Result<FractionMatrixExact> {
+    Ok(FractionMatrixExact {
+        values: matrix
+            .values
+            .iter()
+            .map(|v| float_to_rational(*v))
+            .collect::<Result<Vec<_>>>()?,
+        number_of_rows: matrix.number_of_rows,
+        number_of_columns: matrix.number_of_columns,
+    })
+}
+
+/// Rounds every value of the matrix to the nearest float; the result has the default tolerance.
+pub(crate) fn to_approx(matrix: &FractionMatrixExact) -> FractionMatrixF64 {
+    FractionMatrixF64 {
+        values: matrix.values.iter().map(rational_to_f64).collect(),
+        number_of_rows: matrix.number_of_rows,
+        number_of_columns: matrix.number_of_columns,
+        epsilon: EPSILON,
+    }
+}
 
 impl MaybeExact for FractionMatrixF64 {
     type Approximate = FractionMatrixF64;
@@ -39,6 +67,10 @@ impl MaybeExact for FractionMatrixF64 {
     fn try_to_approx(approx: Self::Approximate) -> Result<Self> {
         Ok(approx)
     }
+
+    fn approx_to_exact(&self) -> Result<FractionMatrixExact> {
+        to_exact(self)
+    }
 }
 
 impl MaybeExact for FractionMatrixExact {
@@ -73,6 +105,14 @@ impl MaybeExact for FractionMatrixExact {
     fn try_to_approx(_: Self::Approximate) -> Result<Self> {
         Err(anyhow!("cannot put float in a fraction"))
     }
+
+    fn into_approx(self) -> Result<Self::Approximate> {
+        Ok(to_approx(&self))
+    }
+
+    fn approx_cow(&self) -> Result<Cow<'_, Self::Approximate>> {
+        Ok(Cow::Owned(to_approx(self)))
+    }
 }
 
 impl MaybeExact for FractionMatrixEnum {
@@ -139,4 +179,90 @@ impl MaybeExact for FractionMatrixEnum {
             Err(anyhow!("cannot put fraction in a float"))
         }
     }
+
+    fn into_approx(self) -> Result<FractionMatrixF64> {
+        match self {
+            FractionMatrixEnum::Approx(m) => Ok(m),
+            FractionMatrixEnum::Exact(m) => Ok(to_approx(&m)),
+            FractionMatrixEnum::CannotCombineExactAndApprox => {
+                Err(anyhow!("cannot combine exact and approximate arithmetic"))
+            }
+        }
+    }
+
+    fn approx_to_exact(&self) -> Result<FractionMatrixExact> {
+        match self {
+            FractionMatrixEnum::Approx(m) => to_exact(m),
+            FractionMatrixEnum::Exact(m) => Ok(m.clone()),
+            FractionMatrixEnum::CannotCombineExactAndApprox => {
+                Err(anyhow!("cannot combine exact and approximate arithmetic"))
+            }
+        }
+    }
+
+    fn approx_cow(&self) -> Result<Cow<'_, FractionMatrixF64>> {
+        match self {
+            FractionMatrixEnum::Approx(m) => Ok(Cow::Borrowed(m)),
+            FractionMatrixEnum::Exact(m) => Ok(Cow::Owned(to_approx(m))),
+            FractionMatrixEnum::CannotCombineExactAndApprox => {
+                Err(anyhow!("cannot combine exact and approximate arithmetic"))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use malachite::rational::Rational;
+    use std::borrow::Cow;
+
+    use crate::{
+        MaybeExact,
+        fraction::fraction_enum::FractionEnum,
+        matrix::{
+            fraction_matrix_enum::FractionMatrixEnum, fraction_matrix_exact::FractionMatrixExact,
+            fraction_matrix_f64::FractionMatrixF64,
+        },
+    };
+
+    #[test]
+    fn maybe_exact_cow() {
+        let exact: FractionMatrixExact =
+            vec![vec![(1, 2).into(), (1, 4).into()]].try_into().unwrap();
+        let approx: FractionMatrixF64 = vec![vec![0.5.into(), 0.25.into()]].try_into().unwrap();
+
+        //no copy if the representation matches
+        assert!(matches!(exact.exact_cow().unwrap(), Cow::Borrowed(_)));
+        assert!(matches!(approx.approx_cow().unwrap(), Cow::Borrowed(_)));
+
+        //conversions otherwise
+        assert_eq!(exact.approx_cow().unwrap().into_owned(), approx);
+        assert_eq!(approx.approx_to_exact().unwrap(), exact);
+        assert!(approx.exact_cow().is_err());
+        assert!(approx.clone().into_exact().is_err());
+        assert_eq!(exact.clone().into_approx().unwrap(), approx);
+
+        let e = FractionMatrixEnum::Exact(exact.clone());
+        assert!(matches!(e.exact_cow().unwrap(), Cow::Borrowed(_)));
+        assert_eq!(e.into_approx().unwrap(), approx);
+        assert!(
+            FractionMatrixEnum::CannotCombineExactAndApprox
+                .into_exact()
+                .is_err()
+        );
+
+        //scalars
+        let f = FractionEnum::Approx(0.75);
+        assert_eq!(f.approx_to_exact().unwrap(), Rational::from_signeds(3, 4));
+        assert!(f.exact_cow().is_err());
+        assert!(f.into_exact().is_err());
+        assert!(FractionEnum::Approx(f64::NAN).approx_to_exact().is_err());
+        assert_eq!(
+            FractionMatrixEnum::Approx(approx.clone())
+                .approx_to_exact()
+                .unwrap(),
+            exact
+        );
+        assert!(FractionMatrixEnum::Approx(approx).exact_cow().is_err());
+    }
 }
@@ -0,0 +1,190 @@
+use anyhow::{Result, anyhow};
+use malachite::rational::Rational;
+
+use crate::{
+    ebi_matrix::EbiMatrix,
+    fraction::{
+        fraction_enum::FractionEnum, fraction_exact::FractionExact, fraction_f64::FractionF64,
+    },
+    matrix::{
+        fraction_matrix_enum::FractionMatrixEnum, fraction_matrix_exact::FractionMatrixExact,
+        fraction_matrix_f64::FractionMatrixF64, sparse_fraction_matrix::SparseFractionMatrix,
+    },
+};
+
+/// The textual forms of a single value in the export formats.
+trait ExportValue {
+    fn to_latex_value(&self) -> Result<String>;
+
+    fn to_csv_value(&self) -> Result<String>;
+}
+
+fn rational_to_latex(value: &Rational) -> String {
+    let sign = if *value < 0u32 { "-" } else { "" };
+    if *value.denominator_ref() == 1u32 {
+        format!("{}{}", sign, value.numerator_ref())
+    } else {
+        format!(
+            "{}\\frac{{{}}}{{{}}}",
+            sign,
+            value.numerator_ref(),
+            value.denominator_ref()
+        )
+    }
+}
+
+impl ExportValue for FractionF64 {
+    fn to_latex_value(&self) -> Result<String> {
+        Ok(self.0.to_string())
+    }
+
+    fn to_csv_value(&self) -> Result<String> {
+        Ok(self.0.to_string())
+    }
+}
+
+impl ExportValue for FractionExact {
+    fn to_latex_value(&self) -> Result<String> {
+        Ok(rational_to_latex(&self.0))
+    }
+
+    fn to_csv_value(&self) -> Result<String> {
+        Ok(self.0.to_string())
+    }
+}
+
+impl ExportValue for FractionEnum {
+    fn to_latex_value(&self) -> Result<String> {
+        match self {
+            FractionEnum::Exact(f) => Ok(rational_to_latex(f)),
+            FractionEnum::Approx(f) => Ok(f.to_string()),
+            FractionEnum::CannotCombineExactAndApprox => {
+                Err(anyhow!("cannot combine exact and approximate arithmetic"))
+            }
+        }
+    }
+
+    fn to_csv_value(&self) -> Result<String> {
+        match self {
+            FractionEnum::Exact(f) => Ok(f.to_string()),
+            FractionEnum::Approx(f) => Ok(f.to_string()),
+            FractionEnum::CannotCombineExactAndApprox => {
+                Err(anyhow!("cannot combine exact and approximate arithmetic"))
+            }
+        }
+    }
+}
+
+/// Returns the rows of the matrix, with the values of each row separated by `separator`.
+fn export_rows<T: ExportValue + Clone, M: EbiMatrix<T>>(
+    matrix: &M,
+    value: impl Fn(&T) -> Result<String>,
+    separator: &str,
+) -> Result<Vec<String>> {
+    (0..matrix.number_of_rows())
+        .map(|row| {
+            Ok((0..matrix.number_of_columns())
+                .map(|column| value(&matrix.get(row, column).unwrap()))
+                .collect::<Result<Vec<_>>>()?
+                .join(separator))
+        })
+        .collect()
+}
+
+fn to_latex<T: ExportValue + Clone, M: EbiMatrix<T>>(matrix: &M) -> Result<String> {
+    let rows = export_rows(matrix, T::to_latex_value, " & ")?;
+    Ok(format!(
+        "\\begin{{pmatrix}}\n{}\n\\end{{pmatrix}}",
+        rows.join(" \\\\\n")
+    ))
+}
+
+fn to_csv<T: ExportValue + Clone, M: EbiMatrix<T>>(matrix: &M) -> Result<String> {
+    let rows = export_rows(matrix, T::to_csv_value, ",")?;
+    Ok(rows.into_iter().map(|row| row + "\n").collect())
+}
+
+macro_rules! export {
+    ($m:ty, $t:ty) => {
+        impl $m {
+            /// Returns the matrix as a LaTeX `pmatrix` environment.
+            /// Exact values are written as fractions, approximate values as decimals.
+            /// Returns an error if the matrix combines exact and approximate arithmetic.
+            pub fn to_latex(&self) -> Result<String> {
+                to_latex::<$t, _>(self)
+            }
+
+            /// Returns the matrix as comma-separated values, one line per row.
+            /// Exact values are written as fractions, such as `1/3`, and approximate values as decimals.
+            /// Returns an error if the matrix combines exact and approximate arithmetic.
+            pub fn to_csv(&self) -> Result<String> {
+                to_csv::<$t, _>(self)
+            }
+        }
+    };
+}
+
+export!(FractionMatrixF64, FractionF64);
+export!(FractionMatrixExact, FractionExact);
+export!(SparseFractionMatrix<FractionF64>, FractionF64);
+export!(SparseFractionMatrix<FractionExact>, FractionExact);
+export!(SparseFractionMatrix<FractionEnum>, FractionEnum);
+
+impl FractionMatrixEnum {
+    /// Returns the matrix as a LaTeX `pmatrix` environment.
+    /// Exact values are written as fractions, approximate values as decimals.
+    /// Returns an error if the matrix combines exact and approximate arithmetic.
+    pub fn to_latex(&self) -> Result<String> {
+        match self {
+            FractionMatrixEnum::CannotCombineExactAndApprox => {
+                Err(anyhow!("cannot combine exact and approximate arithmetic"))
+            }
+            _ => to_latex::<FractionEnum, _>(self),
+        }
+    }
+
+    /// Returns the matrix as comma-separated values, one line per row.
+    /// Exact values are written as fractions, such as `1/3`, and approximate values as decimals.
+    /// Returns an error if the matrix combines exact and approximate arithmetic.
+    pub fn to_csv(&self) -> Result<String> {
+        match self {
+            FractionMatrixEnum::CannotCombineExactAndApprox => {
+                Err(anyhow!("cannot combine exact and approximate arithmetic"))
+            }
+            _ => to_csv::<FractionEnum, _>(self),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::{
+        fraction_matrix_enum::FractionMatrixEnum, fraction_matrix_exact::FractionMatrixExact,
+        fraction_matrix_f64::FractionMatrixF64,
+    };
+
+    #[test]
+    fn export() {
+        let m: FractionMatrixExact = vec![
+            vec![(1, 2).into(), (-1, 3).into()],
+            vec![0.into(), 12.into()],
+        ]
+        .try_into()
+        .unwrap();
+        assert_eq!(
+            m.to_latex().unwrap(),
+            "\\begin{pmatrix}\n\\frac{1}{2} & -\\frac{1}{3} \\\\\n0 & 12\n\\end{pmatrix}"
+        );
+        assert_eq!(m.to_csv().unwrap(), "1/2,-1/3\n0,12\n");
+        assert_eq!(format!("{}", m), "{{1/2, -1/3},\n {  0,   12}}");
+
+        let m: FractionMatrixF64 = vec![vec![0.5.into(), (-2.0).into()]].try_into().unwrap();
+        assert_eq!(m.to_csv().unwrap(), "0.5,-2\n");
+
+        assert!(
+            FractionMatrixEnum::CannotCombineExactAndApprox
+                .to_csv()
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,200 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut, Mul},
+};
+
+use crate::{
+    One, Zero,
+    fraction::{
+        fraction_enum::FractionEnum, fraction_exact::FractionExact, fraction_f64::FractionF64,
+    },
+    matrix::fraction_matrix::fmt_aligned,
+    vector::sum_of_products,
+};
+
+/// A matrix with R rows and C columns, of which the size is known at compile time.
+///
+/// The values are stored inline in an array rather than in a `Vec`, such that small matrices (2x2, 3x3, 4x4) do not allocate, and multiplying matrices of incompatible sizes does not compile.
+/// Exact fractions with small numerators and denominators do not allocate either.
+/// Use [FixedFractionMatrix::to_vec] and `try_into` to convert to a matrix of which the size is only known at runtime.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FixedFractionMatrix<const R: usize, const C: usize, F> {
+    values: [[F; C]; R],
+}
+
+impl<const R: usize, const C: usize, F> FixedFractionMatrix<R, C, F> {
+    pub fn from_rows(values: [[F; C]; R]) -> Self {
+        Self { values }
+    }
+
+    pub fn into_rows(self) -> [[F; C]; R] {
+        self.values
+    }
+
+    pub fn rows(&self) -> &[[F; C]; R] {
+        &self.values
+    }
+
+    pub const fn number_of_rows(&self) -> usize {
+        R
+    }
+
+    pub const fn number_of_columns(&self) -> usize {
+        C
+    }
+
+    /// Returns the rows as vectors, for instance to convert the matrix with `try_into`.
+    pub fn to_vec(self) -> Vec<Vec<F>> {
+        self.values.into_iter().map(|row| row.into()).collect()
+    }
+}
+
+impl<const R: usize, const C: usize, F: Clone> FixedFractionMatrix<R, C, F> {
+    pub fn transpose(&self) -> FixedFractionMatrix<C, R, F> {
+        FixedFractionMatrix {
+            values: std::array::from_fn(|column| {
+                std::array::from_fn(|row| self.values[row][column].clone())
+            }),
+        }
+    }
+}
+
+impl<const R: usize, const C: usize, F: Zero> FixedFractionMatrix<R, C, F> {
+    pub fn new() -> Self {
+        Self {
+            values: std::array::from_fn(|_| std::array::from_fn(|_| F::zero())),
+        }
+    }
+}
+
+impl<const R: usize, const C: usize, F: Zero> Default for FixedFractionMatrix<R, C, F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize, F: Zero + One> FixedFractionMatrix<N, N, F> {
+    pub fn identity() -> Self {
+        Self {
+            values: std::array::from_fn(|row| {
+                std::array::from_fn(|column| if row == column { F::one() } else { F::zero() })
+            }),
+        }
+    }
+}
+
+impl<const R: usize, const C: usize, F> From<[[F; C]; R]> for FixedFractionMatrix<R, C, F> {
+    fn from(values: [[F; C]; R]) -> Self {
+        Self { values }
+    }
+}
+
+/// Borrows the value at (row, column). Panics if the row or column does not exist.
+impl<const R: usize, const C: usize, F> Index<(usize, usize)> for FixedFractionMatrix<R, C, F> {
+    type Output = F;
+
+    fn index(&self, (row, column): (usize, usize)) -> &Self::Output {
+        &self.values[row][column]
+    }
+}
+
+impl<const R: usize, const C: usize, F> IndexMut<(usize, usize)> for FixedFractionMatrix<R, C, F> {
+    fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut Self::Output {
+        &mut self.values[row][column]
+    }
+}
+
+macro_rules! fixed_mul {
+    ($u:ident) => {
+        /// The product of a matrix of R x C with a matrix of C x K.
+        /// Unlike the product of matrices of which the size is only known at runtime, it cannot fail.
+        impl<const R: usize, const C: usize, const K: usize> Mul<&FixedFractionMatrix<C, K, $u>>
+            for &FixedFractionMatrix<R, C, $u>
+        {
+            type Output = FixedFractionMatrix<R, K, $u>;
+
+            fn mul(self, rhs: &FixedFractionMatrix<C, K, $u>) -> Self::Output {
+                FixedFractionMatrix {
+                    values: std::array::from_fn(|row| {
+                        std::array::from_fn(|column| {
+                            sum_of_products(
+                                (0..C).map(|k| (&self.values[row][k], &rhs.values[k][column])),
+                            )
+                        })
+                    }),
+                }
+            }
+        }
+
+        /// The product of a matrix with a column vector.
+        impl<const R: usize, const C: usize> Mul<&[$u; C]> for &FixedFractionMatrix<R, C, $u> {
+            type Output = [$u; R];
+
+            fn mul(self, rhs: &[$u; C]) -> Self::Output {
+                std::array::from_fn(|row| sum_of_products(self.values[row].iter().zip(rhs.iter())))
+            }
+        }
+    };
+}
+
+fixed_mul!(FractionF64);
+fixed_mul!(FractionExact);
+fixed_mul!(FractionEnum);
+
+impl<const R: usize, const C: usize, F: Display> Display for FixedFractionMatrix<R, C, F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let values = self
+            .values
+            .iter()
+            .flatten()
+            .map(|value| value.to_string())
+            .collect::<Vec<_>>();
+        fmt_aligned(f, &values, R, C)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        fraction::{fraction_exact::FractionExact, fraction_f64::FractionF64},
+        matrix::{
+            fixed_fraction_matrix::FixedFractionMatrix, fraction_matrix_exact::FractionMatrixExact,
+        },
+    };
+
+    #[test]
+    fn fixed_fraction_matrix() {
+        let a: FixedFractionMatrix<2, 3, FractionExact> = [
+            [1.into(), (1, 2).into(), 0.into()],
+            [0.into(), 1.into(), (1, 3).into()],
+        ]
+        .into();
+        let b = a.transpose();
+        let product: FixedFractionMatrix<2, 2, FractionExact> = &a * &b;
+        assert_eq!(
+            product,
+            [
+                [(5, 4).into(), (1, 2).into()],
+                [(1, 2).into(), (10, 9).into()]
+            ]
+            .into()
+        );
+
+        //agrees with the product of matrices of runtime size
+        let a_dynamic: FractionMatrixExact = a.clone().to_vec().try_into().unwrap();
+        let b_dynamic: FractionMatrixExact = b.to_vec().try_into().unwrap();
+        let product_dynamic: FractionMatrixExact = product.to_vec().try_into().unwrap();
+        assert_eq!((&a_dynamic * &b_dynamic).unwrap(), product_dynamic);
+
+        let i = FixedFractionMatrix::<3, 3, FractionExact>::identity();
+        assert_eq!(&a * &i, a);
+        assert_eq!(
+            &a * &[6.into(), 2.into(), 3.into()],
+            [FractionExact::from(7), 3.into()]
+        );
+
+        let mut m = FixedFractionMatrix::<2, 2, FractionF64>::new();
+        m[(0, 1)] = 2.into();
+        assert_eq!(m.to_string(), "{{0, 2},\n {0, 0}}");
+    }
+}
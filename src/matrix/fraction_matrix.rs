@@ -3,6 +3,8 @@
 /// It applies two strategies to potentially save time for exact arithmetic matrices:
 /// - it postpones reduction of fractions to the moment of export, or a user-chosen moment.
 /// - it attempts to store values in primitives rather than BigUints at each reduction.
+///
+/// Indexing with `matrix[(row, column)]` is only available if a single arithmetic mode is selected, as the enum matrix has no single value type to borrow; use [EbiMatrix::get](crate::EbiMatrix::get) and [EbiMatrix::set](crate::EbiMatrix::set) instead.
 #[cfg(any(
     all(
         not(feature = "exactarithmetic"),
@@ -44,17 +46,77 @@ macro_rules! pop_front_columns {
     };
 }
 
+#[macro_export]
+macro_rules! remove_column {
+    ($column:expr, $values:expr, $number_of_rows:expr, $number_of_columns:expr) => {
+        for row in (0..$number_of_rows).rev() {
+            $values.remove(row * $number_of_columns + $column);
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! swap_rows {
+    ($row_a:expr, $row_b:expr, $values:expr, $number_of_columns:expr) => {
+        if $row_a != $row_b {
+            let (low, high) = ($row_a.min($row_b), $row_a.max($row_b));
+            let (top, bottom) = $values.split_at_mut(high * $number_of_columns);
+            top[low * $number_of_columns..(low + 1) * $number_of_columns]
+                .swap_with_slice(&mut bottom[..$number_of_columns]);
+        }
+    };
+}
+
+/// Writes the values of a matrix row by row, with the values of each column aligned to the right.
+pub(crate) fn fmt_aligned(
+    f: &mut std::fmt::Formatter<'_>,
+    values: &[String],
+    number_of_rows: usize,
+    number_of_columns: usize,
+) -> std::fmt::Result {
+    if number_of_rows == 0 {
+        return write!(f, "{{}}");
+    }
+    write!(f, "{{{{")?;
+    if number_of_columns > 0 {
+        let widths = (0..number_of_columns)
+            .map(|column| {
+                values
+                    .iter()
+                    .skip(column)
+                    .step_by(number_of_columns)
+                    .map(|value| value.chars().count())
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect::<Vec<_>>();
+        for (i, row) in values.chunks(number_of_columns).enumerate() {
+            for (j, value) in row.iter().enumerate() {
+                write!(f, "{:>width$}", value, width = widths[j])?;
+                if j < row.len() - 1 {
+                    write!(f, ", ")?;
+                }
+            }
+            if i < number_of_rows - 1 {
+                write!(f, "}},\n {{")?;
+            }
+        }
+    } else {
+        //rows without columns
+        for _ in 1..number_of_rows {
+            write!(f, "}},\n {{")?;
+        }
+    }
+    write!(f, "}}}}")
+}
+
 //======================== tests ========================//
 #[cfg(test)]
 mod tests {
 
     use crate::{
-        Inversion,
-        Zero,
-        ebi_matrix::EbiMatrix,
-        f, f0,
-        fraction::fraction::Fraction,
-        matrix::fraction_matrix::FractionMatrix,
+        GaussJordan, IdentityMinus, Inversion, Zero, ebi_matrix::EbiMatrix, f, f0,
+        fraction::fraction::Fraction, matrix::fraction_matrix::FractionMatrix,
     };
 
     #[test]
@@ -118,6 +180,47 @@ mod tests {
         assert_eq!(m1, m3);
     }
 
+    #[test]
+    fn fraction_matrix_rows_columns() {
+        let mut m: FractionMatrix = vec![
+            vec![f!(1, 2), f!(1, 4), f!(1, 4)],
+            vec![f0!(), f!(1), f0!()],
+            vec![f!(1, 3), f!(1, 3), f!(1, 3)],
+        ]
+        .try_into()
+        .unwrap();
+
+        assert_eq!(m.get_row(2).unwrap(), vec![f!(1, 3), f!(1, 3), f!(1, 3)]);
+        assert_eq!(m.get_column(1).unwrap(), vec![f!(1, 4), f!(1), f!(1, 3)]);
+        assert!(m.get_row(3).is_none());
+        assert!(m.get_column(3).is_none());
+        assert_eq!(m.get_diagonal(), vec![f!(1, 2), f!(1), f!(1, 3)]);
+        assert_eq!(m.trace().unwrap(), f!(11, 6));
+
+        //remove the absorbing state
+        m.remove_row(1);
+        m.remove_column(1);
+        let r: FractionMatrix = vec![vec![f!(1, 2), f!(1, 4)], vec![f!(1, 3), f!(1, 3)]]
+            .try_into()
+            .unwrap();
+        assert_eq!(m, r);
+
+        m.swap_rows(0, 1);
+        m.scale_row(1, &f!(2));
+        assert!(m.push_row(vec![f!(1)]).is_err());
+        m.push_row(vec![f0!(), f!(1)]).unwrap();
+        let r: FractionMatrix = vec![
+            vec![f!(1, 3), f!(1, 3)],
+            vec![f!(1), f!(1, 2)],
+            vec![f0!(), f!(1)],
+        ]
+        .try_into()
+        .unwrap();
+        assert_eq!(m, r);
+        assert_eq!(m.get_diagonal(), vec![f!(1, 3), f!(1, 2)]);
+        assert!(m.trace().is_err());
+    }
+
     #[test]
     fn fraction_matrix_inverse() {
         let mut m1: FractionMatrix = vec![
@@ -151,6 +254,22 @@ mod tests {
         assert!(m1.eq(&mut m2));
     }
 
+    #[test]
+    fn identity_diagonal() {
+        let i = FractionMatrix::identity(3);
+        let d = FractionMatrix::diagonal(vec![f!(1), f!(1), f!(1)]);
+        assert_eq!(i, d);
+        assert!(i.is_one(1, 1));
+        assert!(!i.is_one(0, 1));
+
+        let d = FractionMatrix::diagonal(vec![f!(1, 2), f!(-3)]);
+        let correct: FractionMatrix = vec![vec![f!(1, 2), f0!()], vec![f0!(), f!(-3)]]
+            .try_into()
+            .unwrap();
+        assert_eq!(d, correct);
+        assert_eq!(FractionMatrix::identity(0), FractionMatrix::new(0, 0));
+    }
+
     #[test]
     fn display_empty() {
         let m = FractionMatrix::new(0, 0);
@@ -177,4 +296,65 @@ mod tests {
         let u: Vec<Vec<Fraction>> = vec![];
         assert_eq!(m.to_vec(), u);
     }
+
+    #[test]
+    fn display_empty_shapes() {
+        assert_eq!(format!("{}", FractionMatrix::new(0, 0)), "{}");
+        assert_eq!(format!("{}", FractionMatrix::new(0, 2)), "{}");
+        assert_eq!(format!("{}", FractionMatrix::new(1, 0)), "{{}}");
+        assert_eq!(format!("{}", FractionMatrix::new(2, 0)), "{{},\n {}}");
+    }
+
+    #[test]
+    fn mul_empty() {
+        //an empty inner dimension yields zeroes
+        let a = FractionMatrix::new(2, 0);
+        let b = FractionMatrix::new(0, 3);
+        let p = (&a * &b).unwrap();
+        assert_eq!(p, FractionMatrix::new(2, 3));
+        assert_eq!(
+            (&a * &vec![]).unwrap(),
+            vec![Fraction::zero(), Fraction::zero()]
+        );
+        assert_eq!((&vec![] * &b).unwrap(), vec![Fraction::zero(); 3]);
+
+        //empty outer dimensions yield empty results
+        let p = (&b * &FractionMatrix::new(3, 0)).unwrap();
+        assert_eq!((p.number_of_rows(), p.number_of_columns()), (0, 0));
+        assert!((&b * &vec![Fraction::zero(); 3]).unwrap().is_empty());
+
+        //sizes still need to match
+        assert!((&a * &FractionMatrix::new(1, 1)).is_err());
+    }
+
+    #[test]
+    fn algorithms_empty() {
+        let m = FractionMatrix::new(0, 0);
+        assert_eq!(m.clone().invert().unwrap(), m);
+        assert!(FractionMatrix::new(1, 0).invert().is_err());
+        assert!(FractionMatrix::new(0, 1).invert().is_err());
+
+        for (rows, columns) in [(0, 0), (0, 3), (3, 0)] {
+            let mut m = FractionMatrix::new(rows, columns);
+            m.gauss_jordan();
+            assert_eq!(m, FractionMatrix::new(rows, columns));
+            assert_eq!(
+                m.clone().gauss_jordan_reduced().unwrap(),
+                FractionMatrix::new(rows, columns)
+            );
+            m.identity_minus();
+            assert_eq!((m.number_of_rows(), m.number_of_columns()), (rows, columns));
+        }
+    }
+
+    #[test]
+    fn try_from_empty_f64() {
+        use crate::matrix::fraction_matrix_f64::FractionMatrixF64;
+
+        let m = FractionMatrixF64::try_from((0, vec![])).unwrap();
+        assert_eq!((m.number_of_rows(), m.number_of_columns()), (0, 0));
+        let m = FractionMatrixF64::try_from((3, vec![])).unwrap();
+        assert_eq!((m.number_of_rows(), m.number_of_columns()), (0, 3));
+        assert!(FractionMatrixF64::try_from((0, vec![1.into()])).is_err());
+    }
 }
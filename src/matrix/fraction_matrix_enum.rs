@@ -22,6 +22,39 @@ pub enum FractionMatrixEnum {
     CannotCombineExactAndApprox,
 }
 
+impl FractionMatrixEnum {
+    /// Returns the tolerance below which values of this matrix are considered to be zero.
+    /// Exact matrices need no tolerance, and return None.
+    pub fn epsilon(&self) -> Option<f64> {
+        match self {
+            FractionMatrixEnum::Approx(m) => Some(m.epsilon()),
+            FractionMatrixEnum::Exact(_) | FractionMatrixEnum::CannotCombineExactAndApprox => None,
+        }
+    }
+
+    /// Sets the tolerance below which values of this matrix are considered to be zero.
+    /// Has no effect on exact matrices.
+    pub fn set_epsilon(&mut self, epsilon: f64) {
+        if let FractionMatrixEnum::Approx(m) = self {
+            m.set_epsilon(epsilon);
+        }
+    }
+
+    /// Returns a hash of the matrix that is stable across runs, platforms and versions of this crate.
+    /// Only exact matrices have a canonical form.
+    pub fn canonical_hash(&self) -> Result<u64> {
+        match self {
+            FractionMatrixEnum::Exact(m) => Ok(m.canonical_hash()),
+            FractionMatrixEnum::Approx(_) => Err(anyhow!(
+                "approximate matrices have no canonical form to hash"
+            )),
+            FractionMatrixEnum::CannotCombineExactAndApprox => {
+                Err(anyhow!("cannot combine exact and approximate arithmetic"))
+            }
+        }
+    }
+}
+
 impl EbiMatrix<FractionEnum> for FractionMatrixEnum {
     fn new(number_of_rows: usize, number_of_columns: usize) -> Self {
         if exact::is_exact_globally() {
@@ -199,6 +232,74 @@ impl EbiMatrix<FractionEnum> for FractionMatrixEnum {
             FractionMatrixEnum::CannotCombineExactAndApprox => false,
         }
     }
+
+    fn push_row(&mut self, values: Vec<FractionEnum>) -> Result<()> {
+        match self {
+            FractionMatrixEnum::Approx(m) => m.push_row(
+                values
+                    .into_iter()
+                    .map(|f| Ok(FractionF64(f.approx()?)))
+                    .collect::<Result<_>>()?,
+            ),
+            FractionMatrixEnum::Exact(m) => m.push_row(
+                values
+                    .into_iter()
+                    .map(|f| Ok(FractionExact(f.exact()?)))
+                    .collect::<Result<_>>()?,
+            ),
+            FractionMatrixEnum::CannotCombineExactAndApprox => {
+                Err(anyhow!("cannot combine exact and approximate arithmetic"))
+            }
+        }
+    }
+
+    fn remove_row(&mut self, row: usize) {
+        match self {
+            FractionMatrixEnum::Approx(m) => m.remove_row(row),
+            FractionMatrixEnum::Exact(m) => m.remove_row(row),
+            FractionMatrixEnum::CannotCombineExactAndApprox => {}
+        }
+    }
+
+    fn remove_column(&mut self, column: usize) {
+        match self {
+            FractionMatrixEnum::Approx(m) => m.remove_column(column),
+            FractionMatrixEnum::Exact(m) => m.remove_column(column),
+            FractionMatrixEnum::CannotCombineExactAndApprox => {}
+        }
+    }
+
+    fn swap_rows(&mut self, row_a: usize, row_b: usize) {
+        match self {
+            FractionMatrixEnum::Approx(m) => m.swap_rows(row_a, row_b),
+            FractionMatrixEnum::Exact(m) => m.swap_rows(row_a, row_b),
+            FractionMatrixEnum::CannotCombineExactAndApprox => {}
+        }
+    }
+
+    fn scale_row(&mut self, row: usize, factor: &FractionEnum) {
+        match (&mut *self, factor) {
+            (FractionMatrixEnum::Approx(m), FractionEnum::Approx(f)) => {
+                m.scale_row(row, &FractionF64(*f))
+            }
+            (FractionMatrixEnum::Exact(m), FractionEnum::Exact(f)) => m.values
+                [row * m.number_of_columns..(row + 1) * m.number_of_columns]
+                .iter_mut()
+                .for_each(|v| *v *= f),
+            (FractionMatrixEnum::CannotCombineExactAndApprox, _) => {}
+            _ => *self = FractionMatrixEnum::CannotCombineExactAndApprox,
+        }
+    }
+
+    fn trace(&self) -> Result<FractionEnum> {
+        match self {
+            FractionMatrixEnum::Approx(m) => Ok(FractionEnum::Approx(m.trace()?.0)),
+            FractionMatrixEnum::Exact(m) => Ok(FractionEnum::Exact(m.trace()?.0)),
+            FractionMatrixEnum::CannotCombineExactAndApprox => {
+                Err(anyhow!("cannot combine exact and approximate arithmetic"))
+            }
+        }
+    }
 }
 
 impl TryFrom<Vec<Vec<FractionEnum>>> for FractionMatrixEnum {
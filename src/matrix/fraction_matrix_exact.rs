@@ -1,4 +1,5 @@
 use anyhow::{Error, Result, anyhow};
+use fnv::FnvHasher;
 use itertools::Itertools;
 use malachite::{
     base::num::basic::traits::{One as MOne, Zero as MZero},
@@ -6,9 +7,13 @@ use malachite::{
 };
 
 use crate::{
-    One, Signed, Zero, ebi_matrix::EbiMatrix, fraction::fraction_exact::FractionExact,
-    pop_front_columns, push_columns,
+    One, Signed, Zero,
+    ebi_matrix::EbiMatrix,
+    fraction::fraction_exact::{FractionExact, hash_rational_canonically},
+    matrix::fraction_matrix::fmt_aligned,
+    pop_front_columns, push_columns, remove_column, swap_rows,
 };
+use std::hash::{Hash, Hasher};
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct FractionMatrixExact {
@@ -21,6 +26,46 @@ impl FractionMatrixExact {
     pub(crate) fn index(&self, row: usize, column: usize) -> usize {
         row * self.number_of_columns + column
     }
+
+    /// Returns whether the given value is zero. Exact values need no tolerance.
+    pub(crate) fn is_zero_value(&self, value: &Rational) -> bool {
+        value.is_zero()
+    }
+
+    /// Creates a matrix with the given values.
+    pub(crate) fn with_values(
+        &self,
+        values: Vec<Rational>,
+        number_of_rows: usize,
+        number_of_columns: usize,
+    ) -> Self {
+        Self {
+            values,
+            number_of_rows,
+            number_of_columns,
+        }
+    }
+
+    /// Returns a hash of the matrix that is stable across runs, platforms and versions of this crate.
+    /// Equal matrices have equal hashes, regardless of how their values were computed.
+    /// Suitable as a key for memoisation in persistent or shared caches.
+    pub fn canonical_hash(&self) -> u64 {
+        let mut hasher = FnvHasher::default();
+        hasher.write(&(self.number_of_rows as u64).to_le_bytes());
+        hasher.write(&(self.number_of_columns as u64).to_le_bytes());
+        for value in &self.values {
+            hash_rational_canonically(value, &mut hasher);
+        }
+        hasher.finish()
+    }
+}
+
+impl Hash for FractionMatrixExact {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.number_of_rows.hash(state);
+        self.number_of_columns.hash(state);
+        self.values.hash(state);
+    }
 }
 
 impl EbiMatrix<FractionExact> for FractionMatrixExact {
@@ -124,6 +169,60 @@ impl EbiMatrix<FractionExact> for FractionMatrixExact {
     fn is_negative(&self, row: usize, column: usize) -> bool {
         Signed::is_negative(&self.values[row * self.number_of_columns + column])
     }
+
+    fn push_row(&mut self, values: Vec<FractionExact>) -> Result<()> {
+        if values.len() != self.number_of_columns {
+            return Err(anyhow!(
+                "cannot add a row of {} values to a matrix of {} columns",
+                values.len(),
+                self.number_of_columns
+            ));
+        }
+        self.values.extend(values.into_iter().map(|f| f.0));
+        self.number_of_rows += 1;
+        Ok(())
+    }
+
+    fn remove_row(&mut self, row: usize) {
+        self.values
+            .drain(row * self.number_of_columns..(row + 1) * self.number_of_columns);
+        self.number_of_rows -= 1;
+    }
+
+    fn remove_column(&mut self, column: usize) {
+        remove_column!(
+            column,
+            self.values,
+            self.number_of_rows,
+            self.number_of_columns
+        );
+        self.number_of_columns -= 1;
+    }
+
+    fn swap_rows(&mut self, row_a: usize, row_b: usize) {
+        swap_rows!(row_a, row_b, self.values, self.number_of_columns);
+    }
+
+    fn scale_row(&mut self, row: usize, factor: &FractionExact) {
+        self.values[row * self.number_of_columns..(row + 1) * self.number_of_columns]
+            .iter_mut()
+            .for_each(|f| *f *= &factor.0);
+    }
+
+    fn trace(&self) -> Result<FractionExact> {
+        if self.number_of_rows != self.number_of_columns {
+            return Err(anyhow!(
+                "can only take the trace of a square matrix, but got a {}x{} matrix",
+                self.number_of_rows,
+                self.number_of_columns
+            ));
+        }
+        Ok(FractionExact(
+            (0..self.number_of_rows)
+                .map(|i| &self.values[self.index(i, i)])
+                .sum(),
+        ))
+    }
 }
 
 impl TryFrom<Vec<Vec<FractionExact>>> for FractionMatrixExact {
@@ -162,24 +261,49 @@ impl TryFrom<Vec<Vec<FractionExact>>> for FractionMatrixExact {
 
 impl std::fmt::Display for FractionMatrixExact {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{{{{")?;
-        if self.number_of_columns > 0 {
-            for (i, row) in self.values.chunks(self.number_of_columns).enumerate() {
-                for (j, fraction) in row.iter().enumerate() {
-                    write!(f, "{}", fraction.to_string())?;
-                    if j < row.len() - 1 {
-                        write!(f, ", ")?;
-                    }
-                }
-                if i < self.number_of_rows - 1 {
-                    write!(f, "}},\n {{")?;
-                }
-            }
-        } else {
-            for _ in 0..self.number_of_rows {
-                write!(f, "}},\n{{")?;
-            }
-        }
-        write!(f, "}}}}")
+        let values = self
+            .values
+            .iter()
+            .map(|value| value.to_string())
+            .collect::<Vec<_>>();
+        fmt_aligned(f, &values, self.number_of_rows, self.number_of_columns)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        EbiMatrix, fraction::fraction_exact::FractionExact,
+        matrix::fraction_matrix_exact::FractionMatrixExact,
+    };
+
+    #[test]
+    fn canonical_hash() {
+        let a: FractionMatrixExact = vec![vec![(2, 4).into(), 3.into()]].try_into().unwrap();
+
+        //the same values, computed differently
+        let mut b = FractionMatrixExact::new(1, 2);
+        b.set(
+            0,
+            0,
+            FractionExact::from((1, 3)) + FractionExact::from((1, 6)),
+        );
+        b.set(0, 1, FractionExact::from((9, 3)));
+        assert_eq!(a.canonical_hash(), b.canonical_hash());
+
+        //the same values in a different shape
+        let c: FractionMatrixExact = vec![vec![(1, 2).into()], vec![3.into()]]
+            .try_into()
+            .unwrap();
+        assert_ne!(a.canonical_hash(), c.canonical_hash());
+
+        //the sign matters
+        b.set(0, 1, FractionExact::from(-3));
+        assert_ne!(a.canonical_hash(), b.canonical_hash());
+
+        assert_eq!(
+            FractionExact::from((2, 4)).canonical_hash(),
+            FractionExact::from((1, 2)).canonical_hash()
+        );
     }
 }
@@ -1,9 +1,12 @@
 use crate::{
-    One, Signed,
     ebi_matrix::EbiMatrix,
     ebi_number::Zero,
-    fraction::{fraction::EPSILON, fraction_f64::FractionF64},
-    pop_front_columns, push_columns,
+    fraction::{
+        fraction::{EPSILON, is_approximately_zero},
+        fraction_f64::FractionF64,
+    },
+    matrix::fraction_matrix::fmt_aligned,
+    pop_front_columns, push_columns, remove_column, swap_rows,
 };
 use anyhow::{Error, Result, anyhow};
 
@@ -12,12 +15,46 @@ pub struct FractionMatrixF64 {
     pub(crate) values: Vec<f64>,
     pub(crate) number_of_rows: usize,
     pub(crate) number_of_columns: usize,
+    pub(crate) epsilon: f64,
 }
 
 impl FractionMatrixF64 {
     pub(crate) fn index(&self, row: usize, column: usize) -> usize {
         row * self.number_of_columns + column
     }
+
+    /// Returns the tolerance below which values of this matrix are considered to be zero.
+    /// Defaults to [EPSILON].
+    pub fn epsilon(&self) -> f64 {
+        self.epsilon
+    }
+
+    /// Sets the tolerance below which values of this matrix are considered to be zero.
+    /// This tolerance is used by the matrix algorithms, for instance to decide whether a pivot is zero.
+    /// Matrices derived from this matrix inherit its tolerance.
+    pub fn set_epsilon(&mut self, epsilon: f64) {
+        self.epsilon = epsilon;
+    }
+
+    /// Returns whether the given value is zero according to the tolerance of this matrix.
+    pub(crate) fn is_zero_value(&self, value: &f64) -> bool {
+        is_approximately_zero(*value, self.epsilon)
+    }
+
+    /// Creates a matrix with the given values and the tolerance of this matrix.
+    pub(crate) fn with_values(
+        &self,
+        values: Vec<f64>,
+        number_of_rows: usize,
+        number_of_columns: usize,
+    ) -> Self {
+        Self {
+            values,
+            number_of_rows,
+            number_of_columns,
+            epsilon: self.epsilon,
+        }
+    }
 }
 
 impl EbiMatrix<FractionF64> for FractionMatrixF64 {
@@ -26,6 +63,7 @@ impl EbiMatrix<FractionF64> for FractionMatrixF64 {
             number_of_rows,
             number_of_columns,
             values: vec![0f64; number_of_rows * number_of_columns],
+            epsilon: EPSILON,
         }
     }
 
@@ -98,7 +136,7 @@ impl EbiMatrix<FractionF64> for FractionMatrixF64 {
     }
 
     fn is_one(&self, row: usize, column: usize) -> bool {
-        self.values[row * self.number_of_columns + column].is_one()
+        self.is_zero_value(&(self.values[row * self.number_of_columns + column] - 1f64))
     }
 
     fn increase(&mut self, row: usize, column: usize, value: &FractionF64) {
@@ -116,11 +154,67 @@ impl EbiMatrix<FractionF64> for FractionMatrixF64 {
     }
 
     fn is_positive(&self, row: usize, column: usize) -> bool {
-        Signed::is_positive(&self.values[row * self.number_of_columns + column])
+        let value = self.values[row * self.number_of_columns + column];
+        value > 0f64 && !self.is_zero_value(&value)
     }
 
     fn is_negative(&self, row: usize, column: usize) -> bool {
-        Signed::is_negative(&self.values[row * self.number_of_columns + column])
+        let value = self.values[row * self.number_of_columns + column];
+        value < 0f64 && !self.is_zero_value(&value)
+    }
+
+    fn push_row(&mut self, values: Vec<FractionF64>) -> Result<()> {
+        if values.len() != self.number_of_columns {
+            return Err(anyhow!(
+                "cannot add a row of {} values to a matrix of {} columns",
+                values.len(),
+                self.number_of_columns
+            ));
+        }
+        self.values.extend(values.into_iter().map(|f| f.0));
+        self.number_of_rows += 1;
+        Ok(())
+    }
+
+    fn remove_row(&mut self, row: usize) {
+        self.values
+            .drain(row * self.number_of_columns..(row + 1) * self.number_of_columns);
+        self.number_of_rows -= 1;
+    }
+
+    fn remove_column(&mut self, column: usize) {
+        remove_column!(
+            column,
+            self.values,
+            self.number_of_rows,
+            self.number_of_columns
+        );
+        self.number_of_columns -= 1;
+    }
+
+    fn swap_rows(&mut self, row_a: usize, row_b: usize) {
+        swap_rows!(row_a, row_b, self.values, self.number_of_columns);
+    }
+
+    fn scale_row(&mut self, row: usize, factor: &FractionF64) {
+        self.values[row * self.number_of_columns..(row + 1) * self.number_of_columns]
+            .iter_mut()
+            .for_each(|f| *f *= factor.0);
+    }
+
+    fn trace(&self) -> Result<FractionF64> {
+        if self.number_of_rows != self.number_of_columns {
+            return Err(anyhow!(
+                "can only take the trace of a square matrix, but got a {}x{} matrix",
+                self.number_of_rows,
+                self.number_of_columns
+            ));
+        }
+        Ok(FractionF64(
+            (0..self.number_of_rows)
+                .map(|i| self.values[self.index(i, i)])
+                .sum(),
+        ))
     }
 }
 
@@ -132,7 +226,7 @@ impl PartialEq for FractionMatrixF64 {
                 .values
                 .iter()
                 .zip(other.values.iter())
-                .all(|(a, b)| (a - b).abs() < EPSILON)
+                .all(|(a, b)| is_approximately_zero(a - b, self.epsilon.max(other.epsilon)))
     }
 }
 
@@ -143,30 +237,29 @@ impl TryFrom<(usize, Vec<FractionF64>)> for FractionMatrixF64 {
 
     fn try_from(value: (usize, Vec<FractionF64>)) -> Result<Self> {
         let (number_of_columns, values) = value;
+
+        if number_of_columns == 0 {
+            //no columns: the number of rows cannot be derived from the cells
+            if !values.is_empty() {
+                return Err(anyhow!("cells provided for a matrix without columns"));
+            }
+            return Ok(Self::new(0, 0));
+        }
+
         let number_of_rows = values.len() / number_of_columns;
 
         if number_of_rows * number_of_columns != values.len() {
             return Err(anyhow!("some cells of the matrix are not provided"));
         }
 
-        if number_of_rows != 0 {
-            //has rows
-
-            let values = values.into_iter().map(|cell| cell.0).collect::<Vec<_>>();
+        let values = values.into_iter().map(|cell| cell.0).collect::<Vec<_>>();
 
-            Ok(Self {
-                number_of_columns,
-                number_of_rows,
-                values,
-            })
-        } else {
-            //no rows
-            Ok(Self {
-                number_of_columns: 0,
-                number_of_rows: 0,
-                values: vec![],
-            })
-        }
+        Ok(Self {
+            number_of_columns,
+            number_of_rows,
+            values,
+            epsilon: EPSILON,
+        })
     }
 }
 
@@ -195,30 +288,18 @@ impl TryFrom<Vec<Vec<FractionF64>>> for FractionMatrixF64 {
             values: new_values,
             number_of_rows,
             number_of_columns,
+            epsilon: EPSILON,
         })
     }
 }
 
 impl std::fmt::Display for FractionMatrixF64 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{{{{")?;
-        if self.number_of_columns > 0 {
-            for (i, row) in self.values.chunks(self.number_of_columns).enumerate() {
-                for (j, fraction) in row.iter().enumerate() {
-                    write!(f, "{}", fraction.to_string())?;
-                    if j < row.len() - 1 {
-                        write!(f, ", ")?;
-                    }
-                }
-                if i < self.number_of_rows - 1 {
-                    write!(f, "}},\n {{")?;
-                }
-            }
-        } else {
-            for _ in 0..self.number_of_rows {
-                write!(f, "}},\n{{")?;
-            }
-        }
-        write!(f, "}}}}")
+        let values = self
+            .values
+            .iter()
+            .map(|value| value.to_string())
+            .collect::<Vec<_>>();
+        fmt_aligned(f, &values, self.number_of_rows, self.number_of_columns)
     }
 }
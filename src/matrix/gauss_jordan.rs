@@ -1,127 +1,260 @@
-use anyhow::{Result, anyhow};
+use anyhow::{Error, Result, anyhow};
 use malachite::rational::Rational;
-use std::sync::atomic::AtomicBool;
 
 use crate::{
+    GaussJordan,
     ebi_matrix::EbiMatrix,
     ebi_number::{One, Zero},
+    fraction::{
+        fraction_enum::FractionEnum, fraction_exact::FractionExact, fraction_f64::FractionF64,
+    },
     matrix::{
         fraction_matrix_enum::FractionMatrixEnum, fraction_matrix_exact::FractionMatrixExact,
-        fraction_matrix_f64::FractionMatrixF64,
-    }, GaussJordan,
+        fraction_matrix_f64::FractionMatrixF64, inversion::SingularMatrixError,
+    },
+    size_guard::check_exact_size,
 };
 
+/// The optional `$checkpoint` is evaluated after each row.
 macro_rules! gauss_jordan {
-    ($self:ident) => {
+    ($self:ident $(, $checkpoint:expr)?) => {
         let number_of_rows = $self.number_of_rows();
         let number_of_columns = $self.number_of_columns();
 
-        if number_of_rows == 0 || number_of_columns == 0 {
-            return;
-        }
+        if number_of_rows > 0 && number_of_columns > 0 {
+            for row_a in 0..number_of_rows - 1 {
+                if $self.is_zero_value(&$self.values[row_a * number_of_columns + row_a]) {
+                    continue;
+                } else {
+                    for row_b in row_a..number_of_rows - 1 {
+                        //optimisation: do not attempt to add a factor of 0
+                        if !$self.is_zero_value(&$self.values[(row_b + 1) * number_of_columns + row_a])
+                        {
+                            let mut factor =
+                                $self.values[(row_b + 1) * number_of_columns + row_a].clone();
+                            factor /= &$self.values[row_a * number_of_columns + row_a];
+                            // let factor = &values[row_b + 1][row_a] / &values[row_a][row_a];
 
-        for row_a in 0..number_of_rows - 1 {
-            if $self.values[row_a * number_of_columns + row_a].is_zero() {
-                continue;
-            } else {
-                for row_b in row_a..number_of_rows - 1 {
-                    //optimisation: do not attempt to add a factor of 0
-                    if !$self.values[(row_b + 1) * number_of_columns + row_a].is_zero() {
-                        let mut factor =
-                            $self.values[(row_b + 1) * number_of_columns + row_a].clone();
-                        factor /= &$self.values[row_a * number_of_columns + row_a];
-                        // let factor = &values[row_b + 1][row_a] / &values[row_a][row_a];
-
-                        // println!(
-                        //     "\t\t\t\t\tfactor row_a {}, row_b {}, {}",
-                        //     row_a, row_b, factor
-                        // );
-                        for column in row_a..number_of_columns {
-                            let mut old = $self.values[row_a * number_of_columns + column].clone();
-                            old *= &factor;
-                            $self.values[(row_b + 1) * number_of_columns + column] -= old;
-                        }
+                            // println!(
+                            //     "\t\t\t\t\tfactor row_a {}, row_b {}, {}",
+                            //     row_a, row_b, factor
+                            // );
+                            for column in row_a..number_of_columns {
+                                let mut old = $self.values[row_a * number_of_columns + column].clone();
+                                old *= &factor;
+                                $self.values[(row_b + 1) * number_of_columns + column] -= old;
+                            }
 
-                        // log::debug!("\t\t\t       now {}", self);
+                            // log::debug!("\t\t\t       now {}", self);
+                        }
                     }
                 }
+                $($checkpoint;)?
             }
-        }
 
-        // println!("row-reduced echelon\n{:?}", values);
+            // println!("row-reduced echelon\n{:?}", values);
 
-        // log::info!("number of columns {}", self.get_number_of_columns());
+            // log::info!("number of columns {}", self.get_number_of_columns());
 
-        // log::info!("first step done");
+            // log::info!("first step done");
 
-        for i in (0..number_of_rows).rev() {
-            if $self.values[i * number_of_columns + i].is_zero() {
-                continue;
-            } else {
-                for j in (0..i).rev() {
-                    let mut factor = $self.values[j * number_of_columns + i].clone();
-                    factor /= &$self.values[i * number_of_columns + i];
-                    // let factor = &values[j][i] / &values[i][i];
-
-                    for k in i..number_of_columns {
-                        let mut old = $self.values[i * number_of_columns + k].clone();
-                        old *= &factor;
-                        $self.values[j * number_of_columns + k] -= old;
+            for i in (0..number_of_rows).rev() {
+                if $self.is_zero_value(&$self.values[i * number_of_columns + i]) {
+                    continue;
+                } else {
+                    for j in (0..i).rev() {
+                        let mut factor = $self.values[j * number_of_columns + i].clone();
+                        factor /= &$self.values[i * number_of_columns + i];
+                        // let factor = &values[j][i] / &values[i][i];
+
+                        for k in i..number_of_columns {
+                            let mut old = $self.values[i * number_of_columns + k].clone();
+                            old *= &factor;
+                            $self.values[j * number_of_columns + k] -= old;
+                        }
                     }
                 }
+                $($checkpoint;)?
             }
-        }
 
-        // log::debug!("\t\tsecond step        {}", self);
+            // log::debug!("\t\tsecond step        {}", self);
 
-        // log::info!("second step done");
+            // log::info!("second step done");
+        }
     };
 }
+pub(crate) use gauss_jordan;
+
+/// A value that can serve as a pivot during elimination.
+pub(crate) trait Pivot {
+    /// Returns whether `self` is a better pivot than `other`; both are non-zero.
+    fn is_better_pivot_than(&self, other: &Self) -> bool;
+}
+
+/// Partial pivoting: the largest value limits the growth of rounding errors.
+impl Pivot for f64 {
+    fn is_better_pivot_than(&self, other: &Self) -> bool {
+        self.abs() > other.abs()
+    }
+}
 
+/// Exact values have no rounding errors, so the first non-zero value is as good as any.
+impl Pivot for Rational {
+    fn is_better_pivot_than(&self, _other: &Self) -> bool {
+        false
+    }
+}
+
+impl Pivot for FractionF64 {
+    fn is_better_pivot_than(&self, other: &Self) -> bool {
+        self.0.is_better_pivot_than(&other.0)
+    }
+}
+
+impl Pivot for FractionExact {
+    fn is_better_pivot_than(&self, other: &Self) -> bool {
+        self.0.is_better_pivot_than(&other.0)
+    }
+}
+
+impl Pivot for FractionEnum {
+    fn is_better_pivot_than(&self, other: &Self) -> bool {
+        match (self, other) {
+            (FractionEnum::Approx(a), FractionEnum::Approx(b)) => a.is_better_pivot_than(b),
+            _ => false,
+        }
+    }
+}
+
+/// Brings the matrix in reduced row echelon form, of which the leading square block is the identity.
+/// Rows are swapped to find non-zero pivots, which does not change the reduced row echelon form.
+/// Evaluates to a [SingularMatrixError] if the leading square block is singular.
+/// `$self.check_size()` is evaluated after each pivot.
 macro_rules! gauss_jordan_reduced {
     ($self:expr, $t:ident) => {{
-        {
-            $self.gauss_jordan();
-
-            let number_of_rows = $self.number_of_rows();
-            let number_of_columns = $self.number_of_columns();
-
-            let failed = AtomicBool::new(false);
-
-            $self
-                .values
-                .chunks_mut(number_of_columns)
-                .enumerate()
-                .for_each(|(i, row)| {
-                    let factor = row[i].clone();
-                    if factor.is_zero() {
-                        failed.store(true, std::sync::atomic::Ordering::Relaxed);
-                    } else {
-                        for j in number_of_rows..number_of_columns {
-                            row[j] /= &factor;
-                        }
-                        row[i] = $t::one();
-                    }
-                });
+        let number_of_rows = $self.number_of_rows();
+        let number_of_columns = $self.number_of_columns();
+        let k = number_of_rows.min(number_of_columns);
 
-            if failed.load(std::sync::atomic::Ordering::Relaxed) {
-                return Err(anyhow!("matrix has no reduced row-echelon form"));
+        let mut rank = 0;
+        let mut first_zero_pivot_row = None;
+        for column in 0..k {
+            //find the pivot among the rows that have none yet
+            let mut pivot = None;
+            for row in rank..number_of_rows {
+                let value = &$self.values[row * number_of_columns + column];
+                if !$self.is_zero_value(value)
+                    && pivot.is_none_or(|p: usize| {
+                        value.is_better_pivot_than(&$self.values[p * number_of_columns + column])
+                    })
+                {
+                    pivot = Some(row);
+                }
             }
+            let Some(pivot) = pivot else {
+                first_zero_pivot_row.get_or_insert(rank);
+                continue;
+            };
 
-            // log::info!("third step done");
+            //the rows without a pivot are zero before this column
+            if pivot != rank {
+                for c in column..number_of_columns {
+                    $self
+                        .values
+                        .swap(pivot * number_of_columns + c, rank * number_of_columns + c);
+                }
+            }
+
+            //eliminate the column in all other rows
+            for row in (0..number_of_rows).filter(|row| *row != rank) {
+                if $self.is_zero_value(&$self.values[row * number_of_columns + column]) {
+                    continue;
+                }
+                let mut factor = $self.values[row * number_of_columns + column].clone();
+                factor /= &$self.values[rank * number_of_columns + column];
+                for c in column + 1..number_of_columns {
+                    let mut old = $self.values[rank * number_of_columns + c].clone();
+                    old *= &factor;
+                    $self.values[row * number_of_columns + c] -= old;
+                }
+                $self.values[row * number_of_columns + column] = $t::zero();
+            }
 
-            Ok($self)
+            $self.check_size()?;
+            rank += 1;
         }
+
+        if let Some(first_zero_pivot_row) = first_zero_pivot_row {
+            crate::events::debug_event!(
+                rank,
+                first_zero_pivot_row,
+                size = number_of_rows,
+                "singular matrix"
+            );
+            return Err(SingularMatrixError {
+                rank,
+                first_zero_pivot_row,
+            }
+            .into());
+        }
+
+        //scale the pivots to one
+        for i in 0..k {
+            let factor = $self.values[i * number_of_columns + i].clone();
+            for j in number_of_rows..number_of_columns {
+                $self.values[i * number_of_columns + j] /= &factor;
+            }
+            $self.values[i * number_of_columns + i] = $t::one();
+        }
+
+        Ok(())
     }};
 }
 
+/// Rephrases the error of a singular leading block for reduction, which is not about inversion.
+pub(crate) fn reduction_error(error: Error) -> Error {
+    match error.downcast_ref::<SingularMatrixError>() {
+        Some(singular) => anyhow!(
+            "matrix has no reduced row echelon form: its leading square block has rank {}, and the pivot of row {} is zero",
+            singular.rank,
+            singular.first_zero_pivot_row
+        ),
+        None => error,
+    }
+}
+
+impl FractionMatrixF64 {
+    /// Approximate values have a fixed size, thus there is nothing to guard.
+    pub(crate) fn check_size(&self) -> Result<()> {
+        Ok(())
+    }
+
+    /// Brings the matrix in reduced row echelon form, or returns a [SingularMatrixError]; on error, the matrix is left partially eliminated.
+    pub(crate) fn reduce(&mut self) -> Result<()> {
+        gauss_jordan_reduced!(self, f64)
+    }
+}
+
+impl FractionMatrixExact {
+    /// Aborts elimination if the values outgrow the limit of an [ExactSizeGuard](crate::size_guard::ExactSizeGuard).
+    pub(crate) fn check_size(&self) -> Result<()> {
+        check_exact_size(&[&self.values])
+    }
+
+    /// Brings the matrix in reduced row echelon form, or returns a [SingularMatrixError]; on error, the matrix is left partially eliminated.
+    pub(crate) fn reduce(&mut self) -> Result<()> {
+        gauss_jordan_reduced!(self, Rational)
+    }
+}
+
 impl GaussJordan for FractionMatrixF64 {
     fn gauss_jordan(&mut self) {
         gauss_jordan!(self);
     }
 
     fn gauss_jordan_reduced(mut self) -> Result<Self> {
-        gauss_jordan_reduced!(self, f64)
+        self.reduce().map_err(reduction_error)?;
+        Ok(self)
     }
 }
 impl GaussJordan for FractionMatrixExact {
@@ -130,7 +263,8 @@ impl GaussJordan for FractionMatrixExact {
     }
 
     fn gauss_jordan_reduced(mut self) -> Result<Self> {
-        gauss_jordan_reduced!(self, Rational)
+        self.reduce().map_err(reduction_error)?;
+        Ok(self)
     }
 }
 
@@ -1,8 +1,16 @@
-use malachite::{base::num::basic::traits::One, rational::Rational};
+use anyhow::{Result, anyhow};
+use malachite::{
+    base::num::{arithmetic::traits::NegAssign, basic::traits::One},
+    rational::Rational,
+};
 
 use crate::{
     IdentityMinus,
     ebi_matrix::EbiMatrix,
+    exact::is_exact_globally,
+    fraction::{
+        fraction_enum::FractionEnum, fraction_exact::FractionExact, fraction_f64::FractionF64,
+    },
     matrix::{
         fraction_matrix_enum::FractionMatrixEnum, fraction_matrix_exact::FractionMatrixExact,
         fraction_matrix_f64::FractionMatrixF64,
@@ -34,7 +42,7 @@ impl IdentityMinus for FractionMatrixExact {
                     self.values[idx] = &Rational::ONE - &self.values[idx];
                 } else {
                     let idx = self.index(i, j);
-                    self.values[idx] *= -Rational::ONE;
+                    self.values[idx].neg_assign();
                 }
             }
         }
@@ -51,6 +59,95 @@ impl IdentityMinus for FractionMatrixEnum {
     }
 }
 
+macro_rules! identity_minus_from_triplets {
+    ($t:ident, $v:ident, $n:ident, $triplets:ident) => {{
+        let mut result = $t::new($n, $n);
+        for i in 0..$n {
+            let idx = result.index(i, i);
+            result.values[idx] = $v::ONE;
+        }
+        for (row, column, value) in $triplets {
+            if row >= $n || column >= $n {
+                return Err(anyhow!(
+                    "triplet ({}, {}) is outside of a matrix of size {}x{}",
+                    row,
+                    column,
+                    $n,
+                    $n
+                ));
+            }
+            let idx = result.index(row, column);
+            result.values[idx] -= value.0;
+        }
+        Ok(result)
+    }};
+}
+
+impl FractionMatrixF64 {
+    /// Constructs I-Q, where I is the identity matrix of size n x n and Q is given by (row, column, value) triplets, in a single pass.
+    /// Cells not mentioned in the triplets are zero in Q; repeated cells are added up.
+    pub fn identity_minus_from_triplets(
+        n: usize,
+        triplets: impl IntoIterator<Item = (usize, usize, FractionF64)>,
+    ) -> Result<Self> {
+        identity_minus_from_triplets!(FractionMatrixF64, f64, n, triplets)
+    }
+}
+
+impl FractionMatrixExact {
+    /// Constructs I-Q, where I is the identity matrix of size n x n and Q is given by (row, column, value) triplets, in a single pass.
+    /// Cells not mentioned in the triplets are zero in Q; repeated cells are added up.
+    pub fn identity_minus_from_triplets(
+        n: usize,
+        triplets: impl IntoIterator<Item = (usize, usize, FractionExact)>,
+    ) -> Result<Self> {
+        identity_minus_from_triplets!(FractionMatrixExact, Rational, n, triplets)
+    }
+}
+
+impl FractionMatrixEnum {
+    /// Constructs I-Q, where I is the identity matrix of size n x n and Q is given by (row, column, value) triplets, in a single pass.
+    /// Cells not mentioned in the triplets are zero in Q; repeated cells are added up.
+    /// All values must match the global arithmetic mode.
+    pub fn identity_minus_from_triplets(
+        n: usize,
+        triplets: impl IntoIterator<Item = (usize, usize, FractionEnum)>,
+    ) -> Result<Self> {
+        let mut error = None;
+        if is_exact_globally() {
+            let triplets = triplets
+                .into_iter()
+                .map_while(|(row, column, value)| match value {
+                    FractionEnum::Exact(f) => Some((row, column, FractionExact(f))),
+                    _ => {
+                        error = Some(anyhow!("cannot combine exact and approximate arithmetic"));
+                        None
+                    }
+                });
+            let result = FractionMatrixExact::identity_minus_from_triplets(n, triplets)?;
+            match error {
+                Some(error) => Err(error),
+                None => Ok(FractionMatrixEnum::Exact(result)),
+            }
+        } else {
+            let triplets = triplets
+                .into_iter()
+                .map_while(|(row, column, value)| match value {
+                    FractionEnum::Approx(f) => Some((row, column, FractionF64(f))),
+                    _ => {
+                        error = Some(anyhow!("cannot combine exact and approximate arithmetic"));
+                        None
+                    }
+                });
+            let result = FractionMatrixF64::identity_minus_from_triplets(n, triplets)?;
+            match error {
+                Some(error) => Err(error),
+                None => Ok(FractionMatrixEnum::Approx(result)),
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -60,6 +157,30 @@ mod tests {
         matrix::{fraction_matrix_enum::FractionMatrixEnum, identity_minus::IdentityMinus},
     };
 
+    #[test]
+    fn identity_minus_from_triplets() {
+        let m = FractionMatrixEnum::identity_minus_from_triplets(
+            2,
+            vec![
+                (0, 1, f_en!(1, 2)),
+                (1, 1, f_en!(1, 4)),
+                (0, 1, f_en!(1, 4)),
+            ],
+        )
+        .unwrap();
+
+        let mut m2: FractionMatrixEnum =
+            vec![vec![f_en!(0), f_en!(3, 4)], vec![f_en!(0), f_en!(1, 4)]]
+                .try_into()
+                .unwrap();
+        m2.identity_minus();
+
+        assert_eq!(m, m2);
+        assert!(
+            FractionMatrixEnum::identity_minus_from_triplets(2, vec![(2, 0, f_en!(1))]).is_err()
+        );
+    }
+
     #[test]
     fn fraction_matrix_abnormal() {
         let mut m1: FractionMatrixEnum = vec![vec![f_en!(8, 3), f_en!(3, 8)]].try_into().unwrap();
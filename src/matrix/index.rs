@@ -0,0 +1,82 @@
+//! Indexing of matrices by (row, column), borrowing the stored value.
+//!
+//! This is only available for [FractionMatrixF64] and [FractionMatrixExact]: [FractionMatrixEnum](crate::matrix::fraction_matrix_enum::FractionMatrixEnum) stores either `f64` or [Rational] values, thus there is no single value type to borrow.
+//! For the enum, use [EbiMatrix::get](crate::EbiMatrix::get) and [EbiMatrix::set](crate::EbiMatrix::set) instead.
+
+use malachite::rational::Rational;
+use std::ops::{Index, IndexMut};
+
+use crate::matrix::{
+    fraction_matrix_exact::FractionMatrixExact, fraction_matrix_f64::FractionMatrixF64,
+};
+
+macro_rules! index {
+    ($t:ident, $v:ident) => {
+        impl $t {
+            fn checked_index(&self, row: usize, column: usize) -> usize {
+                if row >= self.number_of_rows || column >= self.number_of_columns {
+                    panic!(
+                        "index ({}, {}) is out of bounds for a {}x{} matrix",
+                        row, column, self.number_of_rows, self.number_of_columns
+                    );
+                }
+                self.index(row, column)
+            }
+        }
+
+        /// Borrows the value at (row, column). Panics if the row or column does not exist.
+        impl Index<(usize, usize)> for $t {
+            type Output = $v;
+
+            fn index(&self, (row, column): (usize, usize)) -> &Self::Output {
+                &self.values[self.checked_index(row, column)]
+            }
+        }
+
+        /// Assigns the value at (row, column) in place. Panics if the row or column does not exist.
+        impl IndexMut<(usize, usize)> for $t {
+            fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut Self::Output {
+                let i = self.checked_index(row, column);
+                &mut self.values[i]
+            }
+        }
+    };
+}
+
+index!(FractionMatrixF64, f64);
+index!(FractionMatrixExact, Rational);
+
+#[cfg(test)]
+mod tests {
+    use malachite::rational::Rational;
+
+    use crate::{
+        EbiMatrix,
+        fraction::fraction_exact::FractionExact,
+        matrix::{
+            fraction_matrix_exact::FractionMatrixExact, fraction_matrix_f64::FractionMatrixF64,
+        },
+    };
+
+    #[test]
+    fn index() {
+        let mut m = FractionMatrixExact::new(2, 3);
+        m[(0, 1)] = Rational::from_signeds(1, 3);
+        m[(1, 2)] += Rational::from(2);
+        let third = m[(0, 1)].clone();
+        m[(1, 2)] *= third;
+        assert_eq!(m.get(1, 2), Some(FractionExact::from((2, 3))));
+        assert_eq!(m[(1, 0)], 0u32);
+
+        let mut m = FractionMatrixF64::new(1, 1);
+        m[(0, 0)] = 0.5;
+        assert_eq!(m[(0, 0)], 0.5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_out_of_bounds() {
+        let m = FractionMatrixF64::new(2, 2);
+        let _ = m[(0, 2)];
+    }
+}
@@ -0,0 +1,220 @@
+use malachite::rational::Rational;
+use std::{collections::HashSet, sync::Arc};
+
+use crate::{
+    ebi_matrix::EbiMatrix, fraction::fraction_exact::FractionExact,
+    matrix::fraction_matrix_exact::FractionMatrixExact,
+};
+
+/// A pool of exact values, in which equal values share a single allocation (hash-consing).
+/// An interner may be shared between matrices, such that their repeated values are stored only once.
+/// The pool holds only the shared allocations, and is searched by value through `Borrow<Rational>`.
+#[derive(Clone, Debug, Default)]
+pub struct FractionInterner {
+    values: HashSet<Arc<Rational>>,
+}
+
+impl FractionInterner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the shared allocation of the value, adding it to the pool if it is not present yet.
+    pub fn intern(&mut self, value: Rational) -> Arc<Rational> {
+        if let Some(shared) = self.values.get(&value) {
+            return Arc::clone(shared);
+        }
+        let shared = Arc::new(value);
+        self.values.insert(Arc::clone(&shared));
+        shared
+    }
+
+    /// As [FractionInterner::intern], but copies the value only if it is not in the pool yet.
+    pub fn intern_ref(&mut self, value: &Rational) -> Arc<Rational> {
+        match self.values.get(value) {
+            Some(shared) => Arc::clone(shared),
+            None => self.intern(value.clone()),
+        }
+    }
+
+    /// Returns the shared allocation of the value, if it is in the pool.
+    pub fn get(&self, value: &Rational) -> Option<Arc<Rational>> {
+        self.values.get(value).cloned()
+    }
+
+    /// Returns the number of distinct values in the pool.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Removes the values that are not used outside of the pool anymore.
+    pub fn shrink(&mut self) {
+        self.values.retain(|shared| Arc::strong_count(shared) > 1);
+    }
+}
+
+/// A dense exact matrix in which equal values share an allocation, obtained from a [FractionInterner].
+/// Intended for storing matrices with many repeated values, such as uniform distributions.
+/// For computations, convert the matrix into a [FractionMatrixExact].
+#[derive(Clone, Debug)]
+pub struct InternedMatrix {
+    pub(crate) values: Vec<Arc<Rational>>,
+    pub(crate) number_of_rows: usize,
+    pub(crate) number_of_columns: usize,
+}
+
+impl InternedMatrix {
+    /// Creates an interned copy of the matrix, adding its values to the interner.
+    pub fn from_matrix(matrix: &FractionMatrixExact, interner: &mut FractionInterner) -> Self {
+        Self {
+            values: matrix
+                .values
+                .iter()
+                .map(|value| interner.intern_ref(value))
+                .collect(),
+            number_of_rows: matrix.number_of_rows,
+            number_of_columns: matrix.number_of_columns,
+        }
+    }
+
+    pub fn number_of_rows(&self) -> usize {
+        self.number_of_rows
+    }
+
+    pub fn number_of_columns(&self) -> usize {
+        self.number_of_columns
+    }
+
+    /// Gets a particular value of the matrix, if it exists.
+    pub fn get(&self, row: usize, column: usize) -> Option<FractionExact> {
+        if column >= self.number_of_columns {
+            return None;
+        }
+        let value = self.values.get(row * self.number_of_columns + column)?;
+        Some(FractionExact(value.as_ref().clone()))
+    }
+
+    /// Gets a reference to a particular value of the matrix, if it exists.
+    pub fn get_ref(&self, row: usize, column: usize) -> Option<&Rational> {
+        if column >= self.number_of_columns {
+            return None;
+        }
+        self.values
+            .get(row * self.number_of_columns + column)
+            .map(|value| value.as_ref())
+    }
+
+    /// Sets a particular value of the matrix, sharing its allocation through the interner.
+    /// If row and column do not exist, behaviour is undefined, and may panic.
+    pub fn set(
+        &mut self,
+        row: usize,
+        column: usize,
+        value: FractionExact,
+        interner: &mut FractionInterner,
+    ) {
+        self.values[row * self.number_of_columns + column] = interner.intern(value.0);
+    }
+
+    /// Returns the number of distinct allocations that the matrix refers to.
+    pub fn number_of_distinct_values(&self) -> usize {
+        let mut pointers = self.values.iter().map(Arc::as_ptr).collect::<Vec<_>>();
+        pointers.sort_unstable();
+        pointers.dedup();
+        pointers.len()
+    }
+}
+
+impl PartialEq for InternedMatrix {
+    fn eq(&self, other: &Self) -> bool {
+        self.number_of_rows == other.number_of_rows
+            && self.number_of_columns == other.number_of_columns
+            && self
+                .values
+                .iter()
+                .zip(other.values.iter())
+                .all(|(x, y)| Arc::ptr_eq(x, y) || x == y)
+    }
+}
+
+impl Eq for InternedMatrix {}
+
+/// Interns the values of the matrix in a new interner.
+impl From<&FractionMatrixExact> for InternedMatrix {
+    fn from(value: &FractionMatrixExact) -> Self {
+        Self::from_matrix(value, &mut FractionInterner::new())
+    }
+}
+
+impl From<&InternedMatrix> for FractionMatrixExact {
+    fn from(value: &InternedMatrix) -> Self {
+        let mut result = FractionMatrixExact::new(value.number_of_rows, value.number_of_columns);
+        result.values = value
+            .values
+            .iter()
+            .map(|value| value.as_ref().clone())
+            .collect();
+        result
+    }
+}
+
+impl From<InternedMatrix> for FractionMatrixExact {
+    fn from(value: InternedMatrix) -> Self {
+        (&value).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::{
+        fraction::fraction_exact::FractionExact,
+        matrix::{
+            fraction_matrix_exact::FractionMatrixExact,
+            interned_fraction_matrix::{FractionInterner, InternedMatrix},
+        },
+    };
+
+    #[test]
+    fn interning() {
+        let third = FractionExact::from((1, 3));
+        let m: FractionMatrixExact = vec![vec![third.clone(); 3]; 3].try_into().unwrap();
+
+        let mut interner = FractionInterner::new();
+        let interned = InternedMatrix::from_matrix(&m, &mut interner);
+        assert_eq!(interner.len(), 1);
+        assert_eq!(interned.number_of_distinct_values(), 1);
+        //the pool holds the value once, next to the references of the matrix
+        assert_eq!(Arc::strong_count(&interned.values[0]), 10);
+        assert!(Arc::ptr_eq(
+            &interner.get(&third.0).unwrap(),
+            &interned.values[0]
+        ));
+        assert!(Arc::ptr_eq(&interned.values[0], &interned.values[8]));
+        assert_eq!(interned.get(2, 2), Some(third));
+        assert_eq!(interned.get(0, 3), None);
+
+        //a second matrix shares the values of the first
+        let mut other = InternedMatrix::from_matrix(&m, &mut interner);
+        assert!(Arc::ptr_eq(&interned.values[0], &other.values[0]));
+        assert_eq!(interned, other);
+
+        other.set(1, 1, FractionExact::from(0), &mut interner);
+        assert_eq!(interner.len(), 2);
+        assert_eq!(other.number_of_distinct_values(), 2);
+        assert_ne!(interned, other);
+
+        let back: FractionMatrixExact = other.into();
+        assert_eq!(back.values[4], 0u32);
+        interner.shrink();
+        assert_eq!(interner.len(), 1);
+
+        let back: FractionMatrixExact = (&interned).into();
+        assert_eq!(back, m);
+    }
+}
@@ -1,7 +1,7 @@
 use std::mem;
 
 use crate::{
-    EbiMatrix, GaussJordan, Inversion, One, Recip, Zero,
+    EbiMatrix, Inversion, One, Recip, Zero,
     matrix::{
         fraction_matrix_enum::FractionMatrixEnum, fraction_matrix_exact::FractionMatrixExact,
         fraction_matrix_f64::FractionMatrixF64,
@@ -10,6 +10,28 @@ use crate::{
 use anyhow::{Result, anyhow};
 use malachite::rational::Rational;
 
+/// The error of inverting a singular matrix, which can be recovered with `downcast_ref`.
+///
+/// Elimination swaps rows to find non-zero pivots, which does not change the inverse.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SingularMatrixError {
+    pub rank: usize,
+    /// The first row of the eliminated matrix, after row swaps, of which the pivot is zero.
+    pub first_zero_pivot_row: usize,
+}
+
+impl std::fmt::Display for SingularMatrixError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "matrix is not invertible: it has rank {} and the pivot of row {} is zero",
+            self.rank, self.first_zero_pivot_row
+        )
+    }
+}
+
+impl std::error::Error for SingularMatrixError {}
+
 macro_rules! invert {
     ($self:expr, $t:ident) => {{
         if $self.number_of_columns() != $self.number_of_rows() {
@@ -21,17 +43,13 @@ macro_rules! invert {
             return Ok($self);
         }
 
-        //optimisation: size-one matrix
-        if $self.number_of_rows().is_one() {
-            if $self.values[0].is_zero() {
-                return Err(anyhow!("matrix is not invertible"));
-            }
-
+        //optimisation: size-one matrix; a singular matrix is left to elimination, which reports it
+        if $self.number_of_rows().is_one() && !$self.is_zero_value(&$self.values[0]) {
             $self.values[0] = $self.values[0].clone().recip();
             return Ok($self);
         }
 
-        //optimisation: size-two matrix
+        //optimisation: size-two matrix; a singular matrix is left to elimination, which reports it
         if $self.number_of_rows() == 2 {
             //compute determinant
             let mut det = $self.values[0].clone();
@@ -40,24 +58,29 @@ macro_rules! invert {
             det2 *= &$self.values[2];
             det -= det2;
 
-            if det.is_zero() {
-                return Err(anyhow!("matrix is not invertible"));
-            }
+            if !$self.is_zero_value(&det) {
+                // log::debug!("determinant {}", det);
 
-            // log::debug!("determinant {}", det);
+                det = det.recip();
 
-            det = det.recip();
+                //perform inverse
+                let (m1, m2) = $self.values.split_at_mut(2);
+                mem::swap(&mut m1[0], &mut m2[1]);
 
-            //perform inverse
-            let (m1, m2) = $self.values.split_at_mut(2);
-            mem::swap(&mut m1[0], &mut m2[1]);
+                $self.values[0] *= &det;
+                $self.values[3] *= &det;
 
-            $self.values[0] *= &det;
-            $self.values[3] *= &det;
+                $self.values[2] *= -&det;
+                $self.values[1] *= -det;
+                return Ok($self);
+            }
+        }
 
-            $self.values[2] *= -&det;
-            $self.values[1] *= -det;
-            return Ok($self);
+        //optimisation: symmetric matrix, of which only the lower triangle is read
+        if $self.is_exactly_symmetric() {
+            if let Some(inverse) = $self.invert_symmetric()? {
+                return Ok(inverse);
+            }
         }
 
         // println!("compute inverse of\n{}", $self);
@@ -72,7 +95,7 @@ macro_rules! invert {
         // println!("add identity\n{}", self);
 
         //solve
-        $self = $self.gauss_jordan_reduced()?;
+        $self.reduce()?;
 
         // println!("solved\n{}", self);
 
@@ -117,8 +140,10 @@ mod tests {
     use crate::{
         fraction::{fraction_enum::FractionEnum, fraction_f64::FractionF64},
         matrix::{
-            fraction_matrix_enum::FractionMatrixEnum, fraction_matrix_exact::FractionMatrixExact,
-            fraction_matrix_f64::FractionMatrixF64, inversion::Inversion,
+            fraction_matrix_enum::FractionMatrixEnum,
+            fraction_matrix_exact::FractionMatrixExact,
+            fraction_matrix_f64::FractionMatrixF64,
+            inversion::{Inversion, SingularMatrixError},
         },
     };
 
@@ -217,4 +242,108 @@ mod tests {
 
         assert_eq!(m, i);
     }
+
+    #[test]
+    fn inverse_f64_epsilon() {
+        let m: FractionMatrixF64 = vec![
+            vec![FractionF64::from(1e-10), 0.into()],
+            vec![0.into(), 1.into()],
+        ]
+        .try_into()
+        .unwrap();
+        assert!(m.clone().invert().is_ok());
+
+        let mut m2 = m.clone();
+        m2.set_epsilon(1e-8);
+        assert!(m2.invert().is_err());
+
+        let mut m3: FractionMatrixF64 = vec![
+            vec![1.into(), 2.into(), 3.into()],
+            vec![0.into(), 1.into(), 1.into()],
+            vec![2.into(), 4.into(), FractionF64::from(6.0 + 1e-10)],
+        ]
+        .try_into()
+        .unwrap();
+        assert!(m3.clone().invert().is_ok());
+        m3.set_epsilon(1e-8);
+        assert!(m3.invert().is_err());
+    }
+
+    #[test]
+    fn inverse_zero_leading_pivot() {
+        let m: FractionMatrixExact = vec![
+            vec![0.into(), 1.into(), 0.into()],
+            vec![0.into(), 0.into(), 1.into()],
+            vec![1.into(), 0.into(), 0.into()],
+        ]
+        .try_into()
+        .unwrap();
+        let i: FractionMatrixExact = vec![
+            vec![0.into(), 0.into(), 1.into()],
+            vec![1.into(), 0.into(), 0.into()],
+            vec![0.into(), 1.into(), 0.into()],
+        ]
+        .try_into()
+        .unwrap();
+        assert_eq!(m.invert().unwrap(), i);
+
+        //partial pivoting picks the largest value
+        let m: FractionMatrixF64 = vec![
+            vec![FractionF64::from(1e-20), 1.into(), 0.into()],
+            vec![1.into(), 1.into(), 0.into()],
+            vec![0.into(), 0.into(), 2.into()],
+        ]
+        .try_into()
+        .unwrap();
+        let i: FractionMatrixF64 = vec![
+            vec![(-1).into(), 1.into(), 0.into()],
+            vec![1.into(), 0.into(), 0.into()],
+            vec![0.into(), 0.into(), FractionF64::from(0.5)],
+        ]
+        .try_into()
+        .unwrap();
+        assert_eq!(m.invert().unwrap(), i);
+    }
+
+    #[test]
+    fn inverse_singular() {
+        let m: FractionMatrixExact = vec![
+            vec![1.into(), 2.into(), 3.into()],
+            vec![2.into(), 4.into(), 6.into()],
+            vec![1.into(), 0.into(), 1.into()],
+        ]
+        .try_into()
+        .unwrap();
+        //the third row moves up to pivot the second column
+        let e = m.invert().unwrap_err();
+        assert_eq!(
+            e.downcast_ref::<SingularMatrixError>(),
+            Some(&SingularMatrixError {
+                rank: 2,
+                first_zero_pivot_row: 2
+            })
+        );
+
+        let m: FractionMatrixF64 = vec![vec![0.into(), 1.into()], vec![0.into(), 2.into()]]
+            .try_into()
+            .unwrap();
+        let e = m.invert().unwrap_err();
+        assert_eq!(
+            e.downcast_ref::<SingularMatrixError>(),
+            Some(&SingularMatrixError {
+                rank: 1,
+                first_zero_pivot_row: 0
+            })
+        );
+
+        let m: FractionMatrixEnum = vec![vec![FractionEnum::from(0)]].try_into().unwrap();
+        let e = m.invert().unwrap_err();
+        assert_eq!(
+            e.downcast_ref::<SingularMatrixError>(),
+            Some(&SingularMatrixError {
+                rank: 0,
+                first_zero_pivot_row: 0
+            })
+        );
+    }
 }
@@ -0,0 +1,223 @@
+use anyhow::{Result, anyhow};
+use std::ops::Mul;
+
+use crate::{EbiMatrix, IdentityMinus, Inversion, One, SolutionSpaceOf, Zero};
+
+/// Returns the stationary distribution π of a Markov chain with row-stochastic transition matrix P, that is, the probability vector with π P = π.
+/// The system (Pᵀ - I) π = 0 is solved together with the constraint that π sums to one, exactly for exact matrices.
+///
+/// Returns an error if P is not square, has a negative value or a row that does not sum to one,
+/// or if the stationary distribution is not unique, which is the case if the chain has more than one closed communicating class.
+pub fn stationary_distribution<T, M>(transition_matrix: &M) -> Result<Vec<T>>
+where
+    T: Clone + Zero + One,
+    M: EbiMatrix<T> + SolutionSpaceOf<T>,
+{
+    let n = transition_matrix.number_of_rows();
+    if n != transition_matrix.number_of_columns() {
+        return Err(anyhow!(
+            "a transition matrix must be square, but got a {}x{} matrix",
+            n,
+            transition_matrix.number_of_columns()
+        ));
+    }
+    if n == 0 {
+        return Err(anyhow!(
+            "a Markov chain without states has no stationary distribution"
+        ));
+    }
+
+    //check that the matrix is row-stochastic, and transpose it
+    let mut row_sums = M::new(n, 1);
+    let mut system = M::new(n, n);
+    for row in 0..n {
+        for column in 0..n {
+            if transition_matrix.is_negative(row, column) {
+                return Err(anyhow!(
+                    "a transition matrix cannot have negative values, but the value at ({}, {}) is negative",
+                    row,
+                    column
+                ));
+            }
+            let value = transition_matrix.get(row, column).unwrap();
+            row_sums.increase(row, 0, &value);
+            system.set(column, row, value);
+        }
+        if !row_sums.is_one(row, 0) {
+            return Err(anyhow!(
+                "the rows of a transition matrix must sum to one, but row {} does not",
+                row
+            ));
+        }
+    }
+
+    //(I - Pᵀ) π = 0, with Σ π = 1
+    system.identity_minus();
+    system.push_row(vec![T::one(); n])?;
+    let mut rhs = vec![T::zero(); n];
+    rhs.push(T::one());
+
+    match system.solution_space(&rhs)? {
+        Some(space) if space.is_unique() => Ok(space.particular),
+        Some(space) => Err(anyhow!(
+            "the stationary distribution is not unique, as the chain has {} closed communicating classes",
+            space.dimension() + 1
+        )),
+        None => Err(anyhow!("the Markov chain has no stationary distribution")),
+    }
+}
+
+/// Returns the fundamental matrix N = (I - Q)⁻¹ of an absorbing Markov chain, where Q holds the transition probabilities among its transient states.
+/// N[i][j] is the expected number of visits to transient state j when starting in transient state i.
+///
+/// Returns an error if Q is not square, or if I - Q is singular, which is the case if absorption is not certain from some transient state;
+/// the error can then be downcast to a [SingularMatrixError](crate::matrix::inversion::SingularMatrixError) that names the state.
+pub fn fundamental_matrix<M>(transient: &M) -> Result<M>
+where
+    M: Clone + IdentityMinus + Inversion,
+{
+    let mut result = transient.clone();
+    result.identity_minus();
+    result.invert()
+}
+
+/// Returns the matrix B = N R of an absorbing Markov chain, where N is the [fundamental_matrix] of Q, and R holds the transition probabilities from transient to absorbing states.
+/// B[i][j] is the probability of being absorbed in absorbing state j when starting in transient state i.
+///
+/// Returns an error if the fundamental matrix does not exist, or if R does not have a row for each transient state.
+pub fn absorption_probabilities<M>(transient: &M, absorbing: &M) -> Result<M>
+where
+    M: Clone + IdentityMinus + Inversion,
+    for<'a> &'a M: Mul<&'a M, Output = Result<M>>,
+{
+    &fundamental_matrix(transient)? * absorbing
+}
+
+/// Returns the vector t = N 1 of an absorbing Markov chain, where N is the [fundamental_matrix] of Q.
+/// t[i] is the expected number of steps before absorption when starting in transient state i.
+///
+/// Returns an error if the fundamental matrix does not exist.
+pub fn expected_steps_to_absorption<T, M>(transient: &M) -> Result<Vec<T>>
+where
+    T: Clone + One,
+    M: EbiMatrix<T> + Inversion,
+    for<'a> &'a M: Mul<&'a Vec<T>, Output = Result<Vec<T>>>,
+{
+    let fundamental = fundamental_matrix(transient)?;
+    &fundamental * &vec![T::one(); fundamental.number_of_columns()]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        fraction::{fraction_exact::FractionExact, fraction_f64::FractionF64},
+        matrix::{
+            fraction_matrix_exact::FractionMatrixExact,
+            fraction_matrix_f64::FractionMatrixF64,
+            markov::{
+                absorption_probabilities, expected_steps_to_absorption, fundamental_matrix,
+                stationary_distribution,
+            },
+        },
+    };
+
+    #[test]
+    fn stationary() {
+        let p: FractionMatrixExact = vec![
+            vec![(1, 2).into(), (1, 2).into(), 0.into()],
+            vec![(1, 4).into(), (1, 2).into(), (1, 4).into()],
+            vec![0.into(), (1, 3).into(), (2, 3).into()],
+        ]
+        .try_into()
+        .unwrap();
+        assert_eq!(
+            stationary_distribution(&p).unwrap(),
+            vec![
+                FractionExact::from((2, 9)),
+                FractionExact::from((4, 9)),
+                FractionExact::from((1, 3))
+            ]
+        );
+
+        let p: FractionMatrixF64 =
+            vec![vec![0.5.into(), 0.5.into()], vec![0.25.into(), 0.75.into()]]
+                .try_into()
+                .unwrap();
+        assert_eq!(
+            stationary_distribution(&p).unwrap(),
+            vec![FractionF64::from((1, 3)), FractionF64::from((2, 3))]
+        );
+
+        //two absorbing states
+        let p: FractionMatrixExact = vec![
+            vec![1.into(), 0.into(), 0.into()],
+            vec![(1, 2).into(), 0.into(), (1, 2).into()],
+            vec![0.into(), 0.into(), 1.into()],
+        ]
+        .try_into()
+        .unwrap();
+        assert!(stationary_distribution(&p).is_err());
+
+        let p: FractionMatrixExact =
+            vec![vec![(1, 2).into(), (1, 3).into()], vec![0.into(), 1.into()]]
+                .try_into()
+                .unwrap();
+        assert!(stationary_distribution(&p).is_err());
+
+        let p: FractionMatrixExact = vec![
+            vec![(3, 2).into(), (-1, 2).into()],
+            vec![0.into(), 1.into()],
+        ]
+        .try_into()
+        .unwrap();
+        assert_eq!(
+            stationary_distribution(&p).unwrap_err().to_string(),
+            "a transition matrix cannot have negative values, but the value at (0, 1) is negative"
+        );
+    }
+
+    #[test]
+    fn absorption() {
+        //gambler's ruin with states 0 to 3, of which 0 and 3 are absorbing
+        let q: FractionMatrixExact =
+            vec![vec![0.into(), (1, 2).into()], vec![(1, 2).into(), 0.into()]]
+                .try_into()
+                .unwrap();
+        let r: FractionMatrixExact =
+            vec![vec![(1, 2).into(), 0.into()], vec![0.into(), (1, 2).into()]]
+                .try_into()
+                .unwrap();
+
+        let n: FractionMatrixExact = vec![
+            vec![(4, 3).into(), (2, 3).into()],
+            vec![(2, 3).into(), (4, 3).into()],
+        ]
+        .try_into()
+        .unwrap();
+        assert_eq!(fundamental_matrix(&q).unwrap(), n);
+
+        let b: FractionMatrixExact = vec![
+            vec![(2, 3).into(), (1, 3).into()],
+            vec![(1, 3).into(), (2, 3).into()],
+        ]
+        .try_into()
+        .unwrap();
+        assert_eq!(absorption_probabilities(&q, &r).unwrap(), b);
+        assert_eq!(
+            expected_steps_to_absorption(&q).unwrap(),
+            vec![FractionExact::from(2); 2]
+        );
+
+        let q: FractionMatrixF64 = vec![vec![(1, 2).into()]].try_into().unwrap();
+        assert_eq!(
+            expected_steps_to_absorption(&q).unwrap(),
+            vec![FractionF64::from(2)]
+        );
+
+        //the second state never leaves
+        let q: FractionMatrixExact = vec![vec![(1, 2).into(), 0.into()], vec![0.into(), 1.into()]]
+            .try_into()
+            .unwrap();
+        assert!(fundamental_matrix(&q).is_err());
+    }
+}
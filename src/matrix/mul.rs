@@ -1,59 +1,100 @@
 use anyhow::{Result, anyhow};
 use itertools::iproduct;
-use malachite::rational::Rational;
-use std::ops::Mul;
+use malachite::{Integer, Natural, base::num::basic::traits::Zero as MZero, rational::Rational};
+use std::{borrow::Borrow, ops::Mul};
 
 use crate::{
-    EbiMatrix, MaybeExact, Zero,
+    EbiMatrix, MaybeExact, MulBatch, Zero,
+    events::debug_event,
     fraction::{
         fraction_enum::FractionEnum, fraction_exact::FractionExact, fraction_f64::FractionF64,
+        gcd::over_common_denominator,
     },
     matrix::{
         fraction_matrix_enum::FractionMatrixEnum, fraction_matrix_exact::FractionMatrixExact,
         fraction_matrix_f64::FractionMatrixF64,
     },
+    size_guard::{check_exact_bytes, exact_bytes},
 };
 
-macro_rules! mul_mat_mat {
-    ($t:ident, $u:ident, $v:ident) => {
-        impl Mul for &$t {
-            type Output = Result<$t>;
+/// An operand of a matrix product, of which the value at (row, column) is `values[position(row, column)]`.
+/// Lets matrices and borrowed windows of matrices share the multiplication code.
+pub(crate) struct Operand<'a, V, P: Fn(usize, usize) -> usize> {
+    pub(crate) values: &'a [V],
+    pub(crate) number_of_rows: usize,
+    pub(crate) number_of_columns: usize,
+    pub(crate) position: P,
+}
 
-            fn mul(self, rhs: Self) -> Self::Output {
-                if self.number_of_columns() != rhs.number_of_rows() {
-                    return Err(anyhow!(
-                        "cannot multiply matrix of size {}x{} with a matrix of size {}x{}",
-                        self.number_of_rows(),
-                        self.number_of_columns(),
-                        rhs.number_of_rows(),
-                        rhs.number_of_columns()
-                    ));
-                }
+/// Returns an operand of which the values are stored row by row.
+pub(crate) fn row_major<V>(
+    values: &[V],
+    number_of_rows: usize,
+    number_of_columns: usize,
+) -> Operand<'_, V, impl Fn(usize, usize) -> usize> {
+    Operand {
+        values,
+        number_of_rows,
+        number_of_columns,
+        position: move |row, column| row * number_of_columns + column,
+    }
+}
 
-                let result_rows = self.number_of_rows();
-                let result_columns = rhs.number_of_columns();
-                let mut result = vec![$v::zero(); result_rows * result_columns];
+impl<V, P: Fn(usize, usize) -> usize> Operand<'_, V, P> {
+    fn value(&self, row: usize, column: usize) -> &V {
+        &self.values[(self.position)(row, column)]
+    }
+}
 
-                iproduct!(0..result_rows, 0..result_columns).for_each(|(row, column)| {
-                    for k in 0..self.number_of_columns() {
-                        result[row * result_columns + column] += &self.values
-                            [row * self.number_of_columns() + k]
-                            * &rhs.values[k * rhs.number_of_columns() + column];
-                    }
-                });
+fn check_product_size<V, P, Q>(left: &Operand<V, P>, right: &Operand<V, Q>) -> Result<()>
+where
+    P: Fn(usize, usize) -> usize,
+    Q: Fn(usize, usize) -> usize,
+{
+    if left.number_of_columns != right.number_of_rows {
+        return Err(anyhow!(
+            "cannot multiply matrix of size {}x{} with a matrix of size {}x{}",
+            left.number_of_rows,
+            left.number_of_columns,
+            right.number_of_rows,
+            right.number_of_columns
+        ));
+    }
+    Ok(())
+}
 
-                Ok($t {
-                    values: result,
-                    number_of_columns: result_columns,
-                    number_of_rows: result_rows,
-                })
-            }
+/// Returns the values of the product, row by row.
+pub(crate) fn product_f64<P, Q>(left: &Operand<f64, P>, right: &Operand<f64, Q>) -> Result<Vec<f64>>
+where
+    P: Fn(usize, usize) -> usize,
+    Q: Fn(usize, usize) -> usize,
+{
+    check_product_size(left, right)?;
+    let result_columns = right.number_of_columns;
+    let mut result = vec![0.0; left.number_of_rows * result_columns];
+    iproduct!(0..left.number_of_rows, 0..result_columns).for_each(|(row, column)| {
+        for k in 0..left.number_of_columns {
+            result[row * result_columns + column] += left.value(row, k) * right.value(k, column);
         }
-    };
+    });
+    Ok(result)
+}
+
+impl Mul for &FractionMatrixF64 {
+    type Output = Result<FractionMatrixF64>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let result = product_f64(
+            &row_major(&self.values, self.number_of_rows, self.number_of_columns),
+            &row_major(&rhs.values, rhs.number_of_rows, rhs.number_of_columns),
+        )?;
+        Ok(self.with_values(result, self.number_of_rows, rhs.number_of_columns))
+    }
 }
 
+/// `$check` is applied to the matrix, the vector and the result.
 macro_rules! mul_vec_mat {
-    ($t:ident, $u:ident, $v:ident) => {
+    ($t:ident, $u:ident, $v:ident, $check:expr) => {
         impl Mul<&$t> for &Vec<$u> {
             type Output = Result<Vec<$u>>;
 
@@ -74,14 +115,16 @@ macro_rules! mul_vec_mat {
                             &rhs.values[row * rhs.number_of_columns() + column] * &self[row].0;
                     }
                 }
+                $check(&rhs.values, self, &result)?;
                 Ok(result.into_iter().map(|f| $u(f)).collect())
             }
         }
     };
 }
 
+/// `$check` is applied to the matrix, the vector and the result.
 macro_rules! mul_mat_vec {
-    ($t:ident, $u:ident, $v:ident) => {
+    ($t:ident, $u:ident, $v:ident, $check:expr) => {
         impl Mul<&Vec<$u>> for &$t {
             type Output = Result<Vec<$u>>;
 
@@ -102,6 +145,7 @@ macro_rules! mul_mat_vec {
                             &self.values[row * self.number_of_columns() + column] * &rhs[column].0;
                     }
                 }
+                $check(&self.values, rhs, &result)?;
                 Ok(result.into_iter().map(|f| $u(f)).collect())
             }
         }
@@ -110,18 +154,347 @@ macro_rules! mul_mat_vec {
 
 // ===================== f64 =====================
 
-mul_mat_mat!(FractionMatrixF64, FractionF64, f64);
-mul_vec_mat!(FractionMatrixF64, FractionF64, f64);
-mul_mat_vec!(FractionMatrixF64, FractionF64, f64);
+/// Approximate values have a fixed size, thus there is nothing to guard.
+fn check_f64(_: &[f64], _: &[FractionF64], _: &[f64]) -> Result<()> {
+    Ok(())
+}
+
+mul_vec_mat!(FractionMatrixF64, FractionF64, f64, check_f64);
+mul_mat_vec!(FractionMatrixF64, FractionF64, f64, check_f64);
+
+fn check_batch_vector(number_of_rows: usize, number_of_columns: usize, len: usize) -> Result<()> {
+    if number_of_columns != len {
+        return Err(anyhow!(
+            "cannot multiply matrix of size {}x{} with a vector of size {}",
+            number_of_rows,
+            number_of_columns,
+            len,
+        ));
+    }
+    Ok(())
+}
+
+/// Multiplies the matrix with each of the vectors, traversing the matrix once, with `value` giving access to the float of a vector element.
+fn mul_batch_f64<T>(
+    matrix: &FractionMatrixF64,
+    vectors: &[Vec<T>],
+    value: impl Fn(&T) -> Result<f64>,
+) -> Result<Vec<Vec<f64>>> {
+    let vectors = vectors
+        .iter()
+        .map(|vector| {
+            check_batch_vector(
+                matrix.number_of_rows,
+                matrix.number_of_columns,
+                vector.len(),
+            )?;
+            vector.iter().map(&value).collect::<Result<Vec<_>>>()
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut result = vec![Vec::with_capacity(matrix.number_of_rows); vectors.len()];
+    for row in matrix
+        .values
+        .chunks(matrix.number_of_columns.max(1))
+        .take(matrix.number_of_rows)
+    {
+        for (vector, result) in vectors.iter().zip(result.iter_mut()) {
+            result.push(row.iter().zip(vector.iter()).map(|(a, b)| a * b).sum());
+        }
+    }
+    //rows without columns
+    for result in result.iter_mut() {
+        result.resize(matrix.number_of_rows, 0.0);
+    }
+    Ok(result)
+}
+
+impl MulBatch<FractionF64> for FractionMatrixF64 {
+    fn mul_batch(&self, vectors: &[Vec<FractionF64>]) -> Result<Vec<Vec<FractionF64>>> {
+        Ok(mul_batch_f64(self, vectors, |f| Ok(f.0))?
+            .into_iter()
+            .map(|vector| vector.into_iter().map(FractionF64).collect())
+            .collect())
+    }
+}
+
+impl FractionMatrixF64 {
+    /// Multiplies two matrices, and returns the product together with a matrix of rigorous bounds on the absolute floating-point error of each cell.
+    ///
+    /// The bounds follow the standard forward-error analysis of inner products, |fl(AB) - AB| <= γ(n) |A||B| with γ(n) = nu / (1 - nu),
+    /// where n is the inner dimension and u the unit roundoff. They are inflated to account for the rounding in their own computation and for underflow.
+    pub fn mul_with_error_bound(&self, rhs: &Self) -> Result<(Self, Self)> {
+        let product = (self * rhs)?;
+
+        let n = self.number_of_columns();
+        let unit_roundoff = f64::EPSILON / 2.0;
+        //γ(2n + 2) covers both the product and the evaluation of |A||B|
+        let nu = (2 * n + 2) as f64 * unit_roundoff;
+        if nu >= 1.0 {
+            return Err(anyhow!(
+                "inner dimension {} is too large to bound the rounding error",
+                n
+            ));
+        }
+        let gamma = (nu / (1.0 - nu)).next_up();
+        //each product may underflow by at most half the smallest subnormal
+        let underflow = n as f64 * f64::from_bits(1);
+
+        let mut bounds = product.with_values(
+            vec![0f64; product.values.len()],
+            product.number_of_rows,
+            product.number_of_columns,
+        );
+        iproduct!(0..product.number_of_rows, 0..product.number_of_columns).for_each(
+            |(row, column)| {
+                let mut absolute = 0f64;
+                for k in 0..n {
+                    absolute +=
+                        (self.values[self.index(row, k)] * rhs.values[rhs.index(k, column)]).abs();
+                }
+                let idx = bounds.index(row, column);
+                bounds.values[idx] = (gamma * absolute).next_up() + underflow;
+            },
+        );
+
+        Ok((product, bounds))
+    }
+}
 
 // ===================== exact =====================
 
-mul_mat_mat!(FractionMatrixExact, FractionExact, Rational);
-mul_vec_mat!(FractionMatrixExact, FractionExact, Rational);
-mul_mat_vec!(FractionMatrixExact, FractionExact, Rational);
+/// Scales each of the given lines of values to integer numerators over a common denominator: the lcm of their denominators.
+/// `lines` yields, per line, the positions of its values in `values`.
+fn common_denominators<R: Borrow<Rational>>(
+    values: &[R],
+    lines: impl Iterator<Item = impl Iterator<Item = usize> + Clone>,
+) -> (Vec<Vec<Integer>>, Vec<Natural>) {
+    lines
+        .map(|line| over_common_denominator(line.map(|i| values[i].borrow())))
+        .unzip()
+}
+
+/// Above this number of rows, columns or inner dimension, the integer dot products of an exact product are computed in tiles.
+pub const BLOCKED_MULTIPLICATION_THRESHOLD: usize = 64;
+
+const MULTIPLICATION_BLOCK_SIZE: usize = 32;
+
+/// Computes the dot product of each row with each column, row by row.
+/// With a block size, the rows, columns and inner dimension are split into tiles, such that the numerators of a tile of rows and a tile of columns stay in cache while they are combined.
+/// Each sum is an integer, so no intermediate reduction is needed: each cell is reduced once, when it is divided by its denominator.
+fn integer_products(
+    rows: &[Vec<Integer>],
+    columns: &[Vec<Integer>],
+    block_size: Option<usize>,
+) -> Vec<Integer> {
+    let inner = rows
+        .first()
+        .or(columns.first())
+        .map_or(0, |line| line.len());
+    let block_size = block_size.unwrap_or(usize::MAX).max(1);
+    let mut sums = vec![Integer::ZERO; rows.len() * columns.len()];
+    for k_start in (0..inner).step_by(block_size) {
+        let k_end = inner.min(k_start.saturating_add(block_size));
+        for row_start in (0..rows.len()).step_by(block_size) {
+            for column_start in (0..columns.len()).step_by(block_size) {
+                for (r, row) in rows.iter().enumerate().skip(row_start).take(block_size) {
+                    let row = &row[k_start..k_end];
+                    for (c, column) in columns
+                        .iter()
+                        .enumerate()
+                        .skip(column_start)
+                        .take(block_size)
+                    {
+                        let sum = &mut sums[r * columns.len() + c];
+                        for (a, b) in row.iter().zip(column[k_start..k_end].iter()) {
+                            if *a != 0u32 && *b != 0u32 {
+                                *sum += a * b;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+    sums
+}
+
+/// Returns the values of the product, row by row.
+///
+/// Each row of the left matrix and each column of the right matrix is brought to a common denominator once.
+/// Each cell of the product is then an integer dot product, divided by the product of two denominators and reduced once,
+/// rather than a sum of rationals that is reduced after every term.
+/// Large products are computed in tiles; see [BLOCKED_MULTIPLICATION_THRESHOLD].
+pub(crate) fn product_exact<P, Q>(
+    left: &Operand<Rational, P>,
+    right: &Operand<Rational, Q>,
+) -> Result<Vec<Rational>>
+where
+    P: Fn(usize, usize) -> usize,
+    Q: Fn(usize, usize) -> usize,
+{
+    check_product_size(left, right)?;
+
+    let inner = left.number_of_columns;
+    let (row_numerators, row_denominators) = common_denominators(
+        left.values,
+        (0..left.number_of_rows).map(|row| (0..inner).map(move |k| (left.position)(row, k))),
+    );
+    let (column_numerators, column_denominators) = common_denominators(
+        right.values,
+        (0..right.number_of_columns)
+            .map(|column| (0..inner).map(move |k| (right.position)(k, column))),
+    );
+    let operand_bytes = || {
+        exact_bytes(left.values)
+            + exact_bytes(right.values)
+            + row_numerators.iter().map(exact_bytes).sum::<usize>()
+            + column_numerators.iter().map(exact_bytes).sum::<usize>()
+            + exact_bytes(&row_denominators)
+            + exact_bytes(&column_denominators)
+    };
+    check_exact_bytes(operand_bytes)?;
+
+    let result_rows = left.number_of_rows;
+    let result_columns = right.number_of_columns;
+    let block_size =
+        if result_rows.max(result_columns).max(inner) > BLOCKED_MULTIPLICATION_THRESHOLD {
+            Some(MULTIPLICATION_BLOCK_SIZE)
+        } else {
+            None
+        };
+    debug_event!(
+        rows = result_rows,
+        columns = result_columns,
+        inner,
+        blocked = block_size.is_some(),
+        denominator_bits = row_denominators
+            .iter()
+            .chain(column_denominators.iter())
+            .map(malachite::base::num::logic::traits::SignificantBits::significant_bits)
+            .max()
+            .unwrap_or(0),
+        "exact matrix product"
+    );
+    let sums = integer_products(&row_numerators, &column_numerators, block_size);
+    check_exact_bytes(|| operand_bytes() + exact_bytes(&sums))?;
+
+    let mut result = Vec::with_capacity(result_rows * result_columns);
+    for (row_sums, row_denominator) in sums
+        .chunks(result_columns.max(1))
+        .zip(row_denominators.iter())
+    {
+        for (sum, column_denominator) in row_sums.iter().zip(column_denominators.iter()) {
+            result.push(Rational::from_integers_ref(
+                sum,
+                &Integer::from(row_denominator * column_denominator),
+            ));
+        }
+        check_exact_bytes(|| operand_bytes() + exact_bytes(&sums) + exact_bytes(&result))?;
+    }
+    Ok(result)
+}
+
+impl Mul for &FractionMatrixExact {
+    type Output = Result<FractionMatrixExact>;
+
+    /// See [product_exact].
+    fn mul(self, rhs: Self) -> Self::Output {
+        let result = product_exact(
+            &row_major(&self.values, self.number_of_rows, self.number_of_columns),
+            &row_major(&rhs.values, rhs.number_of_rows, rhs.number_of_columns),
+        )?;
+        Ok(self.with_values(result, self.number_of_rows, rhs.number_of_columns))
+    }
+}
+
+/// Multiplies the matrix with each of the vectors, with `value` giving access to the rational of a vector element.
+/// Each row of the matrix is brought to a common denominator once for all vectors, and each vector once for all rows.
+fn mul_batch_exact<T>(
+    matrix: &FractionMatrixExact,
+    vectors: &[Vec<T>],
+    value: impl Fn(&T) -> Result<&Rational>,
+) -> Result<Vec<Vec<Rational>>> {
+    let inner = matrix.number_of_columns;
+    let (row_numerators, row_denominators) = common_denominators(
+        &matrix.values,
+        (0..matrix.number_of_rows).map(|row| (0..inner).map(move |k| row * inner + k)),
+    );
+
+    let mut vector_numerators = Vec::with_capacity(vectors.len());
+    let mut vector_denominators = Vec::with_capacity(vectors.len());
+    for vector in vectors {
+        check_batch_vector(matrix.number_of_rows, inner, vector.len())?;
+        let values = vector.iter().map(&value).collect::<Result<Vec<_>>>()?;
+        let (mut numerators, mut denominators) =
+            common_denominators(&values, std::iter::once(0..inner));
+        vector_numerators.push(numerators.pop().unwrap());
+        vector_denominators.push(denominators.pop().unwrap());
+    }
+    let operand_bytes = || {
+        exact_bytes(&matrix.values)
+            + row_numerators.iter().map(exact_bytes).sum::<usize>()
+            + exact_bytes(&row_denominators)
+            + vector_numerators.iter().map(exact_bytes).sum::<usize>()
+            + exact_bytes(&vector_denominators)
+    };
+    check_exact_bytes(operand_bytes)?;
+
+    let mut result = vec![Vec::with_capacity(matrix.number_of_rows); vectors.len()];
+    for (row, row_denominator) in row_numerators.iter().zip(row_denominators.iter()) {
+        for ((vector, vector_denominator), result) in vector_numerators
+            .iter()
+            .zip(vector_denominators.iter())
+            .zip(result.iter_mut())
+        {
+            let mut sum = Integer::ZERO;
+            for (a, b) in row.iter().zip(vector.iter()) {
+                if *a != 0u32 && *b != 0u32 {
+                    sum += a * b;
+                }
+            }
+            result.push(Rational::from_integers(
+                sum,
+                Integer::from(row_denominator * vector_denominator),
+            ));
+        }
+        check_exact_bytes(|| operand_bytes() + result.iter().map(exact_bytes).sum::<usize>())?;
+    }
+    Ok(result)
+}
+
+impl MulBatch<FractionExact> for FractionMatrixExact {
+    fn mul_batch(&self, vectors: &[Vec<FractionExact>]) -> Result<Vec<Vec<FractionExact>>> {
+        Ok(mul_batch_exact(self, vectors, |f| Ok(&f.0))?
+            .into_iter()
+            .map(|vector| vector.into_iter().map(FractionExact).collect())
+            .collect())
+    }
+}
+
+fn check_exact(matrix: &[Rational], vector: &[FractionExact], result: &[Rational]) -> Result<()> {
+    check_exact_bytes(|| {
+        exact_bytes(matrix) + exact_bytes(vector.iter().map(|f| &f.0)) + exact_bytes(result)
+    })
+}
+
+mul_vec_mat!(FractionMatrixExact, FractionExact, Rational, check_exact);
+mul_mat_vec!(FractionMatrixExact, FractionExact, Rational, check_exact);
 
 // ===================== enum =====================
 
+fn check_exact_enum(
+    matrix: &[Rational],
+    vector: &[FractionEnum],
+    result: &[Rational],
+) -> Result<()> {
+    check_exact_bytes(|| {
+        exact_bytes(matrix)
+            + exact_bytes(vector.iter().filter_map(|f| f.exact_ref().ok()))
+            + exact_bytes(result)
+    })
+}
+
 impl Mul for &FractionMatrixEnum {
     type Output = Result<FractionMatrixEnum>;
 
@@ -173,6 +546,7 @@ impl Mul<&Vec<FractionEnum>> for &FractionMatrixEnum {
                             * rhs[column].exact_ref()?;
                     }
                 }
+                check_exact_enum(&m.values, rhs, &result)?;
                 Ok(result.into_iter().map(|f| FractionEnum::Exact(f)).collect())
             }
             FractionMatrixEnum::CannotCombineExactAndApprox => {
@@ -182,6 +556,26 @@ impl Mul<&Vec<FractionEnum>> for &FractionMatrixEnum {
     }
 }
 
+impl MulBatch<FractionEnum> for FractionMatrixEnum {
+    fn mul_batch(&self, vectors: &[Vec<FractionEnum>]) -> Result<Vec<Vec<FractionEnum>>> {
+        match self {
+            FractionMatrixEnum::Approx(m) => {
+                Ok(mul_batch_f64(m, vectors, |f| f.approx_ref().copied())?
+                    .into_iter()
+                    .map(|vector| vector.into_iter().map(FractionEnum::Approx).collect())
+                    .collect())
+            }
+            FractionMatrixEnum::Exact(m) => Ok(mul_batch_exact(m, vectors, |f| f.exact_ref())?
+                .into_iter()
+                .map(|vector| vector.into_iter().map(FractionEnum::Exact).collect())
+                .collect()),
+            FractionMatrixEnum::CannotCombineExactAndApprox => {
+                Err(anyhow!("cannot combine exact and approximate arithmetic"))
+            }
+        }
+    }
+}
+
 impl Mul<&FractionMatrixEnum> for &Vec<FractionEnum> {
     type Output = Result<Vec<FractionEnum>>;
 
@@ -217,6 +611,7 @@ impl Mul<&FractionMatrixEnum> for &Vec<FractionEnum> {
                             * self[row].exact_ref()?;
                     }
                 }
+                check_exact_enum(&m.values, self, &result)?;
                 Ok(result.into_iter().map(|f| FractionEnum::Exact(f)).collect())
             }
             FractionMatrixEnum::CannotCombineExactAndApprox => {
@@ -229,19 +624,21 @@ impl Mul<&FractionMatrixEnum> for &Vec<FractionEnum> {
 #[cfg(test)]
 mod tests {
 
-    use crate::{EbiMatrix, MaybeExact, fraction::fraction::Fraction};
+    use crate::{EbiMatrix, MulBatch, fraction::fraction::Fraction};
     use crate::{
         f,
         fraction::{fraction_exact::FractionExact, fraction_f64::FractionF64},
         matrix::{
-            fraction_matrix::FractionMatrix, fraction_matrix_exact::FractionMatrixExact,
+            fraction_matrix::FractionMatrix,
+            fraction_matrix_exact::FractionMatrixExact,
             fraction_matrix_f64::FractionMatrixF64,
+            mul::{BLOCKED_MULTIPLICATION_THRESHOLD, integer_products},
         },
     };
     use anyhow::Result;
+    use malachite::{Integer, rational::Rational};
     use rand::Rng;
     use serial_test::serial;
-    use std::time::Instant;
 
     #[test]
     fn fraction_matrix_mul() {
@@ -386,82 +783,52 @@ mod tests {
         assert_eq!(prod.to_vec(), m3);
     }
 
-    // #[test]
-    fn _bench_mul() {
-        let repeat = 5;
-        let size = 100_usize;
+    #[test]
+    fn mul_with_error_bound() {
+        let m1: FractionMatrixF64 = vec![
+            vec![FractionF64::from(0.1), FractionF64::from(0.2)],
+            vec![FractionF64::from(1e10), FractionF64::from(-1e10)],
+        ]
+        .try_into()
+        .unwrap();
+        let m2: FractionMatrixF64 = vec![
+            vec![FractionF64::from(0.3), FractionF64::from(1.0)],
+            vec![FractionF64::from(0.7), FractionF64::from(1.0 / 3.0)],
+        ]
+        .try_into()
+        .unwrap();
 
-        let mut rng = rand::rng();
-        let sqrt = 1000_u64;
-        let numerators = vec![rng.random_range(0..sqrt); size * size];
-        let denominators = vec![rng.random_range(0..sqrt); size * size];
+        let (product, bounds) = m1.mul_with_error_bound(&m2).unwrap();
 
-        let matrices_f64: Vec<FractionMatrixF64> = (0..repeat)
+        //compare against the exact product of the floats
+        let e1: FractionMatrixExact = m1
+            .to_vec()
             .into_iter()
-            .map(|i| FractionMatrixF64 {
-                number_of_columns: size,
-                number_of_rows: size,
-                values: numerators
-                    .iter()
-                    .zip(denominators.iter())
-                    .enumerate()
-                    .map(|(x, (nom, den))| {
-                        if x == i {
-                            FractionF64::from((*nom as i64, den + 1)).0
-                        } else {
-                            FractionF64::from((*nom as i64, *den)).0
-                        }
-                    })
-                    .collect::<Vec<_>>(),
+            .map(|row| {
+                row.into_iter()
+                    .map(|f| FractionExact(Rational::try_from(f.0).unwrap()))
+                    .collect()
             })
-            .collect();
-
-        let matrices_exact: Vec<FractionMatrixExact> = (0..repeat)
+            .collect::<Vec<Vec<_>>>()
+            .try_into()
+            .unwrap();
+        let e2: FractionMatrixExact = m2
+            .to_vec()
             .into_iter()
-            .map(|i| FractionMatrixExact {
-                number_of_columns: size,
-                number_of_rows: size,
-                values: numerators
-                    .iter()
-                    .zip(denominators.iter())
-                    .enumerate()
-                    .map(|(x, (nom, den))| {
-                        if x == i {
-                            FractionExact::from((*nom as i64, den + 1)).0
-                        } else {
-                            FractionExact::from((*nom as i64, *den)).0
-                        }
-                    })
-                    .collect(),
+            .map(|row| {
+                row.into_iter()
+                    .map(|f| FractionExact(Rational::try_from(f.0).unwrap()))
+                    .collect()
             })
-            .collect();
-
-        //exact
-        {
-            let before = Instant::now();
-            for m in matrices_exact {
-                let m3 = (&m * &m).unwrap();
-
-                if !m3.is_exact() {
-                    panic!()
-                }
-            }
-
-            println!("exact:         {:.2?}", before.elapsed());
-        }
-
-        //f64
-        {
-            let before = Instant::now();
-            for m in matrices_f64 {
-                let m3 = (&m * &m).unwrap();
-
-                if m3.is_exact() {
-                    panic!()
-                }
-            }
+            .collect::<Vec<Vec<_>>>()
+            .try_into()
+            .unwrap();
+        let exact = (&e1 * &e2).unwrap();
 
-            println!("approx f64:    {:.2?}", before.elapsed());
+        for (i, value) in exact.values.iter().enumerate() {
+            let error = value - &Rational::try_from(product.values[i]).unwrap();
+            let bound = Rational::try_from(bounds.values[i]).unwrap();
+            assert!(-&bound <= error && error <= bound);
         }
     }
 
@@ -479,7 +846,7 @@ mod tests {
 
         let x = (&m * &v).unwrap();
 
-        let t = vec![24.into(), (-9).into(), (-23).into()];
+        let t: Vec<Fraction> = vec![24.into(), (-9).into(), (-23).into()];
 
         assert_eq!(x, t);
     }
@@ -493,7 +860,7 @@ mod tests {
 
         let v: Vec<Fraction> = vec![1.into(), 0.into()];
 
-        let answer_mv = vec![0.into(), 0.into()];
+        let answer_mv: Vec<Fraction> = vec![0.into(), 0.into()];
         let answer_vm: Vec<Fraction> = vec![0.into(), 1.into()];
 
         assert_eq!((&m * &v).unwrap(), answer_mv);
@@ -512,7 +879,7 @@ mod tests {
         let v: Vec<Fraction> = vec![0.into(), 1.into()];
         let v2: Vec<Fraction> = vec![0.into(), 1.into(), 2.into()];
 
-        let answer_mv = vec![5.into(), 5.into()];
+        let answer_mv: Vec<Fraction> = vec![5.into(), 5.into()];
         let answer_vm: Vec<Fraction> = vec![0.into(), 1.into(), 2.into()];
 
         assert_eq!((&m * &v2).unwrap(), answer_mv);
@@ -567,4 +934,134 @@ mod tests {
 
         assert_eq!((&f * &a).unwrap(), fa);
     }
+
+    #[test]
+    fn mul_exact_common_denominator() {
+        let mut rng = rand::rng();
+        let m1: FractionMatrixExact = (0..3)
+            .map(|_| {
+                (0..4)
+                    .map(|_| {
+                        FractionExact::from((rng.random_range(-20..20), rng.random_range(1..30)))
+                    })
+                    .collect()
+            })
+            .collect::<Vec<Vec<_>>>()
+            .try_into()
+            .unwrap();
+        let m2: FractionMatrixExact = (0..4)
+            .map(|_| {
+                (0..2)
+                    .map(|_| {
+                        FractionExact::from((rng.random_range(-20..20), rng.random_range(1..30)))
+                    })
+                    .collect()
+            })
+            .collect::<Vec<Vec<_>>>()
+            .try_into()
+            .unwrap();
+
+        let product = (&m1 * &m2).unwrap();
+        for row in 0..3 {
+            for column in 0..2 {
+                let mut sum = Rational::from(0);
+                for k in 0..4 {
+                    sum += &m1.values[m1.index(row, k)] * &m2.values[m2.index(k, column)];
+                }
+                assert_eq!(product.values[product.index(row, column)], sum);
+            }
+        }
+    }
+
+    #[test]
+    fn mul_batch() {
+        let mut rng = rand::rng();
+        let m: FractionMatrixExact = (0..3)
+            .map(|_| {
+                (0..4)
+                    .map(|_| {
+                        FractionExact::from((rng.random_range(-20..20), rng.random_range(1..30)))
+                    })
+                    .collect()
+            })
+            .collect::<Vec<Vec<_>>>()
+            .try_into()
+            .unwrap();
+        let vectors: Vec<Vec<FractionExact>> = (0..5)
+            .map(|_| {
+                (0..4)
+                    .map(|_| {
+                        FractionExact::from((rng.random_range(-20..20), rng.random_range(1..30)))
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let products = m.mul_batch(&vectors).unwrap();
+        assert_eq!(products.len(), 5);
+        for (vector, product) in vectors.iter().zip(products.iter()) {
+            assert_eq!(&(&m * vector).unwrap(), product);
+        }
+
+        let m: FractionMatrixF64 = vec![vec![1.into(), 2.into()], vec![3.into(), 4.into()]]
+            .try_into()
+            .unwrap();
+        assert_eq!(
+            m.mul_batch(&[vec![1.into(), 0.into()], vec![1.into(), 1.into()]])
+                .unwrap(),
+            vec![
+                vec![FractionF64::from(1), 3.into()],
+                vec![FractionF64::from(3), 7.into()]
+            ]
+        );
+        assert!(m.mul_batch(&[vec![1.into()]]).is_err());
+        assert!(m.mul_batch(&[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn mul_exact_blocked() {
+        let mut rng = rand::rng();
+        let mut random = |rows: usize, columns: usize| -> FractionMatrixExact {
+            (0..rows)
+                .map(|_| {
+                    (0..columns)
+                        .map(|_| {
+                            FractionExact::from((
+                                rng.random_range(-20..20),
+                                rng.random_range(1..30),
+                            ))
+                        })
+                        .collect()
+                })
+                .collect::<Vec<Vec<_>>>()
+                .try_into()
+                .unwrap()
+        };
+
+        //above the threshold
+        let m1 = random(BLOCKED_MULTIPLICATION_THRESHOLD + 3, 5);
+        let m2 = random(5, BLOCKED_MULTIPLICATION_THRESHOLD + 1);
+        let product = (&m1 * &m2).unwrap();
+        for row in 0..m1.number_of_rows() {
+            for column in 0..m2.number_of_columns() {
+                let mut sum = Rational::from(0);
+                for k in 0..5 {
+                    sum += &m1.values[m1.index(row, k)] * &m2.values[m2.index(k, column)];
+                }
+                assert_eq!(product.values[product.index(row, column)], sum);
+            }
+        }
+
+        //tiles that do not divide the dimensions
+        let rows = (0..7)
+            .map(|r| (0..5).map(|k| Integer::from(r * 5 + k - 17)).collect())
+            .collect::<Vec<Vec<_>>>();
+        let columns = (0..6)
+            .map(|c| (0..5).map(|k| Integer::from(c - k * 3)).collect())
+            .collect::<Vec<Vec<_>>>();
+        assert_eq!(
+            integer_products(&rows, &columns, Some(2)),
+            integer_products(&rows, &columns, None)
+        );
+    }
 }
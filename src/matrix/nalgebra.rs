@@ -0,0 +1,81 @@
+//! Conversions between approximate matrices and vectors and those of [nalgebra], for instance to use its eigen-solvers.
+//!
+//! Matrices of this crate are stored row by row and those of nalgebra column by column, so each conversion copies the values.
+
+use nalgebra::{DMatrix, DVector};
+
+use crate::{
+    fraction::{fraction::EPSILON, fraction_f64::FractionF64},
+    matrix::fraction_matrix_f64::FractionMatrixF64,
+};
+
+impl From<&FractionMatrixF64> for DMatrix<f64> {
+    fn from(value: &FractionMatrixF64) -> Self {
+        DMatrix::from_row_slice(value.number_of_rows, value.number_of_columns, &value.values)
+    }
+}
+
+impl From<FractionMatrixF64> for DMatrix<f64> {
+    fn from(value: FractionMatrixF64) -> Self {
+        Self::from(&value)
+    }
+}
+
+/// The tolerance of the matrix is [EPSILON].
+impl From<&DMatrix<f64>> for FractionMatrixF64 {
+    fn from(value: &DMatrix<f64>) -> Self {
+        Self {
+            values: value.transpose().as_slice().to_vec(),
+            number_of_rows: value.nrows(),
+            number_of_columns: value.ncols(),
+            epsilon: EPSILON,
+        }
+    }
+}
+
+impl From<DMatrix<f64>> for FractionMatrixF64 {
+    fn from(value: DMatrix<f64>) -> Self {
+        Self::from(&value)
+    }
+}
+
+pub fn to_dvector(values: &[FractionF64]) -> DVector<f64> {
+    DVector::from_iterator(values.len(), values.iter().map(|value| value.0))
+}
+
+pub fn from_dvector(vector: &DVector<f64>) -> Vec<FractionF64> {
+    vector.iter().map(|value| FractionF64(*value)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::{DMatrix, DVector};
+
+    use crate::{
+        fraction::fraction_f64::FractionF64,
+        matrix::{
+            fraction_matrix_f64::FractionMatrixF64,
+            nalgebra::{from_dvector, to_dvector},
+        },
+    };
+
+    #[test]
+    fn nalgebra() {
+        let m: FractionMatrixF64 = vec![
+            vec![1.0.into(), 2.0.into(), 3.0.into()],
+            vec![4.0.into(), 5.0.into(), 6.0.into()],
+        ]
+        .try_into()
+        .unwrap();
+        let n = DMatrix::from(&m);
+        assert_eq!(
+            n,
+            DMatrix::from_row_slice(2, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0])
+        );
+        assert_eq!(FractionMatrixF64::from(n), m);
+
+        let v: Vec<FractionF64> = vec![0.5.into(), 0.25.into()];
+        assert_eq!(to_dvector(&v), DVector::from_vec(vec![0.5, 0.25]));
+        assert_eq!(from_dvector(&to_dvector(&v)), v);
+    }
+}
@@ -0,0 +1,134 @@
+use malachite::base::num::arithmetic::traits::{AbsAssign, NegAssign};
+use std::ops::Neg;
+
+use crate::matrix::{
+    fraction_matrix_enum::FractionMatrixEnum, fraction_matrix_exact::FractionMatrixExact,
+    fraction_matrix_f64::FractionMatrixF64,
+};
+
+impl Neg for FractionMatrixF64 {
+    type Output = Self;
+
+    fn neg(mut self) -> Self::Output {
+        self.values.iter_mut().for_each(|f| *f = -*f);
+        self
+    }
+}
+
+impl Neg for &FractionMatrixF64 {
+    type Output = FractionMatrixF64;
+
+    fn neg(self) -> Self::Output {
+        self.clone().neg()
+    }
+}
+
+/// Flips the sign of each value, without touching numerators and denominators.
+impl Neg for FractionMatrixExact {
+    type Output = Self;
+
+    fn neg(mut self) -> Self::Output {
+        self.values.iter_mut().for_each(|f| f.neg_assign());
+        self
+    }
+}
+
+impl Neg for &FractionMatrixExact {
+    type Output = FractionMatrixExact;
+
+    fn neg(self) -> Self::Output {
+        self.clone().neg()
+    }
+}
+
+impl Neg for FractionMatrixEnum {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        match self {
+            FractionMatrixEnum::Approx(m) => FractionMatrixEnum::Approx(m.neg()),
+            FractionMatrixEnum::Exact(m) => FractionMatrixEnum::Exact(m.neg()),
+            FractionMatrixEnum::CannotCombineExactAndApprox => {
+                FractionMatrixEnum::CannotCombineExactAndApprox
+            }
+        }
+    }
+}
+
+impl Neg for &FractionMatrixEnum {
+    type Output = FractionMatrixEnum;
+
+    fn neg(self) -> Self::Output {
+        self.clone().neg()
+    }
+}
+
+impl FractionMatrixF64 {
+    /// Returns the matrix of the absolute values.
+    pub fn abs(mut self) -> Self {
+        self.values.iter_mut().for_each(|f| *f = f.abs());
+        self
+    }
+}
+
+impl FractionMatrixExact {
+    /// Returns the matrix of the absolute values, by clearing the sign of each value.
+    pub fn abs(mut self) -> Self {
+        self.values.iter_mut().for_each(|f| f.abs_assign());
+        self
+    }
+}
+
+impl FractionMatrixEnum {
+    /// Returns the matrix of the absolute values.
+    pub fn abs(self) -> Self {
+        match self {
+            FractionMatrixEnum::Approx(m) => FractionMatrixEnum::Approx(m.abs()),
+            FractionMatrixEnum::Exact(m) => FractionMatrixEnum::Exact(m.abs()),
+            FractionMatrixEnum::CannotCombineExactAndApprox => {
+                FractionMatrixEnum::CannotCombineExactAndApprox
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::{
+        fraction_matrix_enum::FractionMatrixEnum, fraction_matrix_exact::FractionMatrixExact,
+        fraction_matrix_f64::FractionMatrixF64,
+    };
+
+    #[test]
+    fn neg_abs() {
+        let m: FractionMatrixExact = vec![
+            vec![(1, 2).into(), (-1, 3).into()],
+            vec![0.into(), 4.into()],
+        ]
+        .try_into()
+        .unwrap();
+        let n: FractionMatrixExact = vec![
+            vec![(-1, 2).into(), (1, 3).into()],
+            vec![0.into(), (-4).into()],
+        ]
+        .try_into()
+        .unwrap();
+        assert_eq!(-&m, n);
+        assert_eq!(-n.clone(), m);
+        let a: FractionMatrixExact =
+            vec![vec![(1, 2).into(), (1, 3).into()], vec![0.into(), 4.into()]]
+                .try_into()
+                .unwrap();
+        assert_eq!(n.abs(), a);
+
+        let m: FractionMatrixF64 = vec![vec![1.into(), (-2).into()]].try_into().unwrap();
+        let n: FractionMatrixF64 = vec![vec![(-1).into(), 2.into()]].try_into().unwrap();
+        assert_eq!(-&m, n);
+        assert_eq!(n.abs(), vec![vec![1.into(), 2.into()]].try_into().unwrap());
+
+        assert_eq!(
+            -FractionMatrixEnum::CannotCombineExactAndApprox,
+            FractionMatrixEnum::CannotCombineExactAndApprox
+        );
+    }
+}
@@ -0,0 +1,130 @@
+use anyhow::{Result, anyhow};
+use malachite::{base::num::arithmetic::traits::Abs, rational::Rational};
+
+use crate::{
+    Norm, Sqrt, Zero,
+    fraction::{
+        fraction_enum::FractionEnum, fraction_exact::FractionExact, fraction_f64::FractionF64,
+    },
+    matrix::{
+        fraction_matrix_enum::FractionMatrixEnum, fraction_matrix_exact::FractionMatrixExact,
+        fraction_matrix_f64::FractionMatrixF64,
+    },
+};
+
+macro_rules! norm {
+    ($t:ident, $u:ident, $v:ident) => {
+        impl Norm<$u> for $t {
+            fn norm_1(&self) -> Result<$u> {
+                let mut sums = vec![$v::zero(); self.number_of_columns];
+                for (i, value) in self.values.iter().enumerate() {
+                    sums[i % self.number_of_columns] += value.abs();
+                }
+                Ok($u(sums
+                    .into_iter()
+                    .reduce(|max, sum| if sum > max { sum } else { max })
+                    .unwrap_or_else($v::zero)))
+            }
+
+            fn norm_inf(&self) -> Result<$u> {
+                let mut max = $v::zero();
+                for row in 0..self.number_of_rows {
+                    let sum = self.values[self.index(row, 0)..self.index(row + 1, 0)]
+                        .iter()
+                        .fold($v::zero(), |sum, value| sum + value.abs());
+                    if sum > max {
+                        max = sum;
+                    }
+                }
+                Ok($u(max))
+            }
+
+            fn norm_frobenius(&self, decimal_places: u32) -> Result<$u> {
+                let sum = self
+                    .values
+                    .iter()
+                    .fold($v::zero(), |sum, value| sum + value * value);
+                Ok($u(sum.approx_sqrt(decimal_places)?))
+            }
+        }
+    };
+}
+
+norm!(FractionMatrixF64, FractionF64, f64);
+norm!(FractionMatrixExact, FractionExact, Rational);
+
+impl Norm<FractionEnum> for FractionMatrixEnum {
+    fn norm_1(&self) -> Result<FractionEnum> {
+        match self {
+            FractionMatrixEnum::Approx(m) => Ok(FractionEnum::Approx(m.norm_1()?.0)),
+            FractionMatrixEnum::Exact(m) => Ok(FractionEnum::Exact(m.norm_1()?.0)),
+            FractionMatrixEnum::CannotCombineExactAndApprox => {
+                Err(anyhow!("cannot combine exact and approximate arithmetic"))
+            }
+        }
+    }
+
+    fn norm_inf(&self) -> Result<FractionEnum> {
+        match self {
+            FractionMatrixEnum::Approx(m) => Ok(FractionEnum::Approx(m.norm_inf()?.0)),
+            FractionMatrixEnum::Exact(m) => Ok(FractionEnum::Exact(m.norm_inf()?.0)),
+            FractionMatrixEnum::CannotCombineExactAndApprox => {
+                Err(anyhow!("cannot combine exact and approximate arithmetic"))
+            }
+        }
+    }
+
+    fn norm_frobenius(&self, decimal_places: u32) -> Result<FractionEnum> {
+        match self {
+            FractionMatrixEnum::Approx(m) => {
+                Ok(FractionEnum::Approx(m.norm_frobenius(decimal_places)?.0))
+            }
+            FractionMatrixEnum::Exact(m) => {
+                Ok(FractionEnum::Exact(m.norm_frobenius(decimal_places)?.0))
+            }
+            FractionMatrixEnum::CannotCombineExactAndApprox => {
+                Err(anyhow!("cannot combine exact and approximate arithmetic"))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Norm,
+        fraction::{fraction_exact::FractionExact, fraction_f64::FractionF64},
+        matrix::{
+            fraction_matrix_exact::FractionMatrixExact, fraction_matrix_f64::FractionMatrixF64,
+        },
+    };
+
+    #[test]
+    fn norms() {
+        let m: FractionMatrixExact = vec![
+            vec![(1, 2).into(), (-1, 3).into(), 0.into()],
+            vec![(-1, 4).into(), (1, 4).into(), 2.into()],
+        ]
+        .try_into()
+        .unwrap();
+        assert_eq!(m.norm_1().unwrap(), FractionExact::from(2));
+        assert_eq!(m.norm_inf().unwrap(), FractionExact::from((5, 2)));
+
+        let m: FractionMatrixF64 = vec![vec![3.into(), 0.into()], vec![0.into(), (-4).into()]]
+            .try_into()
+            .unwrap();
+        assert_eq!(m.norm_1().unwrap(), FractionF64::from(4.0));
+        assert_eq!(m.norm_inf().unwrap(), FractionF64::from(4.0));
+        assert_eq!(m.norm_frobenius(10).unwrap(), FractionF64::from(5.0));
+
+        let m: FractionMatrixExact = vec![vec![1.into(), 1.into()]].try_into().unwrap();
+        let norm = m.norm_frobenius(10).unwrap();
+        let error = &norm * &norm - FractionExact::from(2);
+        assert!(error < FractionExact::from((1, 1_000_000_000)));
+        assert!(error > FractionExact::from((-1, 1_000_000_000)));
+
+        let m: FractionMatrixExact = vec![].try_into().unwrap();
+        assert_eq!(m.norm_1().unwrap(), FractionExact::from(0));
+        assert_eq!(m.norm_inf().unwrap(), FractionExact::from(0));
+    }
+}
@@ -0,0 +1,338 @@
+use anyhow::{Result, anyhow};
+use malachite::{Natural, rational::Rational};
+
+use crate::{
+    fraction::{fraction_enum::FractionEnum, fraction_exact::FractionExact},
+    matrix::{
+        fraction_matrix_enum::FractionMatrixEnum, fraction_matrix_exact::FractionMatrixExact,
+        fraction_matrix_f64::FractionMatrixF64, sparse_fraction_matrix::SparseFractionMatrix,
+    },
+};
+
+/// A borrowed row of an exact matrix, which creates fractions only on demand.
+#[derive(Clone, Copy, Debug)]
+pub struct RowView<'a> {
+    values: &'a [Rational],
+}
+
+impl<'a> RowView<'a> {
+    /// Returns the number of columns of the row.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Returns the value in the given column, or None if the column is out of bounds.
+    pub fn get(&self, column: usize) -> Option<FractionExact> {
+        self.values.get(column).cloned().map(FractionExact)
+    }
+
+    /// Returns an iterator over the values of the row, creating each fraction when it is reached.
+    pub fn iter(&self) -> impl Iterator<Item = FractionExact> + 'a {
+        self.values.iter().cloned().map(FractionExact)
+    }
+
+    /// Returns an iterator over the values of the row as (is non-negative, numerator, denominator) triplets, without copying.
+    /// The fractions are reduced and the denominators are positive.
+    pub fn triplets(&self) -> impl Iterator<Item = (bool, &'a Natural, &'a Natural)> + 'a {
+        self.values.iter().map(|value| {
+            (
+                value >= &0u32,
+                value.numerator_ref(),
+                value.denominator_ref(),
+            )
+        })
+    }
+}
+
+/// A borrowed row of an enum matrix.
+#[derive(Clone, Copy, Debug)]
+pub enum RowViewEnum<'a> {
+    Approx(&'a [f64]),
+    Exact(RowView<'a>),
+}
+
+impl<'a> RowViewEnum<'a> {
+    /// Returns the number of columns of the row.
+    pub fn len(&self) -> usize {
+        match self {
+            RowViewEnum::Approx(values) => values.len(),
+            RowViewEnum::Exact(row) => row.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the value in the given column, or None if the column is out of bounds.
+    pub fn get(&self, column: usize) -> Option<FractionEnum> {
+        match self {
+            RowViewEnum::Approx(values) => values.get(column).map(|f| FractionEnum::Approx(*f)),
+            RowViewEnum::Exact(row) => row.values.get(column).cloned().map(FractionEnum::Exact),
+        }
+    }
+}
+
+/// A borrowed value of an enum matrix.
+#[derive(Clone, Copy, Debug)]
+pub enum ValueViewEnum<'a> {
+    Approx(f64),
+    Exact(&'a Rational),
+}
+
+impl ValueViewEnum<'_> {
+    pub fn to_fraction(&self) -> FractionEnum {
+        match self {
+            ValueViewEnum::Approx(f) => FractionEnum::Approx(*f),
+            ValueViewEnum::Exact(f) => FractionEnum::Exact((*f).clone()),
+        }
+    }
+}
+
+impl FractionMatrixF64 {
+    /// Returns an iterator over the rows of the matrix, as slices of the underlying values.
+    pub fn rows(&self) -> impl Iterator<Item = &[f64]> {
+        (0..self.number_of_rows).map(move |row| {
+            &self.values[row * self.number_of_columns..(row + 1) * self.number_of_columns]
+        })
+    }
+
+    /// Returns an iterator over all (row, column, value) entries of the matrix, row by row.
+    pub fn iter_entries(&self) -> impl Iterator<Item = (usize, usize, f64)> {
+        self.values.iter().enumerate().map(|(i, value)| {
+            (
+                i / self.number_of_columns,
+                i % self.number_of_columns,
+                *value,
+            )
+        })
+    }
+
+    /// Returns an iterator over the entries of the matrix that are not zero according to the tolerance of the matrix.
+    pub fn iter_non_zero(&self) -> impl Iterator<Item = (usize, usize, f64)> {
+        self.iter_entries()
+            .filter(|(_, _, value)| !self.is_zero_value(value))
+    }
+}
+
+impl FractionMatrixExact {
+    /// Returns an iterator over the rows of the matrix, as views that create fractions on demand.
+    pub fn rows(&self) -> impl Iterator<Item = RowView<'_>> {
+        (0..self.number_of_rows).map(move |row| RowView {
+            values: &self.values[row * self.number_of_columns..(row + 1) * self.number_of_columns],
+        })
+    }
+
+    /// Returns an iterator over all (row, column, value) entries of the matrix, row by row, without copying the values.
+    pub fn iter_entries(&self) -> impl Iterator<Item = (usize, usize, &Rational)> {
+        self.values.iter().enumerate().map(|(i, value)| {
+            (
+                i / self.number_of_columns,
+                i % self.number_of_columns,
+                value,
+            )
+        })
+    }
+
+    /// Returns an iterator over the entries of the matrix that are not zero, without copying the values.
+    pub fn iter_non_zero(&self) -> impl Iterator<Item = (usize, usize, &Rational)> {
+        self.iter_entries().filter(|(_, _, value)| **value != 0u32)
+    }
+}
+
+impl<T> SparseFractionMatrix<T> {
+    /// Returns an iterator over the rows of the matrix, as slices of (column, value) pairs of the stored values, sorted by column.
+    pub fn rows(&self) -> impl Iterator<Item = &[(usize, T)]> {
+        self.rows.iter().map(|row| row.as_slice())
+    }
+
+    /// Returns an iterator over the stored (row, column, value) entries of the matrix, row by row, without copying the values.
+    /// A sparse matrix stores only values that are not zero; it offers no iterator over all entries, as that would have to create the zeroes.
+    pub fn iter_non_zero(&self) -> impl Iterator<Item = (usize, usize, &T)> {
+        self.rows.iter().enumerate().flat_map(|(row, values)| {
+            values
+                .iter()
+                .map(move |(column, value)| (row, *column, value))
+        })
+    }
+}
+
+impl FractionMatrixEnum {
+    /// Returns an iterator over the rows of the matrix.
+    /// Returns an error if the matrix combines exact and approximate arithmetic.
+    pub fn rows(&self) -> Result<impl Iterator<Item = RowViewEnum<'_>>> {
+        let number_of_rows = match self {
+            FractionMatrixEnum::Approx(m) => m.number_of_rows,
+            FractionMatrixEnum::Exact(m) => m.number_of_rows,
+            FractionMatrixEnum::CannotCombineExactAndApprox => {
+                return Err(anyhow!("cannot combine exact and approximate arithmetic"));
+            }
+        };
+        Ok((0..number_of_rows).map(move |row| match self {
+            FractionMatrixEnum::Approx(m) => RowViewEnum::Approx(
+                &m.values[row * m.number_of_columns..(row + 1) * m.number_of_columns],
+            ),
+            FractionMatrixEnum::Exact(m) => RowViewEnum::Exact(RowView {
+                values: &m.values[row * m.number_of_columns..(row + 1) * m.number_of_columns],
+            }),
+            FractionMatrixEnum::CannotCombineExactAndApprox => unreachable!(),
+        }))
+    }
+
+    /// Returns an iterator over all (row, column, value) entries of the matrix, row by row, without copying the values.
+    /// Returns an error if the matrix combines exact and approximate arithmetic.
+    pub fn iter_entries(
+        &self,
+    ) -> Result<Box<dyn Iterator<Item = (usize, usize, ValueViewEnum<'_>)> + '_>> {
+        match self {
+            FractionMatrixEnum::Approx(m) => {
+                Ok(Box::new(m.iter_entries().map(|(row, column, value)| {
+                    (row, column, ValueViewEnum::Approx(value))
+                })))
+            }
+            FractionMatrixEnum::Exact(m) => {
+                Ok(Box::new(m.iter_entries().map(|(row, column, value)| {
+                    (row, column, ValueViewEnum::Exact(value))
+                })))
+            }
+            FractionMatrixEnum::CannotCombineExactAndApprox => {
+                Err(anyhow!("cannot combine exact and approximate arithmetic"))
+            }
+        }
+    }
+
+    /// Returns an iterator over the entries of the matrix that are not zero, without copying the values.
+    /// Returns an error if the matrix combines exact and approximate arithmetic.
+    pub fn iter_non_zero(
+        &self,
+    ) -> Result<Box<dyn Iterator<Item = (usize, usize, ValueViewEnum<'_>)> + '_>> {
+        match self {
+            FractionMatrixEnum::Approx(m) => {
+                Ok(Box::new(m.iter_non_zero().map(|(row, column, value)| {
+                    (row, column, ValueViewEnum::Approx(value))
+                })))
+            }
+            FractionMatrixEnum::Exact(m) => {
+                Ok(Box::new(m.iter_non_zero().map(|(row, column, value)| {
+                    (row, column, ValueViewEnum::Exact(value))
+                })))
+            }
+            FractionMatrixEnum::CannotCombineExactAndApprox => {
+                Err(anyhow!("cannot combine exact and approximate arithmetic"))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use malachite::{Natural, rational::Rational};
+
+    use crate::{
+        EbiMatrix,
+        fraction::{fraction_enum::FractionEnum, fraction_exact::FractionExact},
+        matrix::{
+            fraction_matrix_enum::FractionMatrixEnum, fraction_matrix_exact::FractionMatrixExact,
+            fraction_matrix_f64::FractionMatrixF64, sparse_fraction_matrix::SparseFractionMatrix,
+        },
+    };
+
+    #[test]
+    fn rows() {
+        let m: FractionMatrixF64 = vec![vec![1.into(), 2.into()], vec![3.into(), 4.into()]]
+            .try_into()
+            .unwrap();
+        let rows = m.rows().collect::<Vec<_>>();
+        assert_eq!(rows, vec![&[1.0, 2.0], &[3.0, 4.0]]);
+
+        let m: FractionMatrixExact = vec![
+            vec![(1, 2).into(), (-1, 3).into()],
+            vec![0.into(), 4.into()],
+        ]
+        .try_into()
+        .unwrap();
+        let rows = m.rows().collect::<Vec<_>>();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].get(1), Some(FractionExact::from((-1, 3))));
+        assert_eq!(rows[0].get(2), None);
+        assert_eq!(
+            rows[1].iter().collect::<Vec<_>>(),
+            vec![FractionExact::from(0), FractionExact::from(4)]
+        );
+        let triplets = rows[0].triplets().collect::<Vec<_>>();
+        assert_eq!(
+            triplets,
+            vec![
+                (true, &Natural::from(1u32), &Natural::from(2u32)),
+                (false, &Natural::from(1u32), &Natural::from(3u32))
+            ]
+        );
+
+        let e = FractionMatrixEnum::Exact(m);
+        assert_eq!(e.rows().unwrap().count(), 2);
+        assert!(
+            FractionMatrixEnum::CannotCombineExactAndApprox
+                .rows()
+                .is_err()
+        );
+
+        //rows without columns
+        assert_eq!(FractionMatrixF64::new(3, 0).rows().count(), 3);
+        assert!(
+            FractionMatrixExact::new(2, 0)
+                .rows()
+                .all(|row| row.is_empty())
+        );
+    }
+
+    #[test]
+    fn entries() {
+        let m: FractionMatrixExact = vec![
+            vec![(1, 2).into(), 0.into(), 0.into()],
+            vec![0.into(), 0.into(), (-1, 3).into()],
+        ]
+        .try_into()
+        .unwrap();
+        assert_eq!(m.iter_entries().count(), 6);
+        assert_eq!(
+            m.iter_entries()
+                .nth(5)
+                .map(|(row, column, _)| (row, column)),
+            Some((1, 2))
+        );
+        let non_zero = m
+            .iter_non_zero()
+            .map(|(row, column, value)| (row, column, FractionExact(value.clone())))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            non_zero,
+            vec![(0, 0, (1, 2).into()), (1, 2, FractionExact::from((-1, 3)))]
+        );
+
+        let s = SparseFractionMatrix::from(&m);
+        assert_eq!(
+            s.iter_non_zero()
+                .map(|(row, column, value)| (row, column, value.clone()))
+                .collect::<Vec<_>>(),
+            non_zero
+        );
+        assert_eq!(s.rows().map(|row| row.len()).collect::<Vec<_>>(), [1, 1]);
+
+        let e = FractionMatrixEnum::Exact(m);
+        assert_eq!(
+            e.iter_non_zero()
+                .unwrap()
+                .map(|(_, _, value)| value.to_fraction())
+                .nth(1),
+            Some(FractionEnum::Exact(Rational::from_signeds(-1, 3)))
+        );
+
+        let m: FractionMatrixF64 = vec![vec![1e-20.into(), 2.into()]].try_into().unwrap();
+        assert_eq!(m.iter_non_zero().collect::<Vec<_>>(), [(0, 1, 2.0)]);
+        assert_eq!(FractionMatrixF64::new(3, 0).iter_entries().count(), 0);
+    }
+}
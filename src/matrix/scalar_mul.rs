@@ -0,0 +1,149 @@
+use std::ops::{Div, DivAssign, Mul, MulAssign};
+
+use crate::{
+    fraction::{
+        fraction_enum::FractionEnum, fraction_exact::FractionExact, fraction_f64::FractionF64,
+    },
+    matrix::{
+        fraction_matrix_enum::FractionMatrixEnum, fraction_matrix_exact::FractionMatrixExact,
+        fraction_matrix_f64::FractionMatrixF64,
+    },
+};
+
+macro_rules! scalar_op {
+    ($t:ident, $u:ident, $trait:ident, $fn:ident, $assign_trait:ident, $assign_fn:ident) => {
+        /// Applies the operation with the scalar to each value in place.
+        /// As for the fractions themselves, dividing an exact matrix by zero panics.
+        impl $assign_trait<&$u> for $t {
+            fn $assign_fn(&mut self, rhs: &$u) {
+                self.values.iter_mut().for_each(|f| f.$assign_fn(&rhs.0));
+            }
+        }
+
+        impl $assign_trait<$u> for $t {
+            fn $assign_fn(&mut self, rhs: $u) {
+                self.$assign_fn(&rhs)
+            }
+        }
+
+        impl $trait<&$u> for $t {
+            type Output = $t;
+
+            fn $fn(mut self, rhs: &$u) -> Self::Output {
+                self.$assign_fn(rhs);
+                self
+            }
+        }
+
+        impl $trait<&$u> for &$t {
+            type Output = $t;
+
+            fn $fn(self, rhs: &$u) -> Self::Output {
+                self.clone().$fn(rhs)
+            }
+        }
+    };
+}
+
+macro_rules! scalar {
+    ($t:ident, $u:ident) => {
+        scalar_op!($t, $u, Mul, mul, MulAssign, mul_assign);
+        scalar_op!($t, $u, Div, div, DivAssign, div_assign);
+    };
+}
+
+scalar!(FractionMatrixF64, FractionF64);
+scalar!(FractionMatrixExact, FractionExact);
+
+macro_rules! scalar_op_enum {
+    ($trait:ident, $fn:ident, $assign_trait:ident, $assign_fn:ident) => {
+        /// Applies the operation with the scalar to each value in place.
+        /// Becomes [FractionMatrixEnum::CannotCombineExactAndApprox] if one of the matrix and the scalar is exact and the other approximate.
+        impl $assign_trait<&FractionEnum> for FractionMatrixEnum {
+            fn $assign_fn(&mut self, rhs: &FractionEnum) {
+                match (self, rhs) {
+                    (FractionMatrixEnum::Approx(m), FractionEnum::Approx(f)) => {
+                        m.$assign_fn(&FractionF64(*f))
+                    }
+                    (FractionMatrixEnum::Exact(m), FractionEnum::Exact(f)) => {
+                        m.values.iter_mut().for_each(|g| g.$assign_fn(f))
+                    }
+                    (s, _) => *s = FractionMatrixEnum::CannotCombineExactAndApprox,
+                }
+            }
+        }
+
+        impl $assign_trait<FractionEnum> for FractionMatrixEnum {
+            fn $assign_fn(&mut self, rhs: FractionEnum) {
+                self.$assign_fn(&rhs)
+            }
+        }
+
+        impl $trait<&FractionEnum> for FractionMatrixEnum {
+            type Output = FractionMatrixEnum;
+
+            fn $fn(mut self, rhs: &FractionEnum) -> Self::Output {
+                self.$assign_fn(rhs);
+                self
+            }
+        }
+
+        impl $trait<&FractionEnum> for &FractionMatrixEnum {
+            type Output = FractionMatrixEnum;
+
+            fn $fn(self, rhs: &FractionEnum) -> Self::Output {
+                self.clone().$fn(rhs)
+            }
+        }
+    };
+}
+
+scalar_op_enum!(Mul, mul, MulAssign, mul_assign);
+scalar_op_enum!(Div, div, DivAssign, div_assign);
+
+#[cfg(test)]
+mod tests {
+    use malachite::rational::Rational;
+
+    use crate::{
+        fraction::{
+            fraction_enum::FractionEnum, fraction_exact::FractionExact, fraction_f64::FractionF64,
+        },
+        matrix::{
+            fraction_matrix_enum::FractionMatrixEnum, fraction_matrix_exact::FractionMatrixExact,
+            fraction_matrix_f64::FractionMatrixF64,
+        },
+    };
+
+    #[test]
+    fn scalar_mul() {
+        //normalise a weighted adjacency matrix by its total weight
+        let weights: FractionMatrixExact = vec![vec![1.into(), 3.into()], vec![0.into(), 2.into()]]
+            .try_into()
+            .unwrap();
+        let n: FractionMatrixExact = vec![
+            vec![(1, 6).into(), (1, 2).into()],
+            vec![0.into(), (1, 3).into()],
+        ]
+        .try_into()
+        .unwrap();
+        assert_eq!(&weights / &FractionExact::from(6), n);
+        let mut o = n.clone();
+        o *= FractionExact::from(6);
+        assert_eq!(o, weights);
+
+        let m: FractionMatrixF64 = vec![vec![1.into(), 2.into()]].try_into().unwrap();
+        assert_eq!(
+            m * &FractionF64::from(0.5),
+            vec![vec![0.5.into(), 1.into()]].try_into().unwrap()
+        );
+
+        let mut e = FractionMatrixEnum::Exact(n.clone());
+        e *= FractionEnum::Exact(Rational::from(6));
+        assert_eq!(e, FractionMatrixEnum::Exact(weights));
+        assert_eq!(
+            &e / &FractionEnum::Approx(2.0),
+            FractionMatrixEnum::CannotCombineExactAndApprox
+        );
+    }
+}
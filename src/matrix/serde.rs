@@ -0,0 +1,200 @@
+use malachite::rational::Rational;
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de, ser};
+
+use crate::{
+    fraction::{
+        fraction::EPSILON,
+        fraction_exact::FractionExact,
+        serde::{F64Repr, RationalRef},
+    },
+    matrix::{
+        fraction_matrix_enum::FractionMatrixEnum, fraction_matrix_exact::FractionMatrixExact,
+        fraction_matrix_f64::FractionMatrixF64,
+    },
+};
+
+/// The serialised form of a matrix: its size and its values in row-major order.
+/// Matrices are serialised with borrowed values, and deserialised into a `Vec`.
+#[derive(Serialize, Deserialize)]
+struct MatrixRepr<V> {
+    number_of_rows: usize,
+    number_of_columns: usize,
+    values: V,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    epsilon: Option<f64>,
+}
+
+impl<T> MatrixRepr<Vec<T>> {
+    fn check<E: de::Error>(&self) -> Result<(), E> {
+        if self.number_of_rows.checked_mul(self.number_of_columns) != Some(self.values.len()) {
+            return Err(E::custom(format!(
+                "a matrix of size {}x{} cannot have {} values",
+                self.number_of_rows,
+                self.number_of_columns,
+                self.values.len()
+            )));
+        }
+        Ok(())
+    }
+}
+
+struct ApproxValues<'a>(&'a [f64]);
+
+impl Serialize for ApproxValues<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.0.iter().map(|value| F64Repr(*value)))
+    }
+}
+
+struct ExactValues<'a>(&'a [Rational]);
+
+impl Serialize for ExactValues<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.0.iter().map(RationalRef))
+    }
+}
+
+/// Non-finite values are serialised as for [FractionF64](crate::fraction::fraction_f64::FractionF64).
+impl Serialize for FractionMatrixF64 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        MatrixRepr {
+            number_of_rows: self.number_of_rows,
+            number_of_columns: self.number_of_columns,
+            values: ApproxValues(&self.values),
+            epsilon: Some(self.epsilon),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for FractionMatrixF64 {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = MatrixRepr::<Vec<F64Repr>>::deserialize(deserializer)?;
+        repr.check()?;
+        Ok(Self {
+            values: repr.values.into_iter().map(|value| value.0).collect(),
+            number_of_rows: repr.number_of_rows,
+            number_of_columns: repr.number_of_columns,
+            epsilon: repr.epsilon.unwrap_or(EPSILON),
+        })
+    }
+}
+
+impl Serialize for FractionMatrixExact {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        MatrixRepr {
+            number_of_rows: self.number_of_rows,
+            number_of_columns: self.number_of_columns,
+            values: ExactValues(&self.values),
+            epsilon: None,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for FractionMatrixExact {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = MatrixRepr::<Vec<FractionExact>>::deserialize(deserializer)?;
+        repr.check()?;
+        Ok(Self {
+            values: repr
+                .values
+                .into_iter()
+                .map(|f| f.0)
+                .collect::<Vec<Rational>>(),
+            number_of_rows: repr.number_of_rows,
+            number_of_columns: repr.number_of_columns,
+        })
+    }
+}
+
+/// The serialised form of a [FractionMatrixEnum]; the variant records the mode.
+#[derive(Deserialize)]
+enum FractionMatrixEnumRepr {
+    Exact(FractionMatrixExact),
+    Approx(FractionMatrixF64),
+}
+
+impl Serialize for FractionMatrixEnum {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            FractionMatrixEnum::Exact(m) => {
+                serializer.serialize_newtype_variant("FractionMatrixEnumRepr", 0, "Exact", m)
+            }
+            FractionMatrixEnum::Approx(m) => {
+                serializer.serialize_newtype_variant("FractionMatrixEnumRepr", 1, "Approx", m)
+            }
+            FractionMatrixEnum::CannotCombineExactAndApprox => Err(ser::Error::custom(
+                "cannot combine exact and approximate arithmetic",
+            )),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for FractionMatrixEnum {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(match FractionMatrixEnumRepr::deserialize(deserializer)? {
+            FractionMatrixEnumRepr::Exact(m) => FractionMatrixEnum::Exact(m),
+            FractionMatrixEnumRepr::Approx(m) => FractionMatrixEnum::Approx(m),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::{
+        fraction_matrix_enum::FractionMatrixEnum, fraction_matrix_exact::FractionMatrixExact,
+        fraction_matrix_f64::FractionMatrixF64,
+    };
+
+    #[test]
+    fn serde_matrices() {
+        let m: FractionMatrixExact = vec![
+            vec![(1, 3).into(), 2.into()],
+            vec![0.into(), (-5, 7).into()],
+        ]
+        .try_into()
+        .unwrap();
+        let json = serde_json::to_string(&m).unwrap();
+        assert_eq!(
+            json,
+            "{\"number_of_rows\":2,\"number_of_columns\":2,\"values\":[\"1/3\",\"2\",\"0\",\"-5/7\"]}"
+        );
+        assert_eq!(
+            serde_json::from_str::<FractionMatrixExact>(&json).unwrap(),
+            m
+        );
+
+        let mut m: FractionMatrixF64 = vec![vec![0.5.into(), 2.into()]].try_into().unwrap();
+        m.set_epsilon(1e-9);
+        let n =
+            serde_json::from_str::<FractionMatrixF64>(&serde_json::to_string(&m).unwrap()).unwrap();
+        assert_eq!(n, m);
+        assert_eq!(n.epsilon(), 1e-9);
+
+        let m = FractionMatrixEnum::Approx(m);
+        let json = serde_json::to_string(&m).unwrap();
+        assert_eq!(
+            serde_json::from_str::<FractionMatrixEnum>(&json).unwrap(),
+            m
+        );
+
+        //non-finite values
+        let m: FractionMatrixF64 = vec![vec![f64::INFINITY.into(), f64::NAN.into()]]
+            .try_into()
+            .unwrap();
+        let json = serde_json::to_string(&m).unwrap();
+        assert!(json.contains("\"values\":[\"inf\",\"NaN\"]"), "{}", json);
+        let n = serde_json::from_str::<FractionMatrixF64>(&json).unwrap();
+        assert_eq!(n.values[0], f64::INFINITY);
+        assert!(n.values[1].is_nan());
+
+        //inconsistent sizes
+        assert!(
+            serde_json::from_str::<FractionMatrixExact>(
+                "{\"number_of_rows\":2,\"number_of_columns\":2,\"values\":[\"1\"]}"
+            )
+            .is_err()
+        );
+    }
+}
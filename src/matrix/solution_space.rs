@@ -0,0 +1,448 @@
+use anyhow::{Result, anyhow};
+use malachite::{
+    base::num::basic::traits::{One, Zero},
+    rational::Rational,
+};
+use std::ops::{AddAssign, Mul};
+
+use crate::{
+    MaybeExact, SolutionSpaceOf, Solve,
+    events::debug_event,
+    fraction::{
+        fraction_enum::FractionEnum, fraction_exact::FractionExact, fraction_f64::FractionF64,
+    },
+    matrix::{
+        fraction_matrix_enum::FractionMatrixEnum, fraction_matrix_exact::FractionMatrixExact,
+        fraction_matrix_f64::FractionMatrixF64,
+    },
+    size_guard::check_exact_size,
+};
+
+/// All solutions of a linear system A x = b: the particular solution plus any linear combination of the basis vectors.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SolutionSpace<T> {
+    pub particular: Vec<T>,
+    pub basis: Vec<Vec<T>>,
+}
+
+impl<T> SolutionSpace<T> {
+    /// Returns the number of free variables.
+    pub fn dimension(&self) -> usize {
+        self.basis.len()
+    }
+
+    /// Returns whether the system has exactly one solution.
+    pub fn is_unique(&self) -> bool {
+        self.basis.is_empty()
+    }
+
+    /// Returns an iterator over the basis vectors of the solution space.
+    pub fn basis(&self) -> impl Iterator<Item = &Vec<T>> {
+        self.basis.iter()
+    }
+
+    /// Returns the solution particular + Σ parameters[i] * basis[i].
+    pub fn solution(&self, parameters: &[T]) -> Result<Vec<T>>
+    where
+        T: Clone + AddAssign<T>,
+        for<'a> &'a T: Mul<&'a T, Output = T>,
+    {
+        if parameters.len() != self.basis.len() {
+            return Err(anyhow!(
+                "expected {} parameters, but {} were given",
+                self.basis.len(),
+                parameters.len()
+            ));
+        }
+        let mut result = self.particular.clone();
+        for (parameter, vector) in parameters.iter().zip(self.basis.iter()) {
+            for (x, v) in result.iter_mut().zip(vector.iter()) {
+                *x += parameter * v;
+            }
+        }
+        Ok(result)
+    }
+}
+
+/// Reduces the augmented matrix [A | b] to reduced row-echelon form with row swaps, and reads off the solution space.
+/// Returns None if the system is inconsistent.
+/// `$values` is the row-major augmented matrix with `$n + 1` columns; `$better` returns whether a candidate pivot value is preferable over the current one;
+/// `$check` is applied to the augmented matrix after each pivot.
+macro_rules! solution_space {
+    ($values:ident, $m:expr, $n:expr, $v:ident, $is_zero:expr, $better:expr, $check:expr) => {{
+        let m = $m;
+        let n = $n;
+        let width = n + 1;
+        let is_zero = $is_zero;
+        let better = $better;
+        let check = $check;
+
+        let mut pivot_columns = vec![];
+        let mut free_columns = vec![];
+        let mut pivot_row = 0;
+        for column in 0..n {
+            //choose a pivot
+            let mut chosen = None;
+            for row in pivot_row..m {
+                let candidate = &$values[row * width + column];
+                if is_zero(candidate) {
+                    continue;
+                }
+                match chosen {
+                    Some(best) if !better(candidate, &$values[best * width + column]) => {}
+                    _ => chosen = Some(row),
+                }
+            }
+            let Some(chosen) = chosen else {
+                free_columns.push(column);
+                continue;
+            };
+
+            //move the pivot row into place
+            if chosen != pivot_row {
+                for k in 0..width {
+                    $values.swap(chosen * width + k, pivot_row * width + k);
+                }
+            }
+
+            //normalise the pivot row
+            let factor = $values[pivot_row * width + column].clone();
+            for k in column..width {
+                $values[pivot_row * width + k] /= &factor;
+            }
+            $values[pivot_row * width + column] = $v::ONE;
+
+            //eliminate the column from all other rows
+            for row in 0..m {
+                if row == pivot_row || is_zero(&$values[row * width + column]) {
+                    continue;
+                }
+                let factor = $values[row * width + column].clone();
+                for k in column..width {
+                    let mut old = $values[pivot_row * width + k].clone();
+                    old *= &factor;
+                    $values[row * width + k] -= old;
+                }
+                $values[row * width + column] = $v::ZERO;
+            }
+
+            pivot_columns.push(column);
+            pivot_row += 1;
+            check(&$values)?;
+        }
+
+        //a zero row with a non-zero right-hand side makes the system inconsistent
+        if (pivot_row..m).any(|row| !is_zero(&$values[row * width + n])) {
+            None
+        } else {
+            let mut particular = vec![$v::ZERO; n];
+            for (row, column) in pivot_columns.iter().enumerate() {
+                particular[*column] = $values[row * width + n].clone();
+            }
+
+            let basis = free_columns
+                .iter()
+                .map(|free| {
+                    let mut vector = vec![$v::ZERO; n];
+                    vector[*free] = $v::ONE;
+                    for (row, column) in pivot_columns.iter().enumerate() {
+                        vector[*column] = -$values[row * width + free].clone();
+                    }
+                    vector
+                })
+                .collect::<Vec<_>>();
+
+            Some((particular, basis))
+        }
+    }};
+}
+
+fn augment<V: Clone>(values: &[V], number_of_columns: usize, rhs: Vec<V>) -> Vec<V> {
+    let mut result = Vec::with_capacity(values.len() + rhs.len());
+    for (row, b) in rhs.into_iter().enumerate() {
+        result.extend_from_slice(&values[row * number_of_columns..(row + 1) * number_of_columns]);
+        result.push(b);
+    }
+    result
+}
+
+fn check_size(number_of_rows: usize, rhs_length: usize) -> Result<()> {
+    if number_of_rows != rhs_length {
+        Err(anyhow!(
+            "cannot solve a system of {} equations with a right-hand side of size {}",
+            number_of_rows,
+            rhs_length
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+impl SolutionSpaceOf<FractionF64> for FractionMatrixF64 {
+    fn solution_space(&self, rhs: &[FractionF64]) -> Result<Option<SolutionSpace<FractionF64>>> {
+        check_size(self.number_of_rows, rhs.len())?;
+        let mut values = augment(
+            &self.values,
+            self.number_of_columns,
+            rhs.iter().map(|f| f.0).collect(),
+        );
+
+        //partial pivoting: the candidate with the largest magnitude
+        let result = solution_space!(
+            values,
+            self.number_of_rows,
+            self.number_of_columns,
+            f64,
+            |v: &f64| self.is_zero_value(v),
+            |candidate: &f64, best: &f64| candidate.abs() > best.abs(),
+            |_: &[f64]| -> Result<()> { Ok(()) }
+        );
+
+        Ok(result.map(|(particular, basis)| SolutionSpace {
+            particular: particular.into_iter().map(FractionF64).collect(),
+            basis: basis
+                .into_iter()
+                .map(|v| v.into_iter().map(FractionF64).collect())
+                .collect(),
+        }))
+    }
+}
+
+impl SolutionSpaceOf<FractionExact> for FractionMatrixExact {
+    fn solution_space(
+        &self,
+        rhs: &[FractionExact],
+    ) -> Result<Option<SolutionSpace<FractionExact>>> {
+        check_size(self.number_of_rows, rhs.len())?;
+        let mut values = augment(
+            &self.values,
+            self.number_of_columns,
+            rhs.iter().map(|f| f.0.clone()).collect(),
+        );
+
+        //any non-zero pivot is exact: take the first
+        let result = solution_space!(
+            values,
+            self.number_of_rows,
+            self.number_of_columns,
+            Rational,
+            |v: &Rational| self.is_zero_value(v),
+            |_: &Rational, _: &Rational| false,
+            |values: &[Rational]| check_exact_size(&[&self.values, values])
+        );
+
+        Ok(result.map(|(particular, basis)| SolutionSpace {
+            particular: particular.into_iter().map(FractionExact).collect(),
+            basis: basis
+                .into_iter()
+                .map(|v| v.into_iter().map(FractionExact).collect())
+                .collect(),
+        }))
+    }
+}
+
+impl SolutionSpaceOf<FractionEnum> for FractionMatrixEnum {
+    fn solution_space(&self, rhs: &[FractionEnum]) -> Result<Option<SolutionSpace<FractionEnum>>> {
+        match self {
+            FractionMatrixEnum::Approx(m) => {
+                let rhs = rhs
+                    .iter()
+                    .map(|f| Ok(FractionF64(*f.approx_ref()?)))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(m.solution_space(&rhs)?.map(|s| SolutionSpace {
+                    particular: s
+                        .particular
+                        .into_iter()
+                        .map(|f| FractionEnum::Approx(f.0))
+                        .collect(),
+                    basis: s
+                        .basis
+                        .into_iter()
+                        .map(|v| v.into_iter().map(|f| FractionEnum::Approx(f.0)).collect())
+                        .collect(),
+                }))
+            }
+            FractionMatrixEnum::Exact(m) => {
+                let rhs = rhs
+                    .iter()
+                    .map(|f| Ok(FractionExact(f.exact_ref()?.clone())))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(m.solution_space(&rhs)?.map(|s| SolutionSpace {
+                    particular: s
+                        .particular
+                        .into_iter()
+                        .map(|f| FractionEnum::Exact(f.0))
+                        .collect(),
+                    basis: s
+                        .basis
+                        .into_iter()
+                        .map(|v| v.into_iter().map(|f| FractionEnum::Exact(f.0)).collect())
+                        .collect(),
+                }))
+            }
+            FractionMatrixEnum::CannotCombineExactAndApprox => {
+                Err(anyhow!("cannot combine exact and approximate arithmetic"))
+            }
+        }
+    }
+}
+
+/// Returns the particular solution if it is the only one.
+fn unique_solution<T>(space: Option<SolutionSpace<T>>) -> Result<Vec<T>> {
+    match space {
+        Some(space) if space.is_unique() => Ok(space.particular),
+        Some(space) => Err(anyhow!(
+            "the system has infinitely many solutions, with {} free variables",
+            space.dimension()
+        )),
+        None => Err(anyhow!("the system has no solution")),
+    }
+}
+
+/// Tridiagonal and other banded systems are solved with elimination within the band; others with elimination on the augmented matrix.
+impl Solve<FractionF64> for FractionMatrixF64 {
+    fn solve(&self, rhs: &[FractionF64]) -> Result<Vec<FractionF64>> {
+        check_size(self.number_of_rows, rhs.len())?;
+        let b = rhs.iter().map(|f| f.0).collect::<Vec<_>>();
+        if let Some(x) = self.solve_banded(&b) {
+            return Ok(x.into_iter().map(FractionF64).collect());
+        }
+        debug_event!(
+            size = self.number_of_rows,
+            "falling back to general elimination"
+        );
+        unique_solution(self.solution_space(rhs)?)
+    }
+}
+
+/// Tridiagonal and other banded systems are solved with elimination within the band; others with elimination on the augmented matrix.
+impl Solve<FractionExact> for FractionMatrixExact {
+    fn solve(&self, rhs: &[FractionExact]) -> Result<Vec<FractionExact>> {
+        check_size(self.number_of_rows, rhs.len())?;
+        let b = rhs.iter().map(|f| f.0.clone()).collect::<Vec<_>>();
+        if let Some(x) = self.solve_banded(&b) {
+            return Ok(x.into_iter().map(FractionExact).collect());
+        }
+        debug_event!(
+            size = self.number_of_rows,
+            "falling back to general elimination"
+        );
+        unique_solution(self.solution_space(rhs)?)
+    }
+}
+
+impl Solve<FractionEnum> for FractionMatrixEnum {
+    fn solve(&self, rhs: &[FractionEnum]) -> Result<Vec<FractionEnum>> {
+        match self {
+            FractionMatrixEnum::Approx(m) => {
+                let rhs = rhs
+                    .iter()
+                    .map(|f| Ok(FractionF64(*f.approx_ref()?)))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(m.solve(&rhs)?
+                    .into_iter()
+                    .map(|f| FractionEnum::Approx(f.0))
+                    .collect())
+            }
+            FractionMatrixEnum::Exact(m) => {
+                let rhs = rhs
+                    .iter()
+                    .map(|f| Ok(FractionExact(f.exact_ref()?.clone())))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(m.solve(&rhs)?
+                    .into_iter()
+                    .map(|f| FractionEnum::Exact(f.0))
+                    .collect())
+            }
+            FractionMatrixEnum::CannotCombineExactAndApprox => {
+                Err(anyhow!("cannot combine exact and approximate arithmetic"))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        SolutionSpaceOf, Solve,
+        fraction::{fraction_exact::FractionExact, fraction_f64::FractionF64},
+        matrix::{
+            fraction_matrix_exact::FractionMatrixExact, fraction_matrix_f64::FractionMatrixF64,
+        },
+    };
+
+    #[test]
+    fn solution_space_exact() {
+        // x + 2y + z = 4
+        // 2x + 4y + 3z = 11
+        let m: FractionMatrixExact = vec![
+            vec![1.into(), 2.into(), 1.into()],
+            vec![2.into(), 4.into(), 3.into()],
+        ]
+        .try_into()
+        .unwrap();
+        let rhs: Vec<FractionExact> = vec![4.into(), 11.into()];
+
+        let space = m.solution_space(&rhs).unwrap().unwrap();
+        assert_eq!(space.dimension(), 1);
+        assert_eq!(
+            space.particular,
+            vec![FractionExact::from(1), 0.into(), 3.into()]
+        );
+        assert_eq!(
+            space.basis[0],
+            vec![FractionExact::from(-2), 1.into(), 0.into()]
+        );
+
+        //every solution satisfies the system
+        let x = space.solution(&[FractionExact::from((5, 7))]).unwrap();
+        assert_eq!((&m * &x).unwrap(), rhs);
+
+        //inconsistent
+        let rhs: Vec<FractionExact> = vec![4.into(), 9.into()];
+        let n: FractionMatrixExact = vec![
+            vec![1.into(), 2.into(), 1.into()],
+            vec![2.into(), 4.into(), 2.into()],
+        ]
+        .try_into()
+        .unwrap();
+        assert!(n.solution_space(&rhs).unwrap().is_none());
+    }
+
+    #[test]
+    fn solution_space_f64() {
+        //the first column needs a row swap
+        let m: FractionMatrixF64 = vec![vec![0.into(), 1.into()], vec![2.into(), 0.into()]]
+            .try_into()
+            .unwrap();
+        let space = m.solution_space(&[3.into(), 4.into()]).unwrap().unwrap();
+        assert!(space.is_unique());
+        assert_eq!(space.particular, vec![FractionF64::from(2), 3.into()]);
+    }
+
+    #[test]
+    fn solve() {
+        let m: FractionMatrixExact = vec![vec![2.into(), 1.into()], vec![1.into(), 3.into()]]
+            .try_into()
+            .unwrap();
+        let rhs: Vec<FractionExact> = vec![1.into(), 2.into()];
+        let x = m.solve(&rhs).unwrap();
+        assert_eq!(x, vec![FractionExact::from((1, 5)), (3, 5).into()]);
+
+        let m: FractionMatrixF64 = vec![vec![0.into(), 1.into()], vec![2.into(), 0.into()]]
+            .try_into()
+            .unwrap();
+        assert_eq!(
+            m.solve(&[3.into(), 4.into()]).unwrap(),
+            vec![FractionF64::from(2), 3.into()]
+        );
+
+        //singular: no solution, or infinitely many
+        let m: FractionMatrixExact = vec![vec![1.into(), 2.into()], vec![2.into(), 4.into()]]
+            .try_into()
+            .unwrap();
+        assert!(m.solve(&[1.into(), 3.into()]).is_err());
+        assert!(m.solve(&[1.into(), 2.into()]).is_err());
+        assert!(m.solve(&[1.into()]).is_err());
+    }
+}
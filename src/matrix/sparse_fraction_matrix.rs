@@ -0,0 +1,865 @@
+use anyhow::{Error, Result, anyhow};
+use std::ops::Mul;
+
+use crate::{
+    EbiMatrix, GaussJordan, IdentityMinus, MaybeExact, One, Signed, Zero,
+    fraction::{
+        fraction_enum::FractionEnum, fraction_exact::FractionExact, fraction_f64::FractionF64,
+    },
+    matrix::{
+        fraction_matrix_enum::FractionMatrixEnum, fraction_matrix_exact::FractionMatrixExact,
+        fraction_matrix_f64::FractionMatrixF64, gauss_jordan::Pivot,
+    },
+    try_ops::TryAdd,
+    vector::sum_of_products,
+};
+
+/// A matrix that stores only its non-zero values, row by row (compressed sparse rows).
+///
+/// Each row is a list of (column, value) pairs, sorted by column.
+/// Values that become zero are removed; approximate values are zero according to their tolerance.
+/// Suited for matrices in which most values are zero, such as the transition matrices of stochastic models.
+#[derive(Clone, Debug)]
+pub struct SparseFractionMatrix<T> {
+    pub(crate) rows: Vec<Vec<(usize, T)>>,
+    pub(crate) number_of_columns: usize,
+}
+
+impl<T: Zero + Clone> SparseFractionMatrix<T> {
+    /// Returns the number of stored (non-zero) values.
+    pub fn number_of_non_zeroes(&self) -> usize {
+        self.rows.iter().map(|row| row.len()).sum()
+    }
+
+    /// Returns an iterator over the non-zero values of the given row, as (column, value) pairs in increasing column order.
+    pub fn row(&self, row: usize) -> impl Iterator<Item = &(usize, T)> {
+        self.rows[row].iter()
+    }
+
+    fn get_ref(&self, row: usize, column: usize) -> Option<&T> {
+        let row = self.rows.get(row)?;
+        row.binary_search_by_key(&column, |(c, _)| *c)
+            .ok()
+            .map(|i| &row[i].1)
+    }
+
+    /// Stores the value, or removes it if it is zero.
+    fn store(&mut self, row: usize, column: usize, value: T) {
+        let row = &mut self.rows[row];
+        match row.binary_search_by_key(&column, |(c, _)| *c) {
+            Ok(i) => {
+                if value.is_zero() {
+                    row.remove(i);
+                } else {
+                    row[i].1 = value;
+                }
+            }
+            Err(i) => {
+                if !value.is_zero() {
+                    row.insert(i, (column, value));
+                }
+            }
+        }
+    }
+
+    /// Applies the function to the value at the given position, which is zero if it is not stored.
+    fn update(&mut self, row: usize, column: usize, f: impl FnOnce(&mut T)) {
+        let mut value = self.get_ref(row, column).cloned().unwrap_or_else(T::zero);
+        f(&mut value);
+        self.store(row, column, value);
+    }
+}
+
+/// Returns `target - factor * source`, considering only the values of `source` in columns of at least `from_column`.
+/// Both rows are sorted by column, and the result is as well.
+fn subtract_multiple<T>(
+    target: Vec<(usize, T)>,
+    factor: &T,
+    source: &[(usize, T)],
+    from_column: usize,
+) -> Vec<(usize, T)>
+where
+    T: Zero + Clone + std::ops::SubAssign<T>,
+    for<'a> &'a T: Mul<&'a T, Output = T> + std::ops::Neg<Output = T>,
+{
+    let mut result = Vec::with_capacity(target.len() + source.len());
+    let mut source = source
+        .iter()
+        .filter(|(column, _)| *column >= from_column)
+        .peekable();
+    let mut target = target.into_iter().peekable();
+    loop {
+        match (target.peek(), source.peek()) {
+            (Some((t, _)), Some((s, _))) if t == s => {
+                let (column, mut value) = target.next().unwrap();
+                let (_, s) = source.next().unwrap();
+                value -= factor * s;
+                if !value.is_zero() {
+                    result.push((column, value));
+                }
+            }
+            (Some((t, _)), Some((s, _))) if t > s => {
+                let (column, s) = source.next().unwrap();
+                let value = -&(factor * s);
+                if !value.is_zero() {
+                    result.push((*column, value));
+                }
+            }
+            (Some(_), _) => result.push(target.next().unwrap()),
+            (None, Some(_)) => {
+                let (column, s) = source.next().unwrap();
+                let value = -&(factor * s);
+                if !value.is_zero() {
+                    result.push((*column, value));
+                }
+            }
+            (None, None) => return result,
+        }
+    }
+}
+
+macro_rules! sparse {
+    ($u:ident, $dense:ident) => {
+        impl EbiMatrix<$u> for SparseFractionMatrix<$u> {
+            fn new(number_of_rows: usize, number_of_columns: usize) -> Self {
+                Self {
+                    rows: vec![vec![]; number_of_rows],
+                    number_of_columns,
+                }
+            }
+
+            fn number_of_rows(&self) -> usize {
+                self.rows.len()
+            }
+
+            fn number_of_columns(&self) -> usize {
+                self.number_of_columns
+            }
+
+            fn get(&self, row: usize, column: usize) -> Option<$u> {
+                if row >= self.rows.len() || column >= self.number_of_columns {
+                    return None;
+                }
+                Some(self.get_ref(row, column).cloned().unwrap_or_else($u::zero))
+            }
+
+            fn is_one(&self, row: usize, column: usize) -> bool {
+                self.get_ref(row, column).is_some_and(|v| v.is_one())
+            }
+
+            fn is_positive(&self, row: usize, column: usize) -> bool {
+                self.get_ref(row, column).is_some_and(|v| v.is_positive())
+            }
+
+            fn is_negative(&self, row: usize, column: usize) -> bool {
+                self.get_ref(row, column).is_some_and(|v| v.is_negative())
+            }
+
+            fn set(&mut self, row: usize, column: usize, value: $u) {
+                self.store(row, column, value);
+            }
+
+            fn increase(&mut self, row: usize, column: usize, value: &$u) {
+                self.update(row, column, |v| *v += value);
+            }
+
+            fn decrease(&mut self, row: usize, column: usize, value: &$u) {
+                self.update(row, column, |v| *v -= value);
+            }
+
+            fn set_row_zero(&mut self, row: usize) {
+                self.rows[row].clear();
+            }
+
+            fn set_zero(&mut self, row: usize, column: usize) {
+                self.store(row, column, $u::zero());
+            }
+
+            fn set_one(&mut self, row: usize, column: usize) {
+                self.store(row, column, $u::one());
+            }
+
+            fn push_columns(&mut self, number_of_columns_to_add: usize) {
+                self.number_of_columns += number_of_columns_to_add;
+            }
+
+            fn push_rows(&mut self, number_of_rows_to_add: usize) {
+                self.rows
+                    .resize(self.rows.len() + number_of_rows_to_add, vec![]);
+            }
+
+            fn pop_front_columns(&mut self, number_of_columns_to_remove: usize) {
+                for row in self.rows.iter_mut() {
+                    row.retain(|(column, _)| *column >= number_of_columns_to_remove);
+                    row.iter_mut()
+                        .for_each(|(column, _)| *column -= number_of_columns_to_remove);
+                }
+                self.number_of_columns -= number_of_columns_to_remove;
+            }
+
+            fn push_row(&mut self, values: Vec<$u>) -> Result<()> {
+                if values.len() != self.number_of_columns {
+                    return Err(anyhow!(
+                        "cannot add a row of {} values to a matrix of {} columns",
+                        values.len(),
+                        self.number_of_columns
+                    ));
+                }
+                self.rows.push(
+                    values
+                        .into_iter()
+                        .enumerate()
+                        .filter(|(_, value)| !value.is_zero())
+                        .collect(),
+                );
+                Ok(())
+            }
+
+            fn remove_row(&mut self, row: usize) {
+                self.rows.remove(row);
+            }
+
+            fn remove_column(&mut self, column: usize) {
+                for row in self.rows.iter_mut() {
+                    row.retain(|(c, _)| *c != column);
+                    row.iter_mut()
+                        .filter(|(c, _)| *c > column)
+                        .for_each(|(c, _)| *c -= 1);
+                }
+                self.number_of_columns -= 1;
+            }
+
+            fn swap_rows(&mut self, row_a: usize, row_b: usize) {
+                self.rows.swap(row_a, row_b);
+            }
+
+            fn scale_row(&mut self, row: usize, factor: &$u) {
+                let row = &mut self.rows[row];
+                row.iter_mut().for_each(|(_, value)| *value *= factor);
+                row.retain(|(_, value)| !value.is_zero());
+            }
+
+            fn trace(&self) -> Result<$u> {
+                if self.rows.len() != self.number_of_columns {
+                    return Err(anyhow!(
+                        "can only take the trace of a square matrix, but got a {}x{} matrix",
+                        self.rows.len(),
+                        self.number_of_columns
+                    ));
+                }
+                //start from a stored value, such that an enum zero does not impose the global mode
+                let mut diagonal = (0..self.rows.len()).filter_map(|i| self.get_ref(i, i));
+                match diagonal.next() {
+                    Some(first) => {
+                        diagonal.try_fold(first.clone(), |sum, value| sum.try_add(value))
+                    }
+                    None => Ok($u::zero()),
+                }
+            }
+
+            fn to_vec(self) -> Vec<Vec<$u>> {
+                let number_of_columns = self.number_of_columns;
+                self.rows
+                    .into_iter()
+                    .map(|row| {
+                        let mut result = vec![$u::zero(); number_of_columns];
+                        for (column, value) in row {
+                            result[column] = value;
+                        }
+                        result
+                    })
+                    .collect()
+            }
+        }
+
+        impl PartialEq for SparseFractionMatrix<$u> {
+            fn eq(&self, other: &Self) -> bool {
+                self.rows.len() == other.rows.len()
+                    && self.number_of_columns == other.number_of_columns
+                    && self.rows.iter().enumerate().all(|(row, values)| {
+                        values.iter().all(|(column, value)| {
+                            other.get_ref(row, *column).unwrap_or(&$u::zero()) == value
+                        })
+                    })
+                    && other.rows.iter().enumerate().all(|(row, values)| {
+                        values.iter().all(|(column, value)| {
+                            self.get_ref(row, *column).unwrap_or(&$u::zero()) == value
+                        })
+                    })
+            }
+        }
+
+        impl Eq for SparseFractionMatrix<$u> {}
+
+        impl TryFrom<Vec<Vec<$u>>> for SparseFractionMatrix<$u> {
+            type Error = Error;
+
+            fn try_from(value: Vec<Vec<$u>>) -> Result<Self> {
+                let number_of_columns = value.first().map_or(0, |row| row.len());
+                let rows = value
+                    .into_iter()
+                    .map(|row| {
+                        if row.len() != number_of_columns {
+                            return Err(anyhow!("number of columns is not consistent"));
+                        }
+                        Ok(row
+                            .into_iter()
+                            .enumerate()
+                            .filter(|(_, value)| !value.is_zero())
+                            .collect())
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(Self {
+                    rows,
+                    number_of_columns,
+                })
+            }
+        }
+
+        impl From<&$dense> for SparseFractionMatrix<$u> {
+            fn from(value: &$dense) -> Self {
+                Self {
+                    rows: (0..value.number_of_rows())
+                        .map(|row| {
+                            (0..value.number_of_columns())
+                                .filter_map(|column| {
+                                    let v = value.get(row, column)?;
+                                    (!v.is_zero()).then_some((column, v))
+                                })
+                                .collect()
+                        })
+                        .collect(),
+                    number_of_columns: value.number_of_columns(),
+                }
+            }
+        }
+
+        impl From<&SparseFractionMatrix<$u>> for $dense {
+            fn from(value: &SparseFractionMatrix<$u>) -> Self {
+                let mut result = $dense::new(value.number_of_rows(), value.number_of_columns());
+                for (row, values) in value.rows.iter().enumerate() {
+                    for (column, v) in values {
+                        result.set(row, *column, v.clone());
+                    }
+                }
+                result
+            }
+        }
+
+        impl IdentityMinus for SparseFractionMatrix<$u> {
+            fn identity_minus(&mut self) {
+                for (i, row) in self.rows.iter_mut().enumerate() {
+                    row.iter_mut().for_each(|(_, value)| *value = -&*value);
+                    if i < self.number_of_columns {
+                        match row.binary_search_by_key(&i, |(c, _)| *c) {
+                            Ok(j) => {
+                                row[j].1 += $u::one();
+                                if row[j].1.is_zero() {
+                                    row.remove(j);
+                                }
+                            }
+                            Err(j) => row.insert(j, (i, $u::one())),
+                        }
+                    }
+                }
+            }
+        }
+
+        impl GaussJordan for SparseFractionMatrix<$u> {
+            fn gauss_jordan(&mut self) {
+                let number_of_rows = self.rows.len();
+
+                //eliminate below the diagonal
+                for row_a in 0..number_of_rows.saturating_sub(1) {
+                    //swap in the row with the best pivot, as in the dense reduction
+                    let mut pivot_row: Option<usize> = None;
+                    for row in row_a..number_of_rows {
+                        if let Some(value) = self.get_ref(row, row_a)
+                            && pivot_row.is_none_or(|p| {
+                                value.is_better_pivot_than(self.get_ref(p, row_a).unwrap())
+                            })
+                        {
+                            pivot_row = Some(row);
+                        }
+                    }
+                    let Some(pivot_row) = pivot_row else {
+                        continue;
+                    };
+                    self.rows.swap(row_a, pivot_row);
+                    let pivot = self.get_ref(row_a, row_a).unwrap().clone();
+                    let (upper, lower) = self.rows.split_at_mut(row_a + 1);
+                    let source = &upper[row_a];
+                    for target in lower.iter_mut() {
+                        if let Ok(i) = target.binary_search_by_key(&row_a, |(c, _)| *c) {
+                            let factor = &target[i].1 / &pivot;
+                            let old = std::mem::take(target);
+                            *target = subtract_multiple(old, &factor, source, row_a);
+                        }
+                    }
+                }
+
+                //eliminate above the diagonal
+                for i in (0..number_of_rows).rev() {
+                    let Some(pivot) = self.get_ref(i, i).cloned() else {
+                        continue;
+                    };
+                    let (upper, lower) = self.rows.split_at_mut(i);
+                    let source = &lower[0];
+                    for target in upper.iter_mut().rev() {
+                        if let Ok(j) = target.binary_search_by_key(&i, |(c, _)| *c) {
+                            let factor = &target[j].1 / &pivot;
+                            let old = std::mem::take(target);
+                            *target = subtract_multiple(old, &factor, source, i);
+                        }
+                    }
+                }
+            }
+
+            fn gauss_jordan_reduced(mut self) -> Result<Self> {
+                self.gauss_jordan();
+
+                let number_of_rows = self.rows.len();
+                let mut failed = false;
+                for i in 0..number_of_rows.min(self.number_of_columns) {
+                    let Some(pivot) = self.get_ref(i, i).cloned() else {
+                        failed = true;
+                        continue;
+                    };
+                    for (column, value) in self.rows[i].iter_mut() {
+                        if *column >= number_of_rows {
+                            *value /= &pivot;
+                        } else if *column == i {
+                            *value = $u::one();
+                        }
+                    }
+                }
+
+                if failed {
+                    return Err(anyhow!("matrix has no reduced row-echelon form"));
+                }
+                Ok(self)
+            }
+        }
+
+        impl Mul for &SparseFractionMatrix<$u> {
+            type Output = Result<SparseFractionMatrix<$u>>;
+
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            fn mul(self, rhs: Self) -> Self::Output {
+                if self.number_of_columns != rhs.rows.len() {
+                    return Err(anyhow!(
+                        "cannot multiply matrix of size {}x{} with a matrix of size {}x{}",
+                        self.rows.len(),
+                        self.number_of_columns,
+                        rhs.rows.len(),
+                        rhs.number_of_columns
+                    ));
+                }
+
+                //row i of the product is the sum of the rows k of rhs, weighted by the values (i, k) of self;
+                //each sum starts from its first product, such that an enum zero does not impose the global mode
+                let mut accumulator: Vec<Option<$u>> = vec![None; rhs.number_of_columns];
+                let rows = self
+                    .rows
+                    .iter()
+                    .map(|row| {
+                        let mut columns = vec![];
+                        for (k, a) in row {
+                            for (column, b) in &rhs.rows[*k] {
+                                match &mut accumulator[*column] {
+                                    Some(sum) => *sum += a * b,
+                                    None => {
+                                        accumulator[*column] = Some(a * b);
+                                        columns.push(*column);
+                                    }
+                                }
+                            }
+                        }
+                        columns.sort_unstable();
+                        columns
+                            .into_iter()
+                            .filter_map(|column| {
+                                let value = accumulator[column].take()?;
+                                (!value.is_zero()).then_some((column, value))
+                            })
+                            .collect()
+                    })
+                    .collect();
+
+                Ok(SparseFractionMatrix {
+                    rows,
+                    number_of_columns: rhs.number_of_columns,
+                })
+            }
+        }
+
+        impl Mul<&Vec<$u>> for &SparseFractionMatrix<$u> {
+            type Output = Result<Vec<$u>>;
+
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            fn mul(self, rhs: &Vec<$u>) -> Self::Output {
+                if self.number_of_columns != rhs.len() {
+                    return Err(anyhow!(
+                        "cannot multiply matrix of size {}x{} with a vector of size {}",
+                        self.rows.len(),
+                        self.number_of_columns,
+                        rhs.len(),
+                    ));
+                }
+
+                //each sum starts from its first product, such that an enum zero does not impose the global mode
+                Ok(self
+                    .rows
+                    .iter()
+                    .map(|row| {
+                        sum_of_products(row.iter().map(|(column, value)| (value, &rhs[*column])))
+                    })
+                    .collect())
+            }
+        }
+
+        impl Mul<&SparseFractionMatrix<$u>> for &Vec<$u> {
+            type Output = Result<Vec<$u>>;
+
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            fn mul(self, rhs: &SparseFractionMatrix<$u>) -> Self::Output {
+                if self.len() != rhs.rows.len() {
+                    return Err(anyhow!(
+                        "cannot multiply a vector of size {} with a matrix of size {}x{}",
+                        self.len(),
+                        rhs.rows.len(),
+                        rhs.number_of_columns,
+                    ));
+                }
+
+                //each sum starts from its first product, such that an enum zero does not impose the global mode
+                let mut result: Vec<Option<$u>> = vec![None; rhs.number_of_columns];
+                for (x, row) in self.iter().zip(rhs.rows.iter()) {
+                    if x.is_zero() {
+                        continue;
+                    }
+                    for (column, value) in row {
+                        match &mut result[*column] {
+                            Some(sum) => *sum += value * x,
+                            None => result[*column] = Some(value * x),
+                        }
+                    }
+                }
+                Ok(result
+                    .into_iter()
+                    .map(|sum| sum.unwrap_or_else($u::zero))
+                    .collect())
+            }
+        }
+    };
+}
+
+sparse!(FractionExact, FractionMatrixExact);
+sparse!(FractionF64, FractionMatrixF64);
+sparse!(FractionEnum, FractionMatrixEnum);
+
+impl MaybeExact for SparseFractionMatrix<FractionExact> {
+    type Approximate = SparseFractionMatrix<FractionF64>;
+    type Exact = SparseFractionMatrix<FractionExact>;
+
+    fn is_exact(&self) -> bool {
+        true
+    }
+
+    fn approx_ref(&self) -> Result<&Self::Approximate> {
+        Err(anyhow!("cannot extract a float from a fraction"))
+    }
+
+    fn exact_ref(&self) -> Result<&Self::Exact> {
+        Ok(self)
+    }
+
+    fn approx(self) -> Result<Self::Approximate> {
+        Err(anyhow!("cannot extract a float from a fraction"))
+    }
+
+    fn exact(self) -> Result<Self::Exact> {
+        Ok(self)
+    }
+
+    fn try_to_exact(exact: Self::Exact) -> Result<Self> {
+        Ok(exact)
+    }
+
+    fn try_to_approx(_: Self::Approximate) -> Result<Self> {
+        Err(anyhow!("cannot put float in a fraction"))
+    }
+}
+
+impl MaybeExact for SparseFractionMatrix<FractionF64> {
+    type Approximate = SparseFractionMatrix<FractionF64>;
+    type Exact = SparseFractionMatrix<FractionExact>;
+
+    fn is_exact(&self) -> bool {
+        false
+    }
+
+    fn approx_ref(&self) -> Result<&Self::Approximate> {
+        Ok(self)
+    }
+
+    fn exact_ref(&self) -> Result<&Self::Exact> {
+        Err(anyhow!("cannot extract a fraction from a float"))
+    }
+
+    fn approx(self) -> Result<Self::Approximate> {
+        Ok(self)
+    }
+
+    fn exact(self) -> Result<Self::Exact> {
+        Err(anyhow!("cannot extract a fraction from a float"))
+    }
+
+    fn try_to_exact(_: Self::Exact) -> Result<Self> {
+        Err(anyhow!("cannot put fraction in a float"))
+    }
+
+    fn try_to_approx(approx: Self::Approximate) -> Result<Self> {
+        Ok(approx)
+    }
+}
+
+/// The mode of an enum sparse matrix is that of its values; a matrix without values follows the global mode.
+impl MaybeExact for SparseFractionMatrix<FractionEnum> {
+    type Approximate = SparseFractionMatrix<FractionEnum>;
+    type Exact = SparseFractionMatrix<FractionEnum>;
+
+    fn is_exact(&self) -> bool {
+        match self.rows.iter().flatten().next() {
+            Some((_, value)) => value.is_exact(),
+            None => crate::is_exact_globally(),
+        }
+    }
+
+    fn approx_ref(&self) -> Result<&Self::Approximate> {
+        if self.is_exact() {
+            Err(anyhow!("cannot extract a float from a fraction"))
+        } else {
+            Ok(self)
+        }
+    }
+
+    fn exact_ref(&self) -> Result<&Self::Exact> {
+        if self.is_exact() {
+            Ok(self)
+        } else {
+            Err(anyhow!("cannot extract a fraction from a float"))
+        }
+    }
+
+    fn approx(self) -> Result<Self::Approximate> {
+        self.approx_ref()?;
+        Ok(self)
+    }
+
+    fn exact(self) -> Result<Self::Exact> {
+        self.exact_ref()?;
+        Ok(self)
+    }
+
+    fn try_to_exact(exact: Self::Exact) -> Result<Self> {
+        exact.exact()
+    }
+
+    fn try_to_approx(approx: Self::Approximate) -> Result<Self> {
+        approx.approx()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use malachite::rational::Rational;
+
+    use crate::{
+        EbiMatrix, GaussJordan, IdentityMinus, MaybeExact,
+        exact::{Exactness, ExactnessScope},
+        fraction::{fraction_enum::FractionEnum, fraction_exact::FractionExact},
+        matrix::{
+            fraction_matrix_enum::FractionMatrixEnum, fraction_matrix_exact::FractionMatrixExact,
+            sparse_fraction_matrix::SparseFractionMatrix,
+        },
+    };
+
+    fn dense() -> FractionMatrixExact {
+        vec![
+            vec![(1, 2).into(), 0.into(), 0.into(), 0.into()],
+            vec![0.into(), 0.into(), (1, 3).into(), 0.into()],
+            vec![(1, 4).into(), 0.into(), 1.into(), (1, 5).into()],
+        ]
+        .try_into()
+        .unwrap()
+    }
+
+    #[test]
+    fn sparse_conversions() {
+        let d = dense();
+        let mut s = SparseFractionMatrix::from(&d);
+        assert_eq!(s.number_of_non_zeroes(), 5);
+        assert_eq!(FractionMatrixExact::from(&s), d);
+        assert_eq!(s.clone().to_vec(), d.clone().to_vec());
+
+        s.set(0, 0, 0.into());
+        assert_eq!(s.number_of_non_zeroes(), 4);
+        s.increase(1, 1, &FractionExact::from(2));
+        assert_eq!(s.get(1, 1), Some(2.into()));
+        s.decrease(1, 1, &FractionExact::from(2));
+        assert_eq!(s.number_of_non_zeroes(), 4);
+
+        s.pop_front_columns(2);
+        assert_eq!(s.number_of_columns(), 2);
+        assert_eq!(s.get(2, 1), Some((1, 5).into()));
+    }
+
+    #[test]
+    fn sparse_rows_columns() {
+        let mut d = dense();
+        let mut s = SparseFractionMatrix::from(&d);
+        assert_eq!(s.get_row(2), d.get_row(2));
+        assert_eq!(s.get_column(2), d.get_column(2));
+        assert_eq!(s.get_diagonal(), d.get_diagonal());
+        assert!(s.trace().is_err());
+
+        fn manipulate<M: EbiMatrix<FractionExact>>(m: &mut M) {
+            m.remove_column(1);
+            m.swap_rows(0, 2);
+            m.remove_row(1);
+            m.scale_row(0, &FractionExact::from(0));
+            m.push_row(vec![0.into(), 2.into(), 0.into()]).unwrap();
+        }
+        manipulate(&mut d);
+        manipulate(&mut s);
+        assert_eq!(FractionMatrixExact::from(&s), d);
+        assert_eq!(s.number_of_non_zeroes(), 2);
+        assert_eq!(s.trace().unwrap(), d.trace().unwrap());
+    }
+
+    #[test]
+    fn sparse_mul() {
+        let a = dense();
+        let b: FractionMatrixExact = vec![
+            vec![1.into(), 2.into()],
+            vec![0.into(), 0.into()],
+            vec![3.into(), 0.into()],
+            vec![0.into(), (1, 7).into()],
+        ]
+        .try_into()
+        .unwrap();
+        let product = (&SparseFractionMatrix::from(&a) * &SparseFractionMatrix::from(&b)).unwrap();
+        assert_eq!(FractionMatrixExact::from(&product), (&a * &b).unwrap());
+
+        let v: Vec<FractionExact> = vec![1.into(), 2.into(), 3.into(), 4.into()];
+        assert_eq!(
+            (&SparseFractionMatrix::from(&a) * &v).unwrap(),
+            (&a * &v).unwrap()
+        );
+        let v: Vec<FractionExact> = vec![1.into(), 2.into(), 3.into()];
+        assert_eq!(
+            (&v * &SparseFractionMatrix::from(&a)).unwrap(),
+            (&v * &a).unwrap()
+        );
+    }
+
+    #[test]
+    fn sparse_gauss_jordan() {
+        let mut a: FractionMatrixExact = vec![
+            vec![(1, 2).into(), 0.into(), (1, 4).into()],
+            vec![0.into(), (1, 3).into(), 0.into()],
+            vec![(1, 4).into(), (1, 5).into(), 0.into()],
+        ]
+        .try_into()
+        .unwrap();
+        a.identity_minus();
+        let mut s = SparseFractionMatrix::from(&a);
+        s.push_columns(1);
+        s.set_one(0, 3);
+        a.push_columns(1);
+        a.set_one(0, 3);
+
+        let s = s.gauss_jordan_reduced().unwrap();
+        let a = a.gauss_jordan_reduced().unwrap();
+        assert_eq!(FractionMatrixExact::from(&s), a);
+    }
+
+    #[test]
+    fn sparse_gauss_jordan_zero_diagonal() {
+        let a: FractionMatrixExact = vec![
+            vec![0.into(), 1.into(), 5.into()],
+            vec![1.into(), 0.into(), 7.into()],
+        ]
+        .try_into()
+        .unwrap();
+        let s = SparseFractionMatrix::from(&a)
+            .gauss_jordan_reduced()
+            .unwrap();
+        let a = a.gauss_jordan_reduced().unwrap();
+        assert_eq!(FractionMatrixExact::from(&s), a);
+        assert_eq!(a.get(0, 2), Some(7.into()));
+
+        let a: FractionMatrixExact = vec![
+            vec![0.into(), 2.into(), 1.into(), 1.into()],
+            vec![0.into(), 1.into(), 0.into(), 2.into()],
+            vec![3.into(), 0.into(), 1.into(), 3.into()],
+        ]
+        .try_into()
+        .unwrap();
+        let s = SparseFractionMatrix::from(&a)
+            .gauss_jordan_reduced()
+            .unwrap();
+        assert_eq!(
+            FractionMatrixExact::from(&s),
+            a.gauss_jordan_reduced().unwrap()
+        );
+
+        //no pivot in the second column
+        let a: FractionMatrixExact = vec![
+            vec![0.into(), 0.into(), 1.into()],
+            vec![1.into(), 0.into(), 1.into()],
+        ]
+        .try_into()
+        .unwrap();
+        assert!(
+            SparseFractionMatrix::from(&a)
+                .gauss_jordan_reduced()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn sparse_mul_scoped_mode() {
+        let a: FractionMatrixEnum = vec![
+            vec![
+                FractionEnum::Exact(Rational::from_signeds(1, 2)),
+                FractionEnum::Exact(0.into()),
+            ],
+            vec![
+                FractionEnum::Exact(1.into()),
+                FractionEnum::Exact(Rational::from_signeds(1, 3)),
+            ],
+        ]
+        .try_into()
+        .unwrap();
+        let s = SparseFractionMatrix::from(&a);
+        let v = vec![FractionEnum::Exact(2.into()), FractionEnum::Exact(3.into())];
+
+        let _scope = ExactnessScope::enter(Exactness::Approximate);
+        let product = (&s * &v).unwrap();
+        assert!(product.iter().all(FractionEnum::is_exact), "{:?}", product);
+        assert_eq!(product[1], FractionEnum::Exact(3.into()));
+        let product = (&v * &s).unwrap();
+        assert!(product.iter().all(FractionEnum::is_exact), "{:?}", product);
+        let product = (&s * &s).unwrap();
+        assert!(product.is_exact());
+        assert!(
+            FractionMatrixEnum::from(&product)
+                .to_vec()
+                .iter()
+                .flatten()
+                .all(|value| !matches!(value, FractionEnum::CannotCombineExactAndApprox))
+        );
+    }
+}
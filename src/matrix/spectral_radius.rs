@@ -0,0 +1,178 @@
+use anyhow::{Result, anyhow};
+use malachite::{
+    base::{
+        num::{arithmetic::traits::Abs, conversion::traits::RoundingFrom},
+        rounding_modes::RoundingMode,
+    },
+    rational::Rational,
+};
+
+use crate::{
+    SpectralRadiusBound,
+    fraction::{
+        exact::float_to_rational, fraction_enum::FractionEnum, fraction_exact::FractionExact,
+        fraction_f64::FractionF64,
+    },
+    matrix::{
+        fraction_matrix_enum::FractionMatrixEnum, fraction_matrix_exact::FractionMatrixExact,
+        fraction_matrix_f64::FractionMatrixF64,
+    },
+};
+
+/// Returns the exact sum of each row of absolute values, or an error if the matrix is not square.
+fn absolute_row_sums(
+    values: impl Iterator<Item = Result<Rational>>,
+    number_of_rows: usize,
+    number_of_columns: usize,
+) -> Result<Vec<Rational>> {
+    if number_of_rows != number_of_columns {
+        return Err(anyhow!(
+            "the spectral radius is only defined for square matrices, but got a {}x{} matrix",
+            number_of_rows,
+            number_of_columns
+        ));
+    }
+    let mut sums = vec![Rational::from(0); number_of_rows];
+    for (i, value) in values.enumerate() {
+        sums[i / number_of_columns] += value?.abs();
+    }
+    Ok(sums)
+}
+
+/// Returns whether all values are non-negative and each row sums to less than one.
+fn is_substochastic_strict(
+    values: impl Iterator<Item = Result<Rational>>,
+    number_of_rows: usize,
+    number_of_columns: usize,
+) -> bool {
+    if number_of_rows != number_of_columns {
+        return false;
+    }
+    let mut sums = vec![Rational::from(0); number_of_rows];
+    for (i, value) in values.enumerate() {
+        match value {
+            Ok(value) if value >= 0u32 => sums[i / number_of_columns] += value,
+            _ => return false,
+        }
+    }
+    sums.iter().all(|sum| *sum < 1u32)
+}
+
+impl SpectralRadiusBound<FractionF64> for FractionMatrixF64 {
+    fn spectral_radius_upper_bound(&self) -> Result<FractionF64> {
+        let sums = absolute_row_sums(
+            self.values.iter().map(|f| float_to_rational(*f)),
+            self.number_of_rows,
+            self.number_of_columns,
+        )?;
+        let max = sums.into_iter().max().unwrap_or_default();
+        Ok(FractionF64(
+            f64::rounding_from(max, RoundingMode::Ceiling).0,
+        ))
+    }
+
+    fn is_substochastic_strict(&self) -> bool {
+        is_substochastic_strict(
+            self.values.iter().map(|f| float_to_rational(*f)),
+            self.number_of_rows,
+            self.number_of_columns,
+        )
+    }
+}
+
+impl SpectralRadiusBound<FractionExact> for FractionMatrixExact {
+    fn spectral_radius_upper_bound(&self) -> Result<FractionExact> {
+        let sums = absolute_row_sums(
+            self.values.iter().map(|f| Ok(f.clone())),
+            self.number_of_rows,
+            self.number_of_columns,
+        )?;
+        Ok(FractionExact(sums.into_iter().max().unwrap_or_default()))
+    }
+
+    fn is_substochastic_strict(&self) -> bool {
+        is_substochastic_strict(
+            self.values.iter().map(|f| Ok(f.clone())),
+            self.number_of_rows,
+            self.number_of_columns,
+        )
+    }
+}
+
+impl SpectralRadiusBound<FractionEnum> for FractionMatrixEnum {
+    fn spectral_radius_upper_bound(&self) -> Result<FractionEnum> {
+        match self {
+            FractionMatrixEnum::Approx(m) => {
+                Ok(FractionEnum::Approx(m.spectral_radius_upper_bound()?.0))
+            }
+            FractionMatrixEnum::Exact(m) => {
+                Ok(FractionEnum::Exact(m.spectral_radius_upper_bound()?.0))
+            }
+            FractionMatrixEnum::CannotCombineExactAndApprox => {
+                Err(anyhow!("cannot combine exact and approximate arithmetic"))
+            }
+        }
+    }
+
+    fn is_substochastic_strict(&self) -> bool {
+        match self {
+            FractionMatrixEnum::Approx(m) => m.is_substochastic_strict(),
+            FractionMatrixEnum::Exact(m) => m.is_substochastic_strict(),
+            FractionMatrixEnum::CannotCombineExactAndApprox => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        EbiMatrix, SpectralRadiusBound,
+        fraction::{fraction_exact::FractionExact, fraction_f64::FractionF64},
+        matrix::{
+            fraction_matrix_exact::FractionMatrixExact, fraction_matrix_f64::FractionMatrixF64,
+        },
+    };
+
+    #[test]
+    fn spectral_radius_upper_bound() {
+        let m: FractionMatrixExact = vec![
+            vec![(1, 2).into(), (1, 3).into()],
+            vec![(-1, 4).into(), (1, 4).into()],
+        ]
+        .try_into()
+        .unwrap();
+        assert_eq!(
+            m.spectral_radius_upper_bound().unwrap(),
+            FractionExact::from((5, 6))
+        );
+        assert!(!m.is_substochastic_strict());
+        assert!(m.clone().abs().is_substochastic_strict());
+
+        let n: FractionMatrixExact =
+            vec![vec![(1, 2).into(), (1, 2).into()], vec![0.into(), 0.into()]]
+                .try_into()
+                .unwrap();
+        assert!(!n.is_substochastic_strict());
+
+        let r: FractionMatrixExact = vec![vec![1.into(), 2.into()]].try_into().unwrap();
+        assert!(r.spectral_radius_upper_bound().is_err());
+        assert!(!r.is_substochastic_strict());
+
+        //0.1 + 0.2 is slightly above 0.3 exactly, and the bound rounds up
+        let m: FractionMatrixF64 = vec![vec![0.1.into(), 0.2.into()], vec![0.into(), 0.into()]]
+            .try_into()
+            .unwrap();
+        let bound = m.spectral_radius_upper_bound().unwrap();
+        assert!(bound.0 >= 0.1 + 0.2);
+        assert!(m.is_substochastic_strict());
+        let m: FractionMatrixF64 = vec![vec![f64::NAN.into()]].try_into().unwrap();
+        assert!(m.spectral_radius_upper_bound().is_err());
+        assert!(!m.is_substochastic_strict());
+        assert_eq!(
+            FractionMatrixF64::new(0, 0)
+                .spectral_radius_upper_bound()
+                .unwrap(),
+            FractionF64::from(0.0)
+        );
+    }
+}
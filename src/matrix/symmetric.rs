@@ -0,0 +1,217 @@
+use anyhow::Result;
+use malachite::rational::Rational;
+
+use crate::{
+    One, Symmetric, Zero,
+    matrix::{
+        fraction_matrix_enum::FractionMatrixEnum, fraction_matrix_exact::FractionMatrixExact,
+        fraction_matrix_f64::FractionMatrixF64,
+    },
+    size_guard::check_exact_size,
+};
+
+/// `$check` is applied to the input and the intermediate values after each column; the decomposition is abandoned if `$accept_pivot` rejects a pivot.
+macro_rules! symmetric {
+    ($t:ident, $v:ident, $check:expr, $accept_pivot:expr) => {
+        impl Symmetric for $t {
+            fn is_symmetric(&self) -> bool {
+                if self.number_of_rows != self.number_of_columns {
+                    return false;
+                }
+                for row in 0..self.number_of_rows {
+                    for column in 0..row {
+                        let mut difference = self.values[self.index(row, column)].clone();
+                        difference -= &self.values[self.index(column, row)];
+                        if !self.is_zero_value(&difference) {
+                            return false;
+                        }
+                    }
+                }
+                true
+            }
+        }
+
+        impl $t {
+            /// Returns whether the matrix is square and equal to its transpose, without tolerance.
+            pub(crate) fn is_exactly_symmetric(&self) -> bool {
+                self.number_of_rows == self.number_of_columns
+                    && (0..self.number_of_rows).all(|row| {
+                        (0..row).all(|column| {
+                            self.values[self.index(row, column)]
+                                == self.values[self.index(column, row)]
+                        })
+                    })
+            }
+
+            /// Inverts a symmetric matrix using an LDLᵀ decomposition, which reads only the lower triangle and computes only the lower triangle of the inverse.
+            /// Does not pivot; returns None if a pivot is zero or rejected, in which case the caller should fall back to general elimination.
+            pub(crate) fn invert_symmetric(&self) -> Result<Option<Self>> {
+                let check = $check;
+                let accept_pivot = $accept_pivot;
+                let n = self.number_of_rows;
+
+                //decompose A = L D Lᵀ, with L unit lower triangular and D diagonal
+                let mut l = vec![$v::zero(); n * n];
+                let mut d: Vec<$v> = Vec::with_capacity(n);
+                for j in 0..n {
+                    let mut pivot = self.values[self.index(j, j)].clone();
+                    for k in 0..j {
+                        pivot -= &l[j * n + k] * &l[j * n + k] * &d[k];
+                    }
+                    if self.is_zero_value(&pivot) || !accept_pivot(&pivot) {
+                        return Ok(None);
+                    }
+
+                    for i in j + 1..n {
+                        let mut value = self.values[self.index(i, j)].clone();
+                        for k in 0..j {
+                            value -= &l[i * n + k] * &l[j * n + k] * &d[k];
+                        }
+                        value /= &pivot;
+                        l[i * n + j] = value;
+                    }
+                    d.push(pivot);
+                    check(&[&self.values, &l, &d])?;
+                }
+
+                //X = L⁻¹, which is unit lower triangular as well
+                let mut x = vec![$v::zero(); n * n];
+                for j in 0..n {
+                    x[j * n + j] = $v::one();
+                    for i in j + 1..n {
+                        let mut value = $v::zero();
+                        for k in j..i {
+                            value -= &l[i * n + k] * &x[k * n + j];
+                        }
+                        x[i * n + j] = value;
+                    }
+                    check(&[&self.values, &l, &d, &x])?;
+                }
+
+                //A⁻¹ = Xᵀ D⁻¹ X; compute the lower triangle and mirror it
+                let mut values = vec![$v::zero(); n * n];
+                for i in 0..n {
+                    for j in 0..=i {
+                        let mut value = $v::zero();
+                        for k in i..n {
+                            value += &x[k * n + i] * &x[k * n + j] / &d[k];
+                        }
+                        values[j * n + i] = value.clone();
+                        values[i * n + j] = value;
+                    }
+                }
+
+                Ok(Some(self.with_values(values, n, n)))
+            }
+        }
+    };
+}
+
+//approximate pivots must be positive, which proves the matrix positive definite, for which the decomposition is stable without pivoting
+symmetric!(
+    FractionMatrixF64,
+    f64,
+    |_: &[&[f64]]| -> Result<()> { Ok(()) },
+    |pivot: &f64| *pivot > 0.0
+);
+symmetric!(
+    FractionMatrixExact,
+    Rational,
+    check_exact_size,
+    |_: &Rational| true
+);
+
+impl Symmetric for FractionMatrixEnum {
+    fn is_symmetric(&self) -> bool {
+        match self {
+            FractionMatrixEnum::Approx(m) => m.is_symmetric(),
+            FractionMatrixEnum::Exact(m) => m.is_symmetric(),
+            FractionMatrixEnum::CannotCombineExactAndApprox => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        EbiMatrix, Inversion, Symmetric,
+        fraction::{fraction_exact::FractionExact, fraction_f64::FractionF64},
+        matrix::{
+            fraction_matrix_exact::FractionMatrixExact, fraction_matrix_f64::FractionMatrixF64,
+        },
+    };
+
+    #[test]
+    fn symmetric_inverse() {
+        let m: FractionMatrixExact = vec![
+            vec![4.into(), 1.into(), (1, 2).into()],
+            vec![1.into(), 3.into(), 0.into()],
+            vec![(1, 2).into(), 0.into(), 2.into()],
+        ]
+        .try_into()
+        .unwrap();
+        assert!(m.is_symmetric());
+
+        let inverse = m.invert_symmetric().unwrap().unwrap();
+        assert!(inverse.is_symmetric());
+        let mut identity = FractionMatrixExact::new(3, 3);
+        (0..3).for_each(|i| identity.set_one(i, i));
+        assert_eq!((&m * &inverse).unwrap(), identity);
+
+        //a zero pivot is left to general elimination
+        let m: FractionMatrixExact = vec![
+            vec![0.into(), 1.into(), 0.into()],
+            vec![1.into(), 0.into(), 0.into()],
+            vec![0.into(), 0.into(), FractionExact::from(2)],
+        ]
+        .try_into()
+        .unwrap();
+        assert!(m.is_symmetric());
+        assert!(m.invert_symmetric().unwrap().is_none());
+
+        let m: FractionMatrixF64 = vec![
+            vec![4.into(), 1.into(), 0.into()],
+            vec![1.into(), 3.into(), 1.into()],
+            vec![0.into(), 1.into(), 2.into()],
+        ]
+        .try_into()
+        .unwrap();
+        assert!(m.is_symmetric());
+        let inverse = m.clone().invert().unwrap();
+        let mut identity = FractionMatrixF64::new(3, 3);
+        (0..3).for_each(|i| identity.set_one(i, i));
+        assert_eq!((&m * &inverse).unwrap(), identity);
+
+        let m: FractionMatrixF64 = vec![vec![1.into(), 2.into()], vec![3.into(), 4.into()]]
+            .try_into()
+            .unwrap();
+        assert!(!m.is_symmetric());
+
+        //an indefinite matrix with a tiny pivot is left to general elimination
+        let m: FractionMatrixF64 = vec![
+            vec![FractionF64::from(1e-12), 1.into(), 0.into()],
+            vec![1.into(), FractionF64::from(1e-12), 1.into()],
+            vec![0.into(), 1.into(), 1.into()],
+        ]
+        .try_into()
+        .unwrap();
+        assert!(m.invert_symmetric().unwrap().is_none());
+        let inverse = m.clone().invert().unwrap();
+        assert_eq!((&m * &inverse).unwrap(), identity);
+
+        //a nearly symmetric matrix is not inverted from its lower triangle
+        let mut m: FractionMatrixF64 = vec![
+            vec![4.into(), 1.into(), 0.into()],
+            vec![FractionF64::from(1.001), 3.into(), 1.into()],
+            vec![0.into(), 1.into(), 2.into()],
+        ]
+        .try_into()
+        .unwrap();
+        m.set_epsilon(1e-2);
+        assert!(m.is_symmetric());
+        assert!(!m.is_exactly_symmetric());
+        let inverse = m.clone().invert().unwrap();
+        m.set_epsilon(1e-13);
+        assert_eq!((&m * &inverse).unwrap(), identity);
+    }
+}
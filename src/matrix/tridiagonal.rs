@@ -0,0 +1,83 @@
+use crate::matrix::{
+    fraction_matrix_exact::FractionMatrixExact, fraction_matrix_f64::FractionMatrixF64,
+};
+
+macro_rules! tridiagonal {
+    ($t:ident) => {
+        impl $t {
+            /// Returns whether the matrix is square and all its non-zero values are on the diagonal, directly above it or directly below it.
+            /// Such systems are solved with elimination within the band, see [bandwidths](Self::bandwidths).
+            pub fn is_tridiagonal(&self) -> bool {
+                if self.number_of_rows != self.number_of_columns {
+                    return false;
+                }
+                let (lower, upper) = self.bandwidths();
+                lower <= 1 && upper <= 1
+            }
+        }
+    };
+}
+
+tridiagonal!(FractionMatrixF64);
+tridiagonal!(FractionMatrixExact);
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Solve,
+        fraction::{fraction_exact::FractionExact, fraction_f64::FractionF64},
+        matrix::{
+            fraction_matrix_exact::FractionMatrixExact, fraction_matrix_f64::FractionMatrixF64,
+        },
+    };
+
+    #[test]
+    fn tridiagonal() {
+        //a birth-death chain
+        let m: FractionMatrixExact = vec![
+            vec![2.into(), (-1).into(), 0.into(), 0.into()],
+            vec![(-1).into(), 2.into(), (-1).into(), 0.into()],
+            vec![0.into(), (-1).into(), 2.into(), (-1).into()],
+            vec![0.into(), 0.into(), (-1).into(), 2.into()],
+        ]
+        .try_into()
+        .unwrap();
+        assert!(m.is_tridiagonal());
+        let rhs: Vec<FractionExact> = vec![1.into(), 0.into(), 0.into(), 1.into()];
+        let x = m.solve_banded(&rhs.iter().map(|f| f.0.clone()).collect::<Vec<_>>());
+        assert!(x.is_some());
+        assert_eq!(m.solve(&rhs).unwrap(), vec![FractionExact::from(1); 4]);
+
+        let m: FractionMatrixF64 = vec![
+            vec![4.into(), 1.into(), 0.into()],
+            vec![1.into(), 4.into(), 1.into()],
+            vec![0.into(), 1.into(), 4.into()],
+        ]
+        .try_into()
+        .unwrap();
+        assert!(m.solve_banded(&[5.0, 6.0, 5.0]).is_some());
+        assert_eq!(
+            m.solve(&[5.into(), 6.into(), 5.into()]).unwrap(),
+            vec![FractionF64::from(1.0); 3]
+        );
+
+        //a zero pivot falls back to general elimination
+        let m: FractionMatrixExact = vec![vec![0.into(), 1.into()], vec![1.into(), 0.into()]]
+            .try_into()
+            .unwrap();
+        assert!(m.solve_banded(&[1.into(), 2.into()]).is_none());
+        assert_eq!(
+            m.solve(&[1.into(), 2.into()]).unwrap(),
+            vec![FractionExact::from(2), 1.into()]
+        );
+
+        let m: FractionMatrixExact = vec![
+            vec![1.into(), 0.into(), 1.into()],
+            vec![0.into(), 1.into(), 0.into()],
+            vec![0.into(), 0.into(), 1.into()],
+        ]
+        .try_into()
+        .unwrap();
+        assert!(!m.is_tridiagonal());
+    }
+}
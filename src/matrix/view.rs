@@ -0,0 +1,485 @@
+//! Borrowed rectangular windows of matrices.
+//!
+//! A [MatrixView] reads a block of rows and columns of a matrix in place, for instance the transient block Q of an absorbing Markov chain in canonical form.
+//! A [MatrixViewMut] additionally applies Gauss-Jordan elimination to the block.
+//! Neither copies the values of the matrix, except to reduce a block, which is reduced on a copy that is written back only if reduction succeeds; use `to_matrix` to obtain an owned copy of the block.
+
+use anyhow::{Result, anyhow};
+use malachite::rational::Rational;
+use std::ops::{Index, IndexMut, Mul, Range};
+
+use crate::{
+    EbiMatrix, GaussJordan, Zero,
+    fraction::fraction::is_approximately_zero,
+    matrix::{
+        fraction_matrix_enum::FractionMatrixEnum,
+        fraction_matrix_exact::FractionMatrixExact,
+        fraction_matrix_f64::FractionMatrixF64,
+        gauss_jordan::{gauss_jordan, reduction_error},
+        mul::{Operand, product_exact, product_f64},
+    },
+};
+
+/// A read-only window of the rows and columns of a matrix.
+#[derive(Debug)]
+pub struct MatrixView<'a, M> {
+    matrix: &'a M,
+    rows: Range<usize>,
+    columns: Range<usize>,
+}
+
+/// A window of the rows and columns of a matrix, of which the values can be changed in place.
+#[derive(Debug)]
+pub struct MatrixViewMut<'a, M> {
+    matrix: &'a mut M,
+    rows: Range<usize>,
+    columns: Range<usize>,
+}
+
+impl<M> MatrixView<'_, M> {
+    pub fn number_of_rows(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn number_of_columns(&self) -> usize {
+        self.columns.len()
+    }
+}
+
+impl<M> MatrixViewMut<'_, M> {
+    pub fn number_of_rows(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn number_of_columns(&self) -> usize {
+        self.columns.len()
+    }
+
+    /// Returns a read-only view of the same window.
+    pub fn as_view(&self) -> MatrixView<'_, M> {
+        MatrixView {
+            matrix: self.matrix,
+            rows: self.rows.clone(),
+            columns: self.columns.clone(),
+        }
+    }
+}
+
+/// Returns an error if the rows and columns are not a window of a matrix of the given size.
+pub(crate) fn check_window(
+    rows: &Range<usize>,
+    columns: &Range<usize>,
+    number_of_rows: usize,
+    number_of_columns: usize,
+) -> Result<()> {
+    if rows.start > rows.end
+        || rows.end > number_of_rows
+        || columns.start > columns.end
+        || columns.end > number_of_columns
+    {
+        return Err(anyhow!(
+            "cannot take rows {:?} and columns {:?} of a {}x{} matrix",
+            rows,
+            columns,
+            number_of_rows,
+            number_of_columns
+        ));
+    }
+    Ok(())
+}
+
+/// The values of a window, indexed row by row as if they were the values of a matrix of the size of the window.
+/// This lets the elimination macros of [gauss_jordan](crate::matrix::gauss_jordan) run on a window.
+struct WindowValues<'a, V> {
+    values: &'a mut [V],
+    offset: usize,
+    stride: usize,
+    number_of_columns: usize,
+}
+
+impl<V> WindowValues<'_, V> {
+    fn position(&self, i: usize) -> usize {
+        self.offset + (i / self.number_of_columns) * self.stride + i % self.number_of_columns
+    }
+}
+
+impl<V> Index<usize> for WindowValues<'_, V> {
+    type Output = V;
+
+    fn index(&self, i: usize) -> &Self::Output {
+        &self.values[self.position(i)]
+    }
+}
+
+impl<V> IndexMut<usize> for WindowValues<'_, V> {
+    fn index_mut(&mut self, i: usize) -> &mut Self::Output {
+        let position = self.position(i);
+        &mut self.values[position]
+    }
+}
+
+/// A window on which Gauss-Jordan elimination is applied in place.
+struct Window<'a, V> {
+    values: WindowValues<'a, V>,
+    number_of_rows: usize,
+    number_of_columns: usize,
+    /// The tolerance of an approximate matrix; unused for exact matrices.
+    epsilon: f64,
+}
+
+impl<V: Clone> Window<'_, V> {
+    fn number_of_rows(&self) -> usize {
+        self.number_of_rows
+    }
+
+    fn number_of_columns(&self) -> usize {
+        self.number_of_columns
+    }
+}
+
+impl Window<'_, f64> {
+    fn is_zero_value(&self, value: &f64) -> bool {
+        is_approximately_zero(*value, self.epsilon)
+    }
+}
+
+impl Window<'_, Rational> {
+    fn is_zero_value(&self, value: &Rational) -> bool {
+        value.is_zero()
+    }
+}
+
+macro_rules! view {
+    ($t:ident, $v:ident, $product:ident, $epsilon:expr) => {
+        impl $t {
+            /// Borrows the given rows and columns of the matrix, without copying them.
+            pub fn view(
+                &self,
+                rows: Range<usize>,
+                columns: Range<usize>,
+            ) -> Result<MatrixView<'_, Self>> {
+                check_window(&rows, &columns, self.number_of_rows, self.number_of_columns)?;
+                Ok(MatrixView {
+                    matrix: self,
+                    rows,
+                    columns,
+                })
+            }
+
+            /// Borrows the given rows and columns of the matrix mutably, without copying them.
+            pub fn view_mut(
+                &mut self,
+                rows: Range<usize>,
+                columns: Range<usize>,
+            ) -> Result<MatrixViewMut<'_, Self>> {
+                check_window(&rows, &columns, self.number_of_rows, self.number_of_columns)?;
+                Ok(MatrixViewMut {
+                    matrix: self,
+                    rows,
+                    columns,
+                })
+            }
+        }
+
+        impl MatrixView<'_, $t> {
+            /// Copies the window into a matrix of its own.
+            pub fn to_matrix(&self) -> $t {
+                let mut values = Vec::with_capacity(self.rows.len() * self.columns.len());
+                for row in self.rows.clone() {
+                    values.extend_from_slice(
+                        &self.matrix.values[self.matrix.index(row, self.columns.start)
+                            ..self.matrix.index(row, self.columns.end)],
+                    );
+                }
+                self.matrix
+                    .with_values(values, self.rows.len(), self.columns.len())
+            }
+
+            fn operand(&self) -> Operand<'_, $v, impl Fn(usize, usize) -> usize> {
+                let stride = self.matrix.number_of_columns;
+                let (first_row, first_column) = (self.rows.start, self.columns.start);
+                Operand {
+                    values: &self.matrix.values,
+                    number_of_rows: self.rows.len(),
+                    number_of_columns: self.columns.len(),
+                    position: move |row, column| (first_row + row) * stride + first_column + column,
+                }
+            }
+        }
+
+        /// Multiplies the windows without copying them into matrices first.
+        impl Mul for &MatrixView<'_, $t> {
+            type Output = Result<$t>;
+
+            fn mul(self, rhs: Self) -> Self::Output {
+                let values = $product(&self.operand(), &rhs.operand())?;
+                Ok(self
+                    .matrix
+                    .with_values(values, self.rows.len(), rhs.columns.len()))
+            }
+        }
+
+        impl MatrixViewMut<'_, $t> {
+            pub fn to_matrix(&self) -> $t {
+                self.as_view().to_matrix()
+            }
+
+            fn window(&mut self) -> Window<'_, $v> {
+                let epsilon = $epsilon(&*self.matrix);
+                Window {
+                    values: WindowValues {
+                        offset: self.matrix.index(self.rows.start, self.columns.start),
+                        stride: self.matrix.number_of_columns,
+                        number_of_columns: self.columns.len(),
+                        values: &mut self.matrix.values,
+                    },
+                    number_of_rows: self.rows.len(),
+                    number_of_columns: self.columns.len(),
+                    epsilon,
+                }
+            }
+        }
+
+        /// Applies elimination to the window only, in place; the rest of the matrix is left as is.
+        impl GaussJordan for MatrixViewMut<'_, $t> {
+            fn gauss_jordan(&mut self) {
+                let mut window = self.window();
+                gauss_jordan!(window);
+            }
+
+            /// Reduces a copy of the window, such that the window is left as is if reduction fails.
+            fn gauss_jordan_reduced(self) -> Result<Self> {
+                let mut reduced = self.to_matrix();
+                reduced.reduce().map_err(reduction_error)?;
+                let width = self.columns.len();
+                for (i, row) in self.rows.clone().enumerate() {
+                    let start = self.matrix.index(row, self.columns.start);
+                    self.matrix.values[start..start + width]
+                        .clone_from_slice(&reduced.values[i * width..(i + 1) * width]);
+                }
+                Ok(self)
+            }
+        }
+    };
+}
+
+view!(
+    FractionMatrixF64,
+    f64,
+    product_f64,
+    |m: &FractionMatrixF64| m.epsilon
+);
+view!(
+    FractionMatrixExact,
+    Rational,
+    product_exact,
+    |_: &FractionMatrixExact| 0.0
+);
+
+impl FractionMatrixEnum {
+    /// Borrows the given rows and columns of the matrix, without copying them.
+    pub fn view(&self, rows: Range<usize>, columns: Range<usize>) -> Result<MatrixView<'_, Self>> {
+        check_window(
+            &rows,
+            &columns,
+            self.number_of_rows(),
+            self.number_of_columns(),
+        )?;
+        Ok(MatrixView {
+            matrix: self,
+            rows,
+            columns,
+        })
+    }
+
+    /// Borrows the given rows and columns of the matrix mutably, without copying them.
+    pub fn view_mut(
+        &mut self,
+        rows: Range<usize>,
+        columns: Range<usize>,
+    ) -> Result<MatrixViewMut<'_, Self>> {
+        check_window(
+            &rows,
+            &columns,
+            self.number_of_rows(),
+            self.number_of_columns(),
+        )?;
+        Ok(MatrixViewMut {
+            matrix: self,
+            rows,
+            columns,
+        })
+    }
+}
+
+impl<'a> MatrixView<'a, FractionMatrixEnum> {
+    /// Returns a view on the same window of the given matrix.
+    fn on<M>(&self, matrix: &'a M) -> MatrixView<'a, M> {
+        MatrixView {
+            matrix,
+            rows: self.rows.clone(),
+            columns: self.columns.clone(),
+        }
+    }
+
+    pub fn to_matrix(&self) -> FractionMatrixEnum {
+        match self.matrix {
+            FractionMatrixEnum::Approx(m) => FractionMatrixEnum::Approx(self.on(m).to_matrix()),
+            FractionMatrixEnum::Exact(m) => FractionMatrixEnum::Exact(self.on(m).to_matrix()),
+            FractionMatrixEnum::CannotCombineExactAndApprox => {
+                FractionMatrixEnum::CannotCombineExactAndApprox
+            }
+        }
+    }
+}
+
+impl Mul for &MatrixView<'_, FractionMatrixEnum> {
+    type Output = Result<FractionMatrixEnum>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        match (self.matrix, rhs.matrix) {
+            (FractionMatrixEnum::Approx(a), FractionMatrixEnum::Approx(b)) => {
+                Ok(FractionMatrixEnum::Approx((&self.on(a) * &rhs.on(b))?))
+            }
+            (FractionMatrixEnum::Exact(a), FractionMatrixEnum::Exact(b)) => {
+                Ok(FractionMatrixEnum::Exact((&self.on(a) * &rhs.on(b))?))
+            }
+            _ => Err(anyhow!("cannot combine exact and approximate arithmetic")),
+        }
+    }
+}
+
+impl MatrixViewMut<'_, FractionMatrixEnum> {
+    pub fn to_matrix(&self) -> FractionMatrixEnum {
+        self.as_view().to_matrix()
+    }
+}
+
+impl GaussJordan for MatrixViewMut<'_, FractionMatrixEnum> {
+    fn gauss_jordan(&mut self) {
+        let (rows, columns) = (self.rows.clone(), self.columns.clone());
+        match &mut *self.matrix {
+            FractionMatrixEnum::Approx(matrix) => MatrixViewMut {
+                matrix,
+                rows,
+                columns,
+            }
+            .gauss_jordan(),
+            FractionMatrixEnum::Exact(matrix) => MatrixViewMut {
+                matrix,
+                rows,
+                columns,
+            }
+            .gauss_jordan(),
+            FractionMatrixEnum::CannotCombineExactAndApprox => {}
+        }
+    }
+
+    fn gauss_jordan_reduced(self) -> Result<Self> {
+        let (rows, columns) = (self.rows.clone(), self.columns.clone());
+        match &mut *self.matrix {
+            FractionMatrixEnum::Approx(matrix) => {
+                MatrixViewMut {
+                    matrix,
+                    rows,
+                    columns,
+                }
+                .gauss_jordan_reduced()?;
+            }
+            FractionMatrixEnum::Exact(matrix) => {
+                MatrixViewMut {
+                    matrix,
+                    rows,
+                    columns,
+                }
+                .gauss_jordan_reduced()?;
+            }
+            FractionMatrixEnum::CannotCombineExactAndApprox => {
+                return Err(anyhow!("cannot combine exact and approximate arithmetic"));
+            }
+        }
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Blocks, GaussJordan,
+        matrix::{
+            fraction_matrix_enum::FractionMatrixEnum, fraction_matrix_exact::FractionMatrixExact,
+        },
+    };
+
+    #[test]
+    fn view() {
+        let mut m: FractionMatrixExact = vec![
+            vec![2.into(), 1.into(), 0.into(), 5.into()],
+            vec![1.into(), 3.into(), (1, 2).into(), 7.into()],
+            vec![0.into(), 1.into(), 1.into(), 1.into()],
+            vec![9.into(), 9.into(), 9.into(), 9.into()],
+        ]
+        .try_into()
+        .unwrap();
+        let original = m.clone();
+
+        //view times view equals submatrix times submatrix
+        let a = m.view(0..3, 1..3).unwrap();
+        let b = m.view(1..3, 0..4).unwrap();
+        assert_eq!((a.number_of_rows(), a.number_of_columns()), (3, 2));
+        assert_eq!(
+            (&a * &b).unwrap(),
+            (&m.submatrix(0..3, 1..3).unwrap() * &m.submatrix(1..3, 0..4).unwrap()).unwrap()
+        );
+        assert!((&b * &b).is_err());
+        assert!(m.view(0..5, 0..1).is_err());
+
+        //elimination in place of the window, leaving the rest of the matrix as is
+        let reduced = m
+            .submatrix(0..3, 0..4)
+            .unwrap()
+            .gauss_jordan_reduced()
+            .unwrap();
+        m.view_mut(0..3, 0..4)
+            .unwrap()
+            .gauss_jordan_reduced()
+            .unwrap();
+        assert_eq!(m.submatrix(0..3, 0..4).unwrap(), reduced);
+        assert_eq!(
+            m.submatrix(3..4, 0..4).unwrap(),
+            original.submatrix(3..4, 0..4).unwrap()
+        );
+
+        //a singular window is left as is
+        let mut s = original.clone();
+        let e = s
+            .view_mut(2..4, 1..3)
+            .unwrap()
+            .gauss_jordan_reduced()
+            .unwrap_err();
+        assert!(
+            e.to_string()
+                .starts_with("matrix has no reduced row echelon form")
+        );
+        assert_eq!(s, original);
+
+        let mut s: FractionMatrixExact = vec![vec![2.into(), 4.into()], vec![1.into(), 2.into()]]
+            .try_into()
+            .unwrap();
+        let before = s.clone();
+        assert!(
+            s.view_mut(0..2, 0..2)
+                .unwrap()
+                .gauss_jordan_reduced()
+                .is_err()
+        );
+        assert_eq!(s, before);
+
+        let e = FractionMatrixEnum::Exact(original);
+        let v = e.view(1..3, 1..3).unwrap();
+        assert_eq!(
+            (&v * &v).unwrap(),
+            (&v.to_matrix() * &v.to_matrix()).unwrap()
+        );
+    }
+}
@@ -0,0 +1,70 @@
+//! Erasure of matrices, for deployments that must scrub intermediate values from memory.
+//!
+//! The size of the matrix is kept, and every value is erased as a single fraction is; see [crate::fraction::zeroize].
+//!
+//! To clear matrices on drop, wrap them in [zeroize::Zeroizing].
+
+use zeroize::Zeroize;
+
+use crate::{
+    fraction::zeroize::zeroize_rational,
+    matrix::{
+        fraction_matrix_enum::FractionMatrixEnum, fraction_matrix_exact::FractionMatrixExact,
+        fraction_matrix_f64::FractionMatrixF64,
+    },
+};
+
+impl Zeroize for FractionMatrixF64 {
+    fn zeroize(&mut self) {
+        self.values.iter_mut().zeroize();
+    }
+}
+
+impl Zeroize for FractionMatrixExact {
+    fn zeroize(&mut self) {
+        self.values.iter_mut().for_each(zeroize_rational);
+    }
+}
+
+impl Zeroize for FractionMatrixEnum {
+    fn zeroize(&mut self) {
+        match self {
+            FractionMatrixEnum::Approx(m) => m.zeroize(),
+            FractionMatrixEnum::Exact(m) => m.zeroize(),
+            FractionMatrixEnum::CannotCombineExactAndApprox => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zeroize::{Zeroize, Zeroizing};
+
+    use crate::{
+        EbiMatrix,
+        matrix::{
+            fraction_matrix_exact::FractionMatrixExact, fraction_matrix_f64::FractionMatrixF64,
+        },
+    };
+
+    #[test]
+    fn zeroize_matrix() {
+        let mut m: FractionMatrixF64 = vec![vec![1.into(), 2.into()], vec![3.into(), 4.into()]]
+            .try_into()
+            .unwrap();
+        m.zeroize();
+        assert_eq!(m, FractionMatrixF64::new(2, 2));
+
+        let mut m: FractionMatrixExact = vec![
+            vec![(1, 3).into(), 2.into()],
+            vec![3.into(), (-4, 7).into()],
+        ]
+        .try_into()
+        .unwrap();
+        m.zeroize();
+        assert_eq!(m, FractionMatrixExact::new(2, 2));
+
+        let m = Zeroizing::new(FractionMatrixExact::new(1, 1));
+        assert_eq!(m.number_of_rows(), 1);
+    }
+}
@@ -1,7 +1,182 @@
-use anyhow::Error;
-use std::str::FromStr;
+use anyhow::{Context, Error, Result, anyhow};
+use malachite::{Natural, base::num::arithmetic::traits::Pow, rational::Rational};
+use std::{io::BufRead, str::FromStr};
 
-use crate::fraction::{fraction_enum::FractionEnum, fraction_exact::FractionExact, fraction_f64::FractionF64};
+use crate::{
+    fraction::{
+        fraction_enum::FractionEnum, fraction_exact::FractionExact, fraction_f64::FractionF64,
+    },
+    matrix::fraction_matrix_exact::FractionMatrixExact,
+};
+
+/// Parses a decimal number exactly, such as "-1.25", or with a repeating part in parentheses, such as "0.1(6)" for 1/6.
+/// Returns None if the string is not in this notation; exponents are not supported.
+pub(crate) fn parse_decimal(s: &str) -> Option<Rational> {
+    let (negative, s) = match s.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, s.strip_prefix('+').unwrap_or(s)),
+    };
+    let (integer, rest) = s.split_once('.').unwrap_or((s, ""));
+    let (fraction, repeating) = match rest.split_once('(') {
+        Some((fraction, repeating)) => (fraction, Some(repeating.strip_suffix(')')?)),
+        None => (rest, None),
+    };
+
+    let is_digits = |part: &str| part.bytes().all(|b| b.is_ascii_digit());
+    if !is_digits(integer) || !is_digits(fraction) || integer.len() + fraction.len() == 0 {
+        return None;
+    }
+    if repeating.is_some_and(|repeating| repeating.is_empty() || !is_digits(repeating)) {
+        return None;
+    }
+
+    let power_of_ten = |digits: usize| Natural::from(10u32).pow(digits as u64);
+    let digits = |part: &str| {
+        if part.is_empty() {
+            Some(Natural::from(0u32))
+        } else {
+            Natural::from_str(part).ok()
+        }
+    };
+
+    let mut result = Rational::from(digits(integer)?)
+        + Rational::from_naturals(digits(fraction)?, power_of_ten(fraction.len()));
+    if let Some(repeating) = repeating {
+        //0.0(r) = r / ((10^|r| - 1) * 10^|fraction|)
+        result += Rational::from_naturals(
+            digits(repeating)?,
+            (power_of_ten(repeating.len()) - Natural::from(1u32)) * power_of_ten(fraction.len()),
+        );
+    }
+    Some(if negative { -result } else { result })
+}
+
+/// The largest exponent, in absolute value, of scientific notation that is parsed exactly.
+/// Larger exponents would produce numbers that do not fit in memory; exact parsing returns an error for them.
+pub const MAX_PARSED_EXPONENT: u64 = 10_000;
+
+/// Parses a number exactly in any of the notations that are not floats, returning None if the string is in none of them:
+/// - a fraction, such as "-1/3";
+/// - a decimal, possibly with a repeating part, such as "0.1(6)", see [parse_decimal];
+/// - scientific notation, such as "1.5e-3";
+/// - any of these followed by a percent sign, such as "12.5%";
+/// - any of these with underscores between digits, such as "1_000_000/3".
+///
+/// Returns an error if the string is in scientific notation with an exponent beyond [MAX_PARSED_EXPONENT].
+pub(crate) fn parse_rational(s: &str) -> Result<Option<Rational>> {
+    if s.contains('_') {
+        let bytes = s.as_bytes();
+        let separates_digits = |i: usize| {
+            i > 0
+                && i + 1 < bytes.len()
+                && bytes[i - 1].is_ascii_digit()
+                && bytes[i + 1].is_ascii_digit()
+        };
+        if (0..bytes.len()).any(|i| bytes[i] == b'_' && !separates_digits(i)) {
+            return Ok(None);
+        }
+        return parse_rational(&s.replace('_', ""));
+    }
+
+    if let Some(percentage) = s.strip_suffix('%') {
+        if percentage.ends_with('%') {
+            return Ok(None);
+        }
+        return Ok(parse_rational(percentage)?.map(|x| x / Rational::from(100u32)));
+    }
+
+    if let Ok(rational) = s.parse::<Rational>() {
+        return Ok(Some(rational));
+    }
+
+    if let Some(rational) = parse_decimal(s) {
+        return Ok(Some(rational));
+    }
+
+    let Some((mantissa, exponent)) = s.split_once(['e', 'E']) else {
+        return Ok(None);
+    };
+    let Some(mantissa) = parse_decimal(mantissa) else {
+        return Ok(None);
+    };
+    let (negative, exponent) = match exponent.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, exponent.strip_prefix('+').unwrap_or(exponent)),
+    };
+    if exponent.is_empty() || !exponent.bytes().all(|b| b.is_ascii_digit()) {
+        return Ok(None);
+    }
+    let exponent = match exponent.parse::<u64>() {
+        Ok(exponent) if exponent <= MAX_PARSED_EXPONENT => exponent,
+        _ => {
+            return Err(anyhow!(
+                "the exponent of {} exceeds the limit of {} for exact parsing",
+                s,
+                MAX_PARSED_EXPONENT
+            ));
+        }
+    };
+    let power = Rational::from(Natural::from(10u32).pow(exponent));
+    Ok(Some(if negative {
+        mantissa / power
+    } else {
+        mantissa * power
+    }))
+}
+
+/// Reads a matrix of fractions, one row per line, with the values of a row separated by commas and/or whitespace.
+/// Empty lines are skipped. Each value may be in any notation that [FractionExact] parses.
+///
+/// The values are parsed directly into the matrix as the lines are read, such that the text is never held in memory as a whole,
+/// and no intermediate `Vec<Vec<_>>` is built.
+/// Returns an error, mentioning the line, if a value cannot be parsed or if rows have different numbers of values.
+pub fn read_fraction_matrix_exact(mut reader: impl BufRead) -> Result<FractionMatrixExact> {
+    let mut values = vec![];
+    let mut number_of_rows = 0;
+    let mut number_of_columns = None;
+    let mut line = String::new();
+    let mut line_number = 0;
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            break;
+        }
+        line_number += 1;
+
+        let before = values.len();
+        for value in line
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|value| !value.is_empty())
+        {
+            let value = FractionExact::from_str(value)
+                .with_context(|| format!("could not parse line {}", line_number))?;
+            values.push(value.0);
+        }
+        let length = values.len() - before;
+        if length == 0 {
+            continue;
+        }
+        match number_of_columns {
+            None => number_of_columns = Some(length),
+            Some(columns) if columns != length => {
+                return Err(anyhow!(
+                    "line {} has {} values, whereas previous rows have {}",
+                    line_number,
+                    length,
+                    columns
+                ));
+            }
+            Some(_) => {}
+        }
+        number_of_rows += 1;
+    }
+
+    Ok(FractionMatrixExact {
+        values,
+        number_of_rows,
+        number_of_columns: number_of_columns.unwrap_or(0),
+    })
+}
 
 #[derive(Clone)]
 pub struct FractionNotParsedYet {
@@ -39,3 +214,121 @@ impl TryFrom<&FractionNotParsedYet> for FractionF64 {
         Ok(Self::from_str(&value.s)?)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use malachite::rational::Rational;
+
+    use crate::{
+        EbiMatrix,
+        fraction::{
+            fraction_enum::FractionEnum, fraction_exact::FractionExact, fraction_f64::FractionF64,
+        },
+        matrix::fraction_matrix_exact::FractionMatrixExact,
+        parsing::{parse_decimal, parse_rational, read_fraction_matrix_exact},
+    };
+
+    #[test]
+    fn repeating_decimals() {
+        assert_eq!(parse_decimal("0.(3)"), Some(Rational::from_signeds(1, 3)));
+        assert_eq!(parse_decimal("0.1(6)"), Some(Rational::from_signeds(1, 6)));
+        assert_eq!(
+            parse_decimal("-2.(142857)"),
+            Some(Rational::from_signeds(-15, 7))
+        );
+        assert_eq!(parse_decimal("0.(9)"), Some(Rational::from(1)));
+        assert_eq!(parse_decimal("+.5"), Some(Rational::from_signeds(1, 2)));
+        assert_eq!(parse_decimal("3."), Some(Rational::from(3)));
+        for invalid in [
+            "", ".", "-", "0.()", "0.(3", "0.3)", "1(3)", "0.(3)4", "1e5", "a.1",
+        ] {
+            assert_eq!(parse_decimal(invalid), None, "{}", invalid);
+        }
+
+        //decimals are exact, however many digits they have
+        let x: FractionExact = "0.12345678901234567890123".parse().unwrap();
+        assert_eq!(
+            x,
+            FractionExact::from((12345678901234567890123u128, 10u128.pow(23)))
+        );
+        let x: FractionExact = "0.1(6)".parse().unwrap();
+        assert_eq!(x, FractionExact::from((1, 6)));
+        let x: FractionF64 = "0.(3)".parse().unwrap();
+        assert_eq!(x, FractionF64::from(1.0 / 3.0));
+        let x: FractionExact = "1.5e2".parse().unwrap();
+        assert_eq!(x, FractionExact::from(150));
+    }
+
+    #[test]
+    fn scientific_percentages_underscores() {
+        assert_eq!(
+            parse_rational("1.5e-3").unwrap(),
+            Some(Rational::from_signeds(3, 2000))
+        );
+        assert_eq!(parse_rational("-2E+2").unwrap(), Some(Rational::from(-200)));
+        assert_eq!(
+            parse_rational("12.5%").unwrap(),
+            Some(Rational::from_signeds(1, 8))
+        );
+        assert_eq!(
+            parse_rational("1/2%").unwrap(),
+            Some(Rational::from_signeds(1, 200))
+        );
+        assert_eq!(
+            parse_rational("1_000_000/3").unwrap(),
+            Some(Rational::from_signeds(1000000, 3))
+        );
+        assert_eq!(
+            parse_rational("1_5e1_0%").unwrap(),
+            Some(Rational::from(15 * 10i64.pow(8)))
+        );
+        for invalid in [
+            "_1", "1_", "1__0", "1_/3", "1%%", "%", "1e", "e5", "1e-", "1e1.5",
+        ] {
+            assert_eq!(parse_rational(invalid).unwrap(), None, "{}", invalid);
+        }
+        for too_large in [
+            "1e99999",
+            "1e-20000",
+            "1_0e2_0000%",
+            "1e99999999999999999999",
+        ] {
+            assert!(parse_rational(too_large).is_err(), "{}", too_large);
+            assert!(too_large.parse::<FractionExact>().is_err(), "{}", too_large);
+        }
+        assert!(parse_rational("1e10000").is_ok());
+
+        //exact, where a float would round
+        let x: FractionExact = "1.1e-20".parse().unwrap();
+        assert_eq!(x, FractionExact::from((11, 10u128.pow(21))));
+        let x: FractionExact = "33.(3)%".parse().unwrap();
+        assert_eq!(x, FractionExact::from((1, 3)));
+        let x: FractionF64 = "12.5%".parse().unwrap();
+        assert_eq!(x, FractionF64::from(0.125));
+        let x: FractionF64 = "1_000.5".parse().unwrap();
+        assert_eq!(x, FractionF64::from(1000.5));
+        assert!("1__000".parse::<FractionF64>().is_err());
+        assert!("50%".parse::<FractionEnum>().unwrap() == FractionEnum::from((1, 2)));
+    }
+
+    #[test]
+    fn read_matrix() {
+        let text = "1/2, 0.5 ,1\n\n0 1e-1   25%\r\n";
+        let m = read_fraction_matrix_exact(text.as_bytes()).unwrap();
+        let correct: FractionMatrixExact = vec![
+            vec![(1, 2).into(), (1, 2).into(), 1.into()],
+            vec![0.into(), (1, 10).into(), (1, 4).into()],
+        ]
+        .try_into()
+        .unwrap();
+        assert_eq!(m, correct);
+
+        assert!(read_fraction_matrix_exact("1 2\n3".as_bytes()).is_err());
+        assert!(read_fraction_matrix_exact("1 x".as_bytes()).is_err());
+        assert!(read_fraction_matrix_exact("1 1e-20000".as_bytes()).is_err());
+        assert_eq!(
+            read_fraction_matrix_exact("".as_bytes()).unwrap(),
+            FractionMatrixExact::new(0, 0)
+        );
+    }
+}
@@ -0,0 +1,198 @@
+//! Python bindings of exact fractions and matrices, with the `python` feature.
+//!
+//! Values cross the boundary as strings in any notation that [FractionExact] parses, such that they remain exact.
+//! To build an extension module, compile this crate as a `cdylib`, for instance with maturin, which exports the module `ebi_arithmetic`.
+//! Other extension modules can include the classes with [register].
+
+use anyhow::Error;
+use pyo3::{
+    exceptions::{PyValueError, PyZeroDivisionError},
+    prelude::*,
+};
+use std::str::FromStr;
+
+use crate::{
+    EbiMatrix, Inversion, Signed, Solve, Zero,
+    fraction::{approximate::Approximate, fraction_exact::FractionExact},
+    matrix::fraction_matrix_exact::FractionMatrixExact,
+};
+
+fn value_error(error: Error) -> PyErr {
+    PyValueError::new_err(error.to_string())
+}
+
+/// An exact fraction.
+#[pyclass(name = "Fraction", module = "ebi_arithmetic", frozen, eq, ord, hash)]
+#[derive(Clone, PartialEq, PartialOrd, Hash)]
+pub struct PyFraction(pub FractionExact);
+
+#[pymethods]
+impl PyFraction {
+    /// Parses a fraction, such as "1/3", "0.25", "0.1(6)" or "1.5e-3".
+    #[new]
+    fn new(value: &str) -> PyResult<Self> {
+        Ok(Self(FractionExact::from_str(value).map_err(value_error)?))
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Fraction(\"{}\")", self.0)
+    }
+
+    fn __float__(&self) -> PyResult<f64> {
+        self.0.clone().approximate().map_err(value_error)
+    }
+
+    fn __add__(&self, other: &Self) -> Self {
+        Self(&self.0 + &other.0)
+    }
+
+    fn __sub__(&self, other: &Self) -> Self {
+        Self(&self.0 - &other.0)
+    }
+
+    fn __mul__(&self, other: &Self) -> Self {
+        Self(&self.0 * &other.0)
+    }
+
+    fn __truediv__(&self, other: &Self) -> PyResult<Self> {
+        if other.0.is_zero() {
+            return Err(PyZeroDivisionError::new_err("division by zero"));
+        }
+        Ok(Self(&self.0 / &other.0))
+    }
+
+    fn __neg__(&self) -> Self {
+        Self(-&self.0)
+    }
+
+    fn __abs__(&self) -> Self {
+        Self(self.0.clone().abs())
+    }
+}
+
+/// A matrix of exact fractions.
+#[pyclass(name = "FractionMatrix", module = "ebi_arithmetic", frozen, eq)]
+#[derive(Clone, PartialEq)]
+pub struct PyFractionMatrix(pub FractionMatrixExact);
+
+#[pymethods]
+impl PyFractionMatrix {
+    /// Creates a matrix from its rows, of which each value is parsed as a [PyFraction].
+    #[new]
+    fn new(rows: Vec<Vec<String>>) -> PyResult<Self> {
+        let rows = rows
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|value| FractionExact::from_str(value))
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(value_error)?;
+        Ok(Self(rows.try_into().map_err(value_error)?))
+    }
+
+    #[getter]
+    fn number_of_rows(&self) -> usize {
+        self.0.number_of_rows()
+    }
+
+    #[getter]
+    fn number_of_columns(&self) -> usize {
+        self.0.number_of_columns()
+    }
+
+    fn get(&self, row: usize, column: usize) -> PyResult<PyFraction> {
+        self.0.get(row, column).map(PyFraction).ok_or_else(|| {
+            PyValueError::new_err(format!(
+                "a {}x{} matrix has no value at ({}, {})",
+                self.0.number_of_rows(),
+                self.0.number_of_columns(),
+                row,
+                column
+            ))
+        })
+    }
+
+    fn rows(&self) -> Vec<Vec<PyFraction>> {
+        self.0
+            .clone()
+            .to_vec()
+            .into_iter()
+            .map(|row| row.into_iter().map(PyFraction).collect())
+            .collect()
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __matmul__(&self, other: &Self) -> PyResult<Self> {
+        Ok(Self((&self.0 * &other.0).map_err(value_error)?))
+    }
+
+    fn inverse(&self) -> PyResult<Self> {
+        Ok(Self(self.0.clone().invert().map_err(value_error)?))
+    }
+
+    /// Returns the unique solution x of A x = b, where A is this matrix.
+    fn solve(&self, b: Vec<PyFraction>) -> PyResult<Vec<PyFraction>> {
+        let b = b.into_iter().map(|value| value.0).collect::<Vec<_>>();
+        Ok(self
+            .0
+            .solve(&b)
+            .map_err(value_error)?
+            .into_iter()
+            .map(PyFraction)
+            .collect())
+    }
+}
+
+/// Adds the classes of this module to a Python module.
+pub fn register(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyFraction>()?;
+    module.add_class::<PyFractionMatrix>()?;
+    Ok(())
+}
+
+#[pymodule]
+fn ebi_arithmetic(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    register(module)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::python::{PyFraction, PyFractionMatrix};
+
+    #[test]
+    fn python() {
+        let third = PyFraction::new("1/3").unwrap();
+        let sixth = PyFraction::new("0.1(6)").unwrap();
+        assert_eq!(third.__add__(&sixth).__str__(), "1/2");
+        assert!(third.__truediv__(&PyFraction::new("0").unwrap()).is_err());
+        assert!(PyFraction::new("x").is_err());
+
+        let m = PyFractionMatrix::new(vec![
+            vec!["2".to_string(), "1".to_string()],
+            vec!["1".to_string(), "3".to_string()],
+        ])
+        .unwrap();
+        let x = m
+            .solve(vec![
+                PyFraction::new("3").unwrap(),
+                PyFraction::new("5").unwrap(),
+            ])
+            .unwrap();
+        assert_eq!(
+            x.iter().map(|value| value.__str__()).collect::<Vec<_>>(),
+            vec!["4/5", "7/5"]
+        );
+        let identity = m.__matmul__(&m.inverse().unwrap()).unwrap();
+        assert_eq!(identity.get(0, 0).unwrap().__str__(), "1");
+        assert_eq!(identity.get(0, 1).unwrap().__str__(), "0");
+    }
+}
@@ -0,0 +1,205 @@
+//======================== size guard ========================//
+
+use anyhow::Result;
+use malachite::{Integer, Natural, platform::Limb, rational::Rational};
+use std::{cell::Cell, mem::size_of};
+
+use crate::events::debug_event;
+
+thread_local! {
+    static SIZE_LIMIT: Cell<Option<usize>> = const { Cell::new(None) };
+}
+
+/// Limits the number of bytes that the exact values of an operation may occupy at once on the current thread.
+/// The limit holds until the guard is dropped, after which the previous limit is restored.
+///
+/// The budget covers all exact values that the operation holds at the same time: its operands, its working copies and intermediate buffers, and its result so far.
+/// Each value counts its own size plus the limbs that malachite allocates for numbers that do not fit in a single limb; temporaries inside a single arithmetic operation are not counted.
+///
+/// The limit is enforced, after each step, by the following operations on exact matrices:
+/// - reduction ([GaussJordan::gauss_jordan_reduced](crate::GaussJordan::gauss_jordan_reduced)), inversion, determinants, solution spaces, and solving systems that are not banded;
+/// - products of matrices, of views, of matrices with vectors, and batches of vectors.
+///
+/// [GaussJordan::gauss_jordan](crate::GaussJordan::gauss_jordan) cannot report an error, and is not guarded.
+///
+/// When the limit is exceeded, the operation aborts with a [SizeLimitExceeded] error, which can be recovered with `downcast_ref`.
+/// The caller may then retry the computation with approximate arithmetic.
+///
+/// ```
+/// use ebi_arithmetic::{Inversion, size_guard::{ExactSizeGuard, SizeLimitExceeded}};
+/// # use ebi_arithmetic::matrix::fraction_matrix_exact::FractionMatrixExact;
+/// # let m: FractionMatrixExact = vec![vec![2.into(), 1.into(), 0.into()], vec![1.into(), 2.into(), 1.into()], vec![0.into(), 1.into(), 3.into()]].try_into().unwrap();
+/// let _guard = ExactSizeGuard::new(1 << 30);
+/// match m.invert() {
+///     Ok(inverse) => {}
+///     Err(e) if e.downcast_ref::<SizeLimitExceeded>().is_some() => { /* fall back to approximate arithmetic */ }
+///     Err(e) => {}
+/// }
+/// ```
+pub struct ExactSizeGuard {
+    previous: Option<usize>,
+}
+
+impl ExactSizeGuard {
+    pub fn new(limit_in_bytes: usize) -> Self {
+        Self {
+            previous: SIZE_LIMIT.with(|limit| limit.replace(Some(limit_in_bytes))),
+        }
+    }
+}
+
+impl Drop for ExactSizeGuard {
+    fn drop(&mut self) {
+        SIZE_LIMIT.with(|limit| limit.set(self.previous));
+    }
+}
+
+/// The error of an exact computation that exceeded the limit set by an [ExactSizeGuard].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SizeLimitExceeded {
+    pub limit_in_bytes: usize,
+    pub size_in_bytes: usize,
+}
+
+impl std::fmt::Display for SizeLimitExceeded {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "exact computation uses {} bytes, which exceeds the limit of {} bytes",
+            self.size_in_bytes, self.limit_in_bytes
+        )
+    }
+}
+
+impl std::error::Error for SizeLimitExceeded {}
+
+/// A value of which the number of occupied bytes is known.
+pub(crate) trait ExactBytes {
+    fn exact_bytes(&self) -> usize;
+}
+
+/// Malachite stores a number of a single limb inline, and larger numbers in a vector of limbs.
+fn limb_bytes(value: &Natural) -> usize {
+    match value.limb_count() {
+        0 | 1 => 0,
+        limbs => limbs as usize * size_of::<Limb>(),
+    }
+}
+
+impl ExactBytes for Natural {
+    fn exact_bytes(&self) -> usize {
+        size_of::<Natural>() + limb_bytes(self)
+    }
+}
+
+impl ExactBytes for Integer {
+    fn exact_bytes(&self) -> usize {
+        size_of::<Integer>() + limb_bytes(self.unsigned_abs_ref())
+    }
+}
+
+impl ExactBytes for Rational {
+    fn exact_bytes(&self) -> usize {
+        size_of::<Rational>()
+            + limb_bytes(self.numerator_ref())
+            + limb_bytes(self.denominator_ref())
+    }
+}
+
+/// Returns the number of bytes that the values occupy.
+pub(crate) fn exact_bytes<'a, T: ExactBytes + 'a>(
+    values: impl IntoIterator<Item = &'a T>,
+) -> usize {
+    values.into_iter().map(ExactBytes::exact_bytes).sum()
+}
+
+/// Returns an error if a size limit is set on this thread and the number of bytes of all the buffers of an operation exceeds it.
+/// Without a limit, this returns immediately, without computing the number of bytes.
+pub(crate) fn check_exact_bytes(size_in_bytes: impl FnOnce() -> usize) -> Result<()> {
+    let Some(limit_in_bytes) = SIZE_LIMIT.with(|limit| limit.get()) else {
+        return Ok(());
+    };
+
+    let size_in_bytes = size_in_bytes();
+    if size_in_bytes > limit_in_bytes {
+        debug_event!(limit_in_bytes, size_in_bytes, "exact size limit exceeded");
+        Err(SizeLimitExceeded {
+            limit_in_bytes,
+            size_in_bytes,
+        }
+        .into())
+    } else {
+        Ok(())
+    }
+}
+
+/// Returns an error if a size limit is set on this thread and the values of the buffers of an operation together exceed it.
+pub(crate) fn check_exact_size(buffers: &[&[Rational]]) -> Result<()> {
+    check_exact_bytes(|| buffers.iter().map(|buffer| exact_bytes(*buffer)).sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Determinant, EbiMatrix, GaussJordan, Inversion, Solve,
+        fraction::{fraction_enum::FractionEnum, fraction_exact::FractionExact},
+        matrix::{
+            fraction_matrix_enum::FractionMatrixEnum, fraction_matrix_exact::FractionMatrixExact,
+        },
+        size_guard::{ExactSizeGuard, SizeLimitExceeded, exact_bytes},
+    };
+
+    #[test]
+    fn size_guard() {
+        let n = 12;
+        let values = (0..n)
+            .map(|i| (0..n).map(|j| (1, i + j + 1).into()).collect())
+            .collect::<Vec<Vec<_>>>();
+        let m: FractionMatrixExact = values.try_into().unwrap();
+        let mut asymmetric = m.clone();
+        asymmetric.set(0, 1, 5.into());
+
+        {
+            let _guard = ExactSizeGuard::new(100);
+            let e = m.clone().invert().unwrap_err();
+            assert!(e.downcast_ref::<SizeLimitExceeded>().is_some());
+            let e = asymmetric.clone().gauss_jordan_reduced().unwrap_err();
+            assert!(e.downcast_ref::<SizeLimitExceeded>().is_some());
+            let e = (&m * &vec![FractionExact::from(1); n]).unwrap_err();
+            assert!(e.downcast_ref::<SizeLimitExceeded>().is_some());
+        }
+
+        //the budget covers all buffers of an operation, not only the values of the matrix
+        let size = exact_bytes(&m.values);
+        {
+            let _guard = ExactSizeGuard::new(size * 2);
+            let e = m.clone().invert().unwrap_err();
+            assert!(e.downcast_ref::<SizeLimitExceeded>().is_some());
+            let e = m.determinant().unwrap_err();
+            assert!(e.downcast_ref::<SizeLimitExceeded>().is_some());
+            let e = (&m * &m).unwrap_err();
+            assert!(e.downcast_ref::<SizeLimitExceeded>().is_some());
+            let v = vec![FractionExact::from(1); n];
+            let e = m.solve(&v).unwrap_err();
+            assert!(e.downcast_ref::<SizeLimitExceeded>().is_some());
+            assert!((&m * &v).is_ok());
+        }
+
+        //the limit is lifted when the guard is dropped
+        assert!((&m * &m).is_ok());
+        assert!(m.clone().invert().is_ok());
+
+        //exact products of the enum types are guarded as well
+        let m = FractionMatrixEnum::Exact(m);
+        let v = vec![FractionEnum::Exact(1.into()); n];
+        {
+            let _guard = ExactSizeGuard::new(100);
+            let e = (&m * &v).unwrap_err();
+            assert!(e.downcast_ref::<SizeLimitExceeded>().is_some());
+            let e = (&v * &m).unwrap_err();
+            assert!(e.downcast_ref::<SizeLimitExceeded>().is_some());
+        }
+        assert!((&m * &v).is_ok());
+        assert!((&v * &m).is_ok());
+    }
+}
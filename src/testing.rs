@@ -0,0 +1,301 @@
+//! Generators of arbitrary fractions and small matrices for property-based testing with quickcheck and proptest.
+//!
+//! Generated fractions have an `i32` numerator and a non-zero `u32` denominator.
+//! Generated matrices have at most 4 rows and 4 columns.
+//! With quickcheck, fractions shrink towards zero, and matrices shrink by dropping their last row or column, and by shrinking one value at a time.
+//! [FractionEnum] and [FractionMatrixEnum] values follow the global arithmetic mode at generation time.
+
+use malachite::{
+    Integer,
+    base::{num::conversion::traits::RoundingFrom, rounding_modes::RoundingMode},
+    rational::Rational,
+};
+use proptest::{
+    arbitrary::{Arbitrary as PArbitrary, any},
+    collection::vec,
+    strategy::{BoxedStrategy, Strategy},
+};
+use quickcheck::{Arbitrary as QArbitrary, Gen};
+
+use crate::{
+    EbiMatrix,
+    exact::is_exact_globally,
+    fraction::{
+        fraction_enum::FractionEnum, fraction_exact::FractionExact, fraction_f64::FractionF64,
+    },
+    matrix::{
+        fraction_matrix_enum::FractionMatrixEnum, fraction_matrix_exact::FractionMatrixExact,
+        fraction_matrix_f64::FractionMatrixF64,
+    },
+};
+
+const MAX_MATRIX_SIZE: usize = 4;
+
+fn arbitrary_numerator_denominator(g: &mut Gen) -> (i32, u32) {
+    (
+        <i32 as QArbitrary>::arbitrary(g),
+        <u32 as QArbitrary>::arbitrary(g).max(1),
+    )
+}
+
+//======================== quickcheck ========================//
+
+impl QArbitrary for FractionExact {
+    fn arbitrary(g: &mut Gen) -> Self {
+        Self::from(arbitrary_numerator_denominator(g))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        //shrink towards zero, via the integer part
+        let mut shrunk = vec![];
+        if self.0 != 0u32 {
+            shrunk.push(Self::from(0));
+            let integer_part = Self(Rational::from(
+                Integer::rounding_from(&self.0, RoundingMode::Down).0,
+            ));
+            if integer_part != *self && integer_part.0 != 0u32 {
+                shrunk.push(integer_part);
+            }
+        }
+        Box::new(shrunk.into_iter())
+    }
+}
+
+impl QArbitrary for FractionF64 {
+    fn arbitrary(g: &mut Gen) -> Self {
+        Self::from(arbitrary_numerator_denominator(g))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(self.0.shrink().map(Self))
+    }
+}
+
+impl QArbitrary for FractionEnum {
+    fn arbitrary(g: &mut Gen) -> Self {
+        if is_exact_globally() {
+            FractionEnum::Exact(<FractionExact as QArbitrary>::arbitrary(g).0)
+        } else {
+            FractionEnum::Approx(<FractionF64 as QArbitrary>::arbitrary(g).0)
+        }
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        match self {
+            FractionEnum::Exact(f) => Box::new(
+                FractionExact(f.clone())
+                    .shrink()
+                    .map(|f| FractionEnum::Exact(f.0)),
+            ),
+            FractionEnum::Approx(f) => Box::new(f.shrink().map(FractionEnum::Approx)),
+            FractionEnum::CannotCombineExactAndApprox => quickcheck::empty_shrinker(),
+        }
+    }
+}
+
+macro_rules! quickcheck_matrix {
+    ($t:ident, $u:ident) => {
+        impl QArbitrary for $t {
+            fn arbitrary(g: &mut Gen) -> Self {
+                let number_of_rows = <usize as QArbitrary>::arbitrary(g) % (MAX_MATRIX_SIZE + 1);
+                let number_of_columns = <usize as QArbitrary>::arbitrary(g) % (MAX_MATRIX_SIZE + 1);
+                let values: Vec<Vec<$u>> = (0..number_of_rows)
+                    .map(|_| {
+                        (0..number_of_columns)
+                            .map(|_| <$u as QArbitrary>::arbitrary(g))
+                            .collect()
+                    })
+                    .collect();
+                values.try_into().unwrap()
+            }
+
+            fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+                let number_of_columns = self.number_of_columns();
+                let rows: Vec<Vec<$u>> = (0..self.number_of_rows())
+                    .map(|row| {
+                        (0..number_of_columns)
+                            .map(|column| self.get(row, column).unwrap())
+                            .collect()
+                    })
+                    .collect();
+
+                //smaller dimensions first
+                let mut smaller = vec![];
+                if !rows.is_empty() {
+                    smaller.push(rows[..rows.len() - 1].to_vec());
+                }
+                if number_of_columns > 0 {
+                    smaller.push(
+                        rows.iter()
+                            .map(|row| row[..number_of_columns - 1].to_vec())
+                            .collect(),
+                    );
+                }
+
+                //then smaller values, one at a time
+                let values = (0..rows.len() * number_of_columns).flat_map(move |index| {
+                    let (row, column) = (index / number_of_columns, index % number_of_columns);
+                    let rows = rows.clone();
+                    rows[row][column].shrink().map(move |value| {
+                        let mut rows = rows.clone();
+                        rows[row][column] = value;
+                        rows
+                    })
+                });
+
+                Box::new(
+                    smaller
+                        .into_iter()
+                        .chain(values)
+                        .map(|rows| rows.try_into().unwrap()),
+                )
+            }
+        }
+    };
+}
+
+quickcheck_matrix!(FractionMatrixExact, FractionExact);
+quickcheck_matrix!(FractionMatrixF64, FractionF64);
+quickcheck_matrix!(FractionMatrixEnum, FractionEnum);
+
+//======================== proptest ========================//
+
+fn numerator_denominator() -> impl Strategy<Value = (i32, u32)> {
+    (any::<i32>(), 1..=u32::MAX)
+}
+
+impl PArbitrary for FractionExact {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        numerator_denominator().prop_map(Self::from).boxed()
+    }
+}
+
+impl PArbitrary for FractionF64 {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        numerator_denominator().prop_map(Self::from).boxed()
+    }
+}
+
+impl PArbitrary for FractionEnum {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        numerator_denominator()
+            .prop_map(|(numerator, denominator)| {
+                if is_exact_globally() {
+                    FractionEnum::Exact(FractionExact::from((numerator, denominator)).0)
+                } else {
+                    FractionEnum::Approx(FractionF64::from((numerator, denominator)).0)
+                }
+            })
+            .boxed()
+    }
+}
+
+macro_rules! proptest_matrix {
+    ($t:ident, $u:ident) => {
+        impl PArbitrary for $t {
+            type Parameters = ();
+            type Strategy = BoxedStrategy<Self>;
+
+            fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+                (0..=MAX_MATRIX_SIZE, 0..=MAX_MATRIX_SIZE)
+                    .prop_flat_map(|(number_of_rows, number_of_columns)| {
+                        vec(vec(any::<$u>(), number_of_columns), number_of_rows)
+                    })
+                    .prop_map(|values| values.try_into().unwrap())
+                    .boxed()
+            }
+        }
+    };
+}
+
+proptest_matrix!(FractionMatrixExact, FractionExact);
+proptest_matrix!(FractionMatrixF64, FractionF64);
+proptest_matrix!(FractionMatrixEnum, FractionEnum);
+
+#[cfg(test)]
+mod tests {
+    use proptest::{prelude::any, strategy::Strategy, test_runner::TestRunner};
+    use quickcheck::{Arbitrary, Gen};
+
+    use crate::{
+        EbiMatrix, Signed, fraction::fraction_exact::FractionExact,
+        matrix::fraction_matrix_exact::FractionMatrixExact,
+    };
+
+    #[test]
+    fn quickcheck_generates() {
+        let mut g = Gen::new(100);
+        for _ in 0..100 {
+            let m = FractionMatrixExact::arbitrary(&mut g);
+            assert!(m.number_of_rows() <= 4 && m.number_of_columns() <= 4);
+        }
+        let f = FractionExact::from((7, 2));
+        assert!(f.shrink().all(|s| s.abs() < f.clone().abs()));
+    }
+
+    #[test]
+    fn quickcheck_shrinks_matrices() {
+        let m: FractionMatrixExact = vec![
+            vec![(7, 2).into(), 0.into()],
+            vec![1.into(), (-1, 3).into()],
+        ]
+        .try_into()
+        .unwrap();
+        let shrunk: Vec<FractionMatrixExact> = m.shrink().collect();
+
+        //the last row, then the last column, is dropped
+        assert_eq!(
+            (shrunk[0].number_of_rows(), shrunk[0].number_of_columns()),
+            (1, 2)
+        );
+        assert_eq!(
+            (shrunk[1].number_of_rows(), shrunk[1].number_of_columns()),
+            (2, 1)
+        );
+
+        //then one value at a time is shrunk, keeping the size
+        assert!(shrunk[2..].iter().all(|s| {
+            s.number_of_rows() == 2
+                && s.number_of_columns() == 2
+                && (0..4)
+                    .filter(|i| s.get(i / 2, i % 2) != m.get(i / 2, i % 2))
+                    .count()
+                    == 1
+        }));
+        assert!(
+            shrunk.contains(
+                &vec![vec![3.into(), 0.into()], vec![1.into(), (-1, 3).into()]]
+                    .try_into()
+                    .unwrap()
+            )
+        );
+
+        //shrinking terminates
+        let mut m = m;
+        while let Some(smaller) = m.shrink().next() {
+            m = smaller;
+        }
+        assert_eq!(m.number_of_rows(), 0);
+    }
+
+    #[test]
+    fn proptest_generates() {
+        let mut runner = TestRunner::default();
+        runner
+            .run(&any::<FractionMatrixExact>(), |m| {
+                assert!(m.number_of_rows() <= 4 && m.number_of_columns() <= 4);
+                Ok(())
+            })
+            .unwrap();
+        let _ = any::<FractionExact>().new_tree(&mut runner).unwrap();
+    }
+}
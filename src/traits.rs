@@ -0,0 +1,45 @@
+//! All traits of the crate, for authors of algorithms that are generic over the arithmetic backend.
+//!
+//! `use ebi_arithmetic::traits::*;` brings every operation into scope, regardless of the module that defines it.
+//!
+//! Scalars ([FractionExact](crate::fraction::fraction_exact::FractionExact), [FractionF64](crate::fraction::fraction_f64::FractionF64) and [FractionEnum](crate::fraction::fraction_enum::FractionEnum)) implement
+//! [EbiNumber], [Zero], [One], [Signed], [Round], [Recip], [Pow], [OneMinus], [Random], [ChooseRandomly], [Sqrt], [NthRoot], [Exponential], [ToNative], [ToInteger], [ToPercentage], [FormatWith], [MaybeExact], [Approximate], [CheckedFrom], [Clamp], [ToDuration], [Odds], [TotalOrd], [ApproxEq], [OutwardRounding], [ToDual], [Log] and the non-panicking [TryAdd], [TrySub], [TryMul], [TryDiv] and [TryCmp].
+//! Exact values are compared exactly; approximate values are compared with a tolerance (see [EPSILON](crate::fraction::fraction::EPSILON)).
+//! [Sqrt], [NthRoot] and [Exponential] approximate to the requested number of decimals, also for exact values.
+//! [FractionExact](crate::fraction::fraction_exact::FractionExact) and [FractionF64](crate::fraction::fraction_f64::FractionF64) also implement [DynNumber], the object-safe subset of these operations that [DynFraction](crate::fraction::dyn_fraction::DynFraction) dispatches to.
+//!
+//! Matrices ([FractionMatrixExact](crate::matrix::fraction_matrix_exact::FractionMatrixExact), [FractionMatrixF64](crate::matrix::fraction_matrix_f64::FractionMatrixF64) and [FractionMatrixEnum](crate::matrix::fraction_matrix_enum::FractionMatrixEnum)) implement
+//! [EbiMatrix], [MaybeExact], [IdentityMinus], [Inversion], [GaussJordan], [BlockDiagonal], [Blocks], [ConvexCombination], [Diff], [SolutionSpaceOf], [Solve], [SpectralRadiusBound], [Symmetric], [Determinant], [Norm], [MulBatch] and [ApproxEq].
+//!
+//! For the enum backends, combining exact and approximate values yields an error or a poison value, rather than a silent conversion.
+//!
+//! Downstream types that implement these traits only need the methods without a default. Methods added to [EbiMatrix] and [ChooseRandomly] have defaults, so existing implementations keep compiling. [Round] is the exception: its documentation lists the methods that implementors need to add.
+
+pub use crate::dual_run::ToDual;
+pub use crate::ebi_log_polynomial::EbiLogPolynomial;
+pub use crate::ebi_matrix::{
+    BlockDiagonal, Blocks, ConvexCombination, Determinant, Diff, EbiMatrix, GaussJordan,
+    IdentityMinus, Inversion, MulBatch, Norm, SolutionSpaceOf, Solve, SpectralRadiusBound,
+    Symmetric,
+};
+pub use crate::ebi_number::{
+    ChooseRandomly, EbiNumber, Exponential, NthRoot, One, OneMinus, Pow, Random, Recip, Round,
+    Signed, Sqrt, ToInteger, ToNative, ToPercentage, Zero,
+};
+pub use crate::exact::MaybeExact;
+pub use crate::exporter::Exporter;
+pub use crate::fraction::approx_eq::ApproxEq;
+pub use crate::fraction::approximate::Approximate;
+pub use crate::fraction::checked_from::CheckedFrom;
+pub use crate::fraction::clamp::Clamp;
+#[cfg(any(feature = "rust_decimal", feature = "bigdecimal"))]
+pub use crate::fraction::decimal::ToDecimal;
+pub use crate::fraction::duration::ToDuration;
+pub use crate::fraction::dyn_fraction::DynNumber;
+pub use crate::fraction::format::FormatWith;
+pub use crate::fraction::interval::OutwardRounding;
+pub use crate::fraction::odds::Odds;
+pub use crate::fraction::ordered_fraction::TotalOrd;
+pub use crate::fraction::signed::Numerator;
+pub use crate::log::{Log, LogOf};
+pub use crate::try_ops::{TryAdd, TryCmp, TryDiv, TryMul, TrySub};
@@ -0,0 +1,181 @@
+//! Arithmetic and comparison that return an error instead of panicking or producing a poison value.
+//!
+//! The operators of this crate panic in some situations, such as an exact division by zero or a comparison of an exact and an approximate [FractionEnum],
+//! and combine exact and approximate values into [FractionEnum::CannotCombineExactAndApprox].
+//! The traits in this module never panic, which makes them suitable for long-running processes that embed this crate.
+//! For conversions, see [CheckedFrom](crate::fraction::checked_from::CheckedFrom).
+
+use anyhow::{Result, anyhow};
+use malachite::rational::Rational;
+use std::cmp::Ordering;
+
+use crate::{
+    Zero,
+    fraction::{
+        fraction_enum::FractionEnum, fraction_exact::FractionExact, fraction_f64::FractionF64,
+    },
+};
+
+pub trait TryAdd: Sized {
+    /// Returns the sum, or an error if the operands cannot be combined.
+    fn try_add(&self, rhs: &Self) -> Result<Self>;
+}
+
+pub trait TrySub: Sized {
+    /// Returns the difference, or an error if the operands cannot be combined.
+    fn try_sub(&self, rhs: &Self) -> Result<Self>;
+}
+
+pub trait TryMul: Sized {
+    /// Returns the product, or an error if the operands cannot be combined.
+    fn try_mul(&self, rhs: &Self) -> Result<Self>;
+}
+
+pub trait TryDiv: Sized {
+    /// Returns the quotient, or an error if the operands cannot be combined or if the divisor is zero.
+    fn try_div(&self, rhs: &Self) -> Result<Self>;
+}
+
+pub trait TryCmp {
+    /// Returns the ordering of the values, or an error if they cannot be compared.
+    fn try_cmp(&self, rhs: &Self) -> Result<Ordering>;
+}
+
+fn division_by_zero() -> anyhow::Error {
+    anyhow!("cannot divide by zero")
+}
+
+macro_rules! try_ops {
+    ($t:ident) => {
+        impl TryAdd for $t {
+            fn try_add(&self, rhs: &Self) -> Result<Self> {
+                Ok(self + rhs)
+            }
+        }
+
+        impl TrySub for $t {
+            fn try_sub(&self, rhs: &Self) -> Result<Self> {
+                Ok(self - rhs)
+            }
+        }
+
+        impl TryMul for $t {
+            fn try_mul(&self, rhs: &Self) -> Result<Self> {
+                Ok(self * rhs)
+            }
+        }
+
+        impl TryDiv for $t {
+            fn try_div(&self, rhs: &Self) -> Result<Self> {
+                if rhs.is_zero() {
+                    return Err(division_by_zero());
+                }
+                Ok(self / rhs)
+            }
+        }
+
+        impl TryCmp for $t {
+            fn try_cmp(&self, rhs: &Self) -> Result<Ordering> {
+                Ok(self.cmp(rhs))
+            }
+        }
+    };
+}
+
+try_ops!(FractionF64);
+try_ops!(FractionExact);
+
+/// Applies an operation to two values of the same mode, or returns an error if the modes differ.
+macro_rules! try_enum {
+    ($x:ident, $y:ident, $exact:expr, $approx:expr) => {
+        match ($x, $y) {
+            (FractionEnum::Exact(x), FractionEnum::Exact(y)) => {
+                Ok(FractionEnum::Exact($exact(x, y)))
+            }
+            (FractionEnum::Approx(x), FractionEnum::Approx(y)) => {
+                Ok(FractionEnum::Approx($approx(*x, *y)))
+            }
+            _ => Err(anyhow!("cannot combine exact and approximate arithmetic")),
+        }
+    };
+}
+
+impl TryAdd for FractionEnum {
+    fn try_add(&self, rhs: &Self) -> Result<Self> {
+        try_enum!(self, rhs, |x: &Rational, y| x + y, |x: f64, y| x + y)
+    }
+}
+
+impl TrySub for FractionEnum {
+    fn try_sub(&self, rhs: &Self) -> Result<Self> {
+        try_enum!(self, rhs, |x: &Rational, y| x - y, |x: f64, y| x - y)
+    }
+}
+
+impl TryMul for FractionEnum {
+    fn try_mul(&self, rhs: &Self) -> Result<Self> {
+        try_enum!(self, rhs, |x: &Rational, y| x * y, |x: f64, y| x * y)
+    }
+}
+
+impl TryDiv for FractionEnum {
+    fn try_div(&self, rhs: &Self) -> Result<Self> {
+        if rhs.is_zero() {
+            return Err(division_by_zero());
+        }
+        try_enum!(self, rhs, |x: &Rational, y| x / y, |x: f64, y| x / y)
+    }
+}
+
+impl TryCmp for FractionEnum {
+    fn try_cmp(&self, rhs: &Self) -> Result<Ordering> {
+        match (self, rhs) {
+            (FractionEnum::Exact(_), FractionEnum::Exact(_))
+            | (FractionEnum::Approx(_), FractionEnum::Approx(_)) => Ok(self.cmp(rhs)),
+            _ => Err(anyhow!("cannot compare exact and approximate arithmetic")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use malachite::rational::Rational;
+    use std::cmp::Ordering;
+
+    use crate::{
+        fraction::{fraction_enum::FractionEnum, fraction_exact::FractionExact},
+        try_ops::{TryAdd, TryCmp, TryDiv, TryMul, TrySub},
+    };
+
+    #[test]
+    fn try_ops() {
+        let exact = FractionEnum::Exact(Rational::from_signeds(1, 2));
+        let approx = FractionEnum::Approx(0.5);
+
+        assert_eq!(
+            exact.try_add(&exact).unwrap(),
+            FractionEnum::Exact(1.into())
+        );
+        assert_eq!(approx.try_mul(&approx).unwrap(), FractionEnum::Approx(0.25));
+        assert!(exact.try_sub(&approx).is_err());
+        assert!(
+            approx
+                .try_add(&FractionEnum::CannotCombineExactAndApprox)
+                .is_err()
+        );
+
+        assert!(exact.try_div(&FractionEnum::Exact(0.into())).is_err());
+        assert!(approx.try_div(&FractionEnum::Approx(0.0)).is_err());
+        assert!(
+            FractionExact::from(1)
+                .try_div(&FractionExact::from(0))
+                .is_err()
+        );
+
+        assert_eq!(
+            exact.try_cmp(&FractionEnum::Exact(1.into())).unwrap(),
+            Ordering::Less
+        );
+        assert!(exact.try_cmp(&approx).is_err());
+    }
+}
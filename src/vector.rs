@@ -0,0 +1,192 @@
+//! Operations on vectors of fractions, generic over the arithmetic backends.
+//!
+//! Sums start from their first term rather than from zero, such that the result for vectors of [FractionEnum](crate::fraction::fraction_enum::FractionEnum)s does not depend on the global arithmetic mode.
+
+use anyhow::{Result, anyhow};
+use std::ops::{Add, AddAssign, Div, Mul};
+
+use crate::{Signed, Sqrt, Zero};
+
+/// Computes the sum of the terms, starting from the first term rather than from zero.
+pub(crate) fn sum_from_first<F: Zero + AddAssign>(mut terms: impl Iterator<Item = F>) -> F {
+    match terms.next() {
+        Some(first) => {
+            let mut sum = first;
+            for term in terms {
+                sum += term;
+            }
+            sum
+        }
+        None => F::zero(),
+    }
+}
+
+/// Computes the sum of the products of the pairs, starting from the first product rather than from zero.
+pub(crate) fn sum_of_products<'a, F>(pairs: impl Iterator<Item = (&'a F, &'a F)>) -> F
+where
+    F: Zero + AddAssign + 'a,
+    for<'b> &'b F: Mul<&'b F, Output = F>,
+{
+    sum_from_first(pairs.map(|(a, b)| a * b))
+}
+
+/// Returns the running combination of the values: the first value, then the combination of that with the second value, and so on.
+pub(crate) fn cumulative<'a, F: Clone + 'a>(
+    values: impl Iterator<Item = &'a F>,
+    combine: impl Fn(&F, &F) -> F,
+) -> Vec<F> {
+    let mut result: Vec<F> = Vec::with_capacity(values.size_hint().0);
+    for value in values {
+        let next = match result.last() {
+            Some(last) => combine(last, value),
+            None => value.clone(),
+        };
+        result.push(next);
+    }
+    result
+}
+
+/// Returns the prefix sums of the values: the i-th element is the sum of the first i + 1 values.
+/// The last element is the sum of all values, such that the prefix sums of a probability distribution form its cumulative distribution function.
+pub fn cumulative_sum<F: Clone>(values: &[F]) -> Vec<F>
+where
+    for<'b> &'b F: Add<&'b F, Output = F>,
+{
+    cumulative(values.iter(), |sum, value| sum + value)
+}
+
+/// Returns the prefix products of the values: the i-th element is the product of the first i + 1 values.
+pub fn cumulative_product<F: Clone>(values: &[F]) -> Vec<F>
+where
+    for<'b> &'b F: Mul<&'b F, Output = F>,
+{
+    cumulative(values.iter(), |product, value| product * value)
+}
+
+/// Returns the dot product of the vectors.
+/// Returns an error if the vectors have different lengths.
+pub fn dot<F>(a: &[F], b: &[F]) -> Result<F>
+where
+    F: Zero + AddAssign,
+    for<'b> &'b F: Mul<&'b F, Output = F>,
+{
+    if a.len() != b.len() {
+        return Err(anyhow!(
+            "cannot take the dot product of vectors of lengths {} and {}",
+            a.len(),
+            b.len()
+        ));
+    }
+    Ok(sum_of_products(a.iter().zip(b.iter())))
+}
+
+/// Returns the sum of the absolute values of the vector.
+pub fn norm_1<F: Zero + AddAssign + Signed + Clone>(vector: &[F]) -> F {
+    sum_from_first(vector.iter().map(|value| value.clone().abs()))
+}
+
+/// Returns the Euclidean length of the vector, within 1/10^`decimal_places` of the actual value.
+pub fn norm_2<F>(vector: &[F], decimal_places: u32) -> Result<F>
+where
+    F: Zero + AddAssign + Sqrt,
+    for<'b> &'b F: Mul<&'b F, Output = F>,
+{
+    dot(vector, vector)?.approx_sqrt(decimal_places)
+}
+
+/// Returns the vector divided by its [norm_1], such that its absolute values sum to one.
+/// Unlike dividing by the [norm_2], this keeps exact vectors exact, and turns a vector of weights into a probability distribution.
+/// Returns an error if all values of the vector are zero.
+pub fn normalize<F>(vector: &[F]) -> Result<Vec<F>>
+where
+    F: Zero + AddAssign + Signed + Clone,
+    for<'b> &'b F: Div<&'b F, Output = F>,
+{
+    let norm = norm_1(vector);
+    if norm.is_zero() {
+        return Err(anyhow!(
+            "cannot normalize a vector of which all values are zero"
+        ));
+    }
+    Ok(vector.iter().map(|value| value / &norm).collect())
+}
+
+/// Returns the cosine of the angle between the vectors, within about 1/10^`decimal_places` of the actual value.
+/// Returns an error if the vectors have different lengths, or if one of them has only zero values.
+pub fn cosine_similarity<F>(a: &[F], b: &[F], decimal_places: u32) -> Result<F>
+where
+    F: Zero + AddAssign + Sqrt,
+    for<'b> &'b F: Mul<&'b F, Output = F> + Div<&'b F, Output = F>,
+{
+    let numerator = dot(a, b)?;
+    let a_squared = dot(a, a)?;
+    let b_squared = dot(b, b)?;
+    if a_squared.is_zero() || b_squared.is_zero() {
+        return Err(anyhow!(
+            "cannot take the cosine similarity of a vector of which all values are zero"
+        ));
+    }
+    //a single square root, such that only one approximation is made
+    let denominator = (&a_squared * &b_squared).approx_sqrt(decimal_places)?;
+    Ok(&numerator / &denominator)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        fraction::{fraction_exact::FractionExact, fraction_f64::FractionF64},
+        vector::{
+            cosine_similarity, cumulative_product, cumulative_sum, dot, norm_1, norm_2, normalize,
+        },
+    };
+
+    #[test]
+    fn vector() {
+        let a: Vec<FractionExact> = vec![3.into(), (-4).into()];
+        let b: Vec<FractionExact> = vec![(1, 2).into(), 1.into()];
+        assert_eq!(dot(&a, &b).unwrap(), FractionExact::from((-5, 2)));
+        assert!(dot(&a, &b[..1]).is_err());
+
+        assert_eq!(norm_1(&a), FractionExact::from(7));
+        assert_eq!(norm_2(&a, 5).unwrap(), FractionExact::from(5));
+        assert_eq!(
+            normalize(&a).unwrap(),
+            vec![FractionExact::from((3, 7)), FractionExact::from((-4, 7))]
+        );
+        assert!(normalize(&[FractionExact::from(0)]).is_err());
+
+        assert_eq!(
+            cosine_similarity(&a, &a, 5).unwrap(),
+            FractionExact::from(1)
+        );
+        let c: Vec<FractionF64> = vec![1.0.into(), 0.0.into()];
+        let d: Vec<FractionF64> = vec![1.0.into(), 1.0.into()];
+        assert_eq!(
+            cosine_similarity(&c, &d, 0).unwrap(),
+            FractionF64::from(1.0 / 2f64.sqrt())
+        );
+        assert!(cosine_similarity(&c, &[0.0.into(), 0.0.into()], 0).is_err());
+    }
+
+    #[test]
+    fn cumulative() {
+        let values: Vec<FractionExact> = vec![(1, 2).into(), (1, 3).into(), (1, 6).into()];
+        assert_eq!(
+            cumulative_sum(&values),
+            vec![
+                FractionExact::from((1, 2)),
+                FractionExact::from((5, 6)),
+                FractionExact::from(1)
+            ]
+        );
+        assert_eq!(
+            cumulative_product(&values),
+            vec![
+                FractionExact::from((1, 2)),
+                FractionExact::from((1, 6)),
+                FractionExact::from((1, 36))
+            ]
+        );
+        assert!(cumulative_sum::<FractionF64>(&[]).is_empty());
+    }
+}
@@ -0,0 +1,218 @@
+//! JavaScript bindings of exact fractions and matrices, with the `wasm` feature, for instance to run exact computations in a browser.
+//!
+//! Values cross the boundary as strings in any notation that [FractionExact] parses, such that they remain exact.
+//! Build with `wasm-pack build --target web --features wasm`.
+//!
+//! The fallible operations are implemented as functions that return [anyhow::Result], which the bindings convert into JavaScript errors,
+//! such that they can be tested without a JavaScript runtime.
+
+use anyhow::{Error, Result, anyhow};
+use std::str::FromStr;
+use wasm_bindgen::prelude::*;
+
+use crate::{
+    EbiMatrix, Inversion, Solve, Zero, fraction::fraction_exact::FractionExact,
+    matrix::fraction_matrix_exact::FractionMatrixExact,
+};
+
+fn js_error(error: Error) -> JsError {
+    JsError::new(&error.to_string())
+}
+
+fn parse_all(values: &[String]) -> Result<Vec<FractionExact>> {
+    values
+        .iter()
+        .map(|value| FractionExact::from_str(value))
+        .collect()
+}
+
+/// An exact fraction.
+#[wasm_bindgen(js_name = Fraction)]
+pub struct WasmFraction(FractionExact);
+
+#[wasm_bindgen(js_class = Fraction)]
+impl WasmFraction {
+    /// Parses a fraction, such as "1/3", "0.25", "0.1(6)" or "1.5e-3".
+    #[wasm_bindgen(constructor)]
+    pub fn new(value: &str) -> Result<WasmFraction, JsError> {
+        Ok(Self(FractionExact::from_str(value).map_err(js_error)?))
+    }
+
+    pub fn add(&self, other: &WasmFraction) -> WasmFraction {
+        Self(&self.0 + &other.0)
+    }
+
+    pub fn sub(&self, other: &WasmFraction) -> WasmFraction {
+        Self(&self.0 - &other.0)
+    }
+
+    pub fn mul(&self, other: &WasmFraction) -> WasmFraction {
+        Self(&self.0 * &other.0)
+    }
+
+    pub fn div(&self, other: &WasmFraction) -> Result<WasmFraction, JsError> {
+        self.checked_div(other).map_err(js_error)
+    }
+
+    pub fn equals(&self, other: &WasmFraction) -> bool {
+        self.0 == other.0
+    }
+
+    /// Returns -1, 0 or 1 if this fraction is smaller than, equal to or larger than the other fraction, as `Array.prototype.sort` expects.
+    pub fn compare(&self, other: &WasmFraction) -> i32 {
+        self.0.cmp(&other.0) as i32
+    }
+
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_js_string(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+impl WasmFraction {
+    fn checked_div(&self, other: &WasmFraction) -> Result<WasmFraction> {
+        if other.0.is_zero() {
+            return Err(anyhow!("division by zero"));
+        }
+        Ok(Self(&self.0 / &other.0))
+    }
+}
+
+/// A matrix of exact fractions.
+#[wasm_bindgen(js_name = FractionMatrix)]
+pub struct WasmFractionMatrix(FractionMatrixExact);
+
+#[wasm_bindgen(js_class = FractionMatrix)]
+impl WasmFractionMatrix {
+    /// Creates a matrix from its values in row-major order, each parsed as a fraction.
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        number_of_rows: usize,
+        number_of_columns: usize,
+        values: Vec<String>,
+    ) -> Result<WasmFractionMatrix, JsError> {
+        Self::try_new(number_of_rows, number_of_columns, &values).map_err(js_error)
+    }
+
+    #[wasm_bindgen(getter = numberOfRows)]
+    pub fn number_of_rows(&self) -> usize {
+        self.0.number_of_rows()
+    }
+
+    #[wasm_bindgen(getter = numberOfColumns)]
+    pub fn number_of_columns(&self) -> usize {
+        self.0.number_of_columns()
+    }
+
+    /// Returns the value at (row, column), or undefined if it does not exist.
+    pub fn get(&self, row: usize, column: usize) -> Option<String> {
+        self.0.get(row, column).map(|value| value.to_string())
+    }
+
+    pub fn mul(&self, other: &WasmFractionMatrix) -> Result<WasmFractionMatrix, JsError> {
+        Ok(Self((&self.0 * &other.0).map_err(js_error)?))
+    }
+
+    pub fn inverse(&self) -> Result<WasmFractionMatrix, JsError> {
+        Ok(Self(self.0.clone().invert().map_err(js_error)?))
+    }
+
+    /// Returns the unique solution x of A x = b, where A is this matrix.
+    pub fn solve(&self, b: Vec<String>) -> Result<Vec<String>, JsError> {
+        self.try_solve(&b).map_err(js_error)
+    }
+
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_js_string(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+impl WasmFractionMatrix {
+    fn try_new(
+        number_of_rows: usize,
+        number_of_columns: usize,
+        values: &[String],
+    ) -> Result<WasmFractionMatrix> {
+        if number_of_rows.checked_mul(number_of_columns) != Some(values.len()) {
+            return Err(anyhow!(
+                "a matrix of size {}x{} cannot have {} values",
+                number_of_rows,
+                number_of_columns,
+                values.len()
+            ));
+        }
+        Ok(Self(FractionMatrixExact {
+            values: parse_all(values)?
+                .into_iter()
+                .map(|value| value.0)
+                .collect(),
+            number_of_rows,
+            number_of_columns,
+        }))
+    }
+
+    fn try_solve(&self, b: &[String]) -> Result<Vec<String>> {
+        Ok(self
+            .0
+            .solve(&parse_all(b)?)?
+            .iter()
+            .map(|value| value.to_string())
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::wasm::{WasmFraction, WasmFractionMatrix};
+
+    //JsError cannot be constructed outside of a JavaScript runtime, so the error paths are tested on the functions that return anyhow errors
+
+    fn strings(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn wasm_fraction() {
+        let x = WasmFraction::new("1/3").unwrap();
+        let y = WasmFraction::new("0.1(6)").unwrap();
+        assert_eq!(x.add(&y).to_js_string(), "1/2");
+        assert_eq!(x.sub(&y).to_js_string(), "1/6");
+        assert_eq!(x.mul(&y).to_js_string(), "1/18");
+        assert_eq!(x.div(&y).unwrap().to_js_string(), "2");
+        assert_eq!(x.compare(&y), 1);
+        assert_eq!(y.compare(&x), -1);
+        assert!(x.equals(&WasmFraction::new("2/6").unwrap()));
+
+        let zero = WasmFraction::new("0").unwrap();
+        assert_eq!(
+            x.checked_div(&zero).err().unwrap().to_string(),
+            "division by zero"
+        );
+    }
+
+    #[test]
+    fn wasm_matrix() {
+        let m = WasmFractionMatrix::new(2, 2, strings(&["2", "1", "1", "1"])).unwrap();
+        assert_eq!(m.number_of_rows(), 2);
+        assert_eq!(m.number_of_columns(), 2);
+        assert_eq!(m.get(1, 0), Some("1".to_string()));
+        assert_eq!(m.get(2, 0), None);
+
+        let inverse = m.inverse().unwrap();
+        assert_eq!(inverse.get(0, 1), Some("-1".to_string()));
+        let product = m.mul(&inverse).unwrap();
+        assert_eq!(product.get(0, 0), Some("1".to_string()));
+        assert_eq!(product.get(0, 1), Some("0".to_string()));
+        assert_eq!(m.solve(strings(&["3", "2"])).unwrap(), strings(&["1", "1"]));
+
+        assert!(WasmFractionMatrix::try_new(2, 2, &strings(&["1", "2", "3"])).is_err());
+        assert!(WasmFractionMatrix::try_new(usize::MAX, 2, &[]).is_err());
+        assert!(WasmFractionMatrix::try_new(1, 1, &strings(&["x"])).is_err());
+        assert!(m.try_solve(&strings(&["1"])).is_err());
+        assert!(m.try_solve(&strings(&["1", "x"])).is_err());
+
+        let singular = WasmFractionMatrix::try_new(1, 1, &strings(&["0"])).unwrap();
+        assert!(singular.try_solve(&strings(&["1"])).is_err());
+    }
+}